@@ -0,0 +1,202 @@
+//! Angle wrapping operations module
+//!
+//! Generates the wrapping arithmetic of the `[-PI, PI]` type and the conversions of
+//! arbitrary finite angles into it and into the canonical `[0, TAU)` type. Both types
+//! are looked up by their bounds, so invocations that do not define them get no angle
+//! methods.
+
+use core::f64::consts::{PI, TAU};
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::config::{Bounds, Sign, TypeConfig};
+
+/// Finds the constraint admitting exactly `[-PI, PI]`
+fn find_pi_type(config: &TypeConfig) -> Option<proc_macro2::Ident> {
+    config.find_type_by_constraints(Sign::Any, &Bounds::closed(Some(-PI), Some(PI)), false)
+}
+
+/// Finds the constraint admitting exactly `[0, TAU)`
+fn find_tau_type(config: &TypeConfig) -> Option<proc_macro2::Ident> {
+    let bounds = Bounds {
+        upper_inclusive: false,
+        ..Bounds::closed(Some(0.0), Some(TAU))
+    };
+    config.find_type_by_constraints(Sign::Positive, &bounds, false)
+}
+
+/// Generates the raw wrapping helpers shared by all angle methods
+fn generate_wrap_helpers(pi_type: &proc_macro2::Ident) -> TokenStream {
+    quote! {
+        /// Folds a finite angle into `[0, TAU)`
+        #[inline]
+        fn wrap_angle_to_tau<F: Float>(angle: F) -> F {
+            let wrapped = angle.rem_euclid(F::TAU);
+            // A tiny negative angle rounds up to `TAU` itself
+            if wrapped >= F::TAU { wrapped - F::TAU } else { wrapped }
+        }
+
+        /// Folds a finite angle into `[-PI, PI]`, keeping angles already in range
+        #[inline]
+        fn wrap_angle_to_pi<F: Float>(angle: F) -> F {
+            if angle.satisfies::<#pi_type>() {
+                return angle;
+            }
+            let wrapped = wrap_angle_to_tau(angle);
+            // `wrapped - TAU` is exact for `wrapped` in (PI, TAU), giving (-PI, 0)
+            if wrapped > F::PI { wrapped - F::TAU } else { wrapped }
+        }
+    }
+}
+
+/// Generates `wrap_to_tau`, if the config defines a `[0, TAU)` type
+fn generate_wrap_to_tau(config: &TypeConfig) -> TokenStream {
+    let Some(tau_type) = find_tau_type(config) else {
+        return quote! {};
+    };
+
+    quote! {
+        /// Wraps the angle (in radians) into `[0, TAU)`
+        ///
+        /// Angles already in range are returned unchanged.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use core::f64::consts::{FRAC_PI_2, PI, TAU};
+        /// use strict_num_extended::*;
+        ///
+        /// let heading = FinF64::new(-FRAC_PI_2).unwrap().wrap_to_tau();
+        /// assert_eq!(heading.get(), 3.0 * FRAC_PI_2);
+        /// assert_eq!(FinF64::new(TAU).unwrap().wrap_to_tau().get(), 0.0);
+        /// assert_eq!(FinF64::new(PI).unwrap().wrap_to_tau().get(), PI);
+        /// ```
+        #[inline]
+        #[must_use]
+        pub fn wrap_to_tau(self) -> Strict<F, #tau_type> {
+            // SAFETY: `wrap_angle_to_tau` returns a value in [0, TAU) for finite input
+            unsafe { Strict::new_unchecked(wrap_angle_to_tau(self.value)) }
+        }
+    }
+}
+
+/// Generates the angle wrapping methods
+pub fn generate_angle_impls(config: &TypeConfig) -> TokenStream {
+    let Some(pi_type) = find_pi_type(config) else {
+        return quote! {};
+    };
+    let helpers = generate_wrap_helpers(&pi_type);
+    let wrap_to_tau = generate_wrap_to_tau(config);
+
+    quote! {
+        #helpers
+
+        impl<F: Float, C: Constraint> Strict<F, C> {
+            /// Wraps the angle (in radians) into `[-PI, PI]`
+            ///
+            /// Angles already in range, including `PI` and `-PI`, are returned unchanged;
+            /// other angles are folded into `(-PI, PI]`.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use core::f64::consts::{FRAC_PI_2, PI};
+            /// use strict_num_extended::*;
+            ///
+            /// let angle = FinF64::new(3.0 * FRAC_PI_2).unwrap().wrap_to_pi();
+            /// assert_eq!(angle.get(), -FRAC_PI_2);
+            /// assert_eq!(FinF64::new(-PI).unwrap().wrap_to_pi().get(), -PI);
+            /// assert_eq!(FinF64::new(3.0 * PI).unwrap().wrap_to_pi().get(), PI);
+            /// ```
+            #[inline]
+            #[must_use]
+            pub fn wrap_to_pi(self) -> Strict<F, #pi_type> {
+                // SAFETY: `wrap_angle_to_pi` returns a value in [-PI, PI] for finite input
+                unsafe { Strict::new_unchecked(wrap_angle_to_pi(self.value)) }
+            }
+
+            #wrap_to_tau
+
+            /// Computes the shortest signed rotation (in radians) from `self` to `target`
+            ///
+            /// The result is positive for a counter-clockwise rotation. Opposite angles
+            /// are `PI` or `-PI` apart, depending on rounding.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use core::f64::consts::{FRAC_PI_2, PI};
+            /// use strict_num_extended::*;
+            ///
+            /// let from = PiBoundedF64::new(-3.0).unwrap();
+            /// let to = PiBoundedF64::new(3.0).unwrap();
+            /// let delta: PiBoundedF64 = from.shortest_angle_to(to);
+            /// assert!((delta.get() - (6.0 - 2.0 * PI)).abs() < 1e-12);
+            ///
+            /// let heading = FinF64::new(5.0 * FRAC_PI_2).unwrap();
+            /// assert_eq!(heading.shortest_angle_to(FinF64::new(0.0).unwrap()).get(), -FRAC_PI_2);
+            /// ```
+            #[inline]
+            #[must_use]
+            pub fn shortest_angle_to<R: Constraint>(
+                self,
+                target: Strict<F, R>,
+            ) -> Strict<F, #pi_type> {
+                let delta = wrap_angle_to_pi(target.value) - wrap_angle_to_pi(self.value);
+                // SAFETY: `delta` lies in [-TAU, TAU], so it is finite and wraps into
+                // [-PI, PI]
+                unsafe { Strict::new_unchecked(wrap_angle_to_pi(delta)) }
+            }
+        }
+
+        impl<F: Float> Strict<F, #pi_type> {
+            /// Adds two angles, wrapping the sum back into `[-PI, PI]`
+            ///
+            /// Sums already in range are exact; others are shifted by exactly `TAU`.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use core::f64::consts::{FRAC_PI_2, PI};
+            /// use strict_num_extended::*;
+            ///
+            /// let a = PiBoundedF64::new(3.0 * PI / 4.0).unwrap();
+            /// let b = PiBoundedF64::new(FRAC_PI_2).unwrap();
+            /// assert!((a.wrapping_add(b).get() - (-3.0 * PI / 4.0)).abs() < 1e-12);
+            ///
+            /// let pi = PiBoundedF64::new(PI).unwrap();
+            /// assert_eq!(pi.wrapping_add(PiBoundedF64::new(0.0).unwrap()).get(), PI);
+            /// ```
+            #[inline]
+            #[must_use]
+            pub fn wrapping_add(self, rhs: Self) -> Self {
+                // SAFETY: the sum lies in [-TAU, TAU], so it is finite and wraps into
+                // [-PI, PI]
+                unsafe { Strict::new_unchecked(wrap_angle_to_pi(self.value + rhs.value)) }
+            }
+
+            /// Subtracts two angles, wrapping the difference back into `[-PI, PI]`
+            ///
+            /// Differences already in range are exact; others are shifted by exactly `TAU`.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use core::f64::consts::{FRAC_PI_2, PI};
+            /// use strict_num_extended::*;
+            ///
+            /// let a = PiBoundedF64::new(-3.0 * PI / 4.0).unwrap();
+            /// let b = PiBoundedF64::new(FRAC_PI_2).unwrap();
+            /// assert!((a.wrapping_sub(b).get() - 3.0 * PI / 4.0).abs() < 1e-12);
+            /// ```
+            #[inline]
+            #[must_use]
+            pub fn wrapping_sub(self, rhs: Self) -> Self {
+                // SAFETY: the difference lies in [-TAU, TAU], so it is finite and wraps
+                // into [-PI, PI]
+                unsafe { Strict::new_unchecked(wrap_angle_to_pi(self.value - rhs.value)) }
+            }
+        }
+    }
+}
@@ -1,8 +1,24 @@
 //! Arithmetic operations module
 
+mod assign_ops;
 mod binary_ops;
+mod bounded_ops;
+mod fused_ops;
+mod integer_ops;
 mod neg_ops;
+mod recip_ops;
+mod ref_ops;
+mod saturating_ops;
 
 // Re-export all functions
-pub use binary_ops::generate_arithmetic_impls;
-pub use neg_ops::generate_neg_impls;
+pub use assign_ops::generate_assign_impls;
+pub use binary_ops::{
+    apply_op, generate_arithmetic_impls, generate_arithmetic_output_impls, operator_variant,
+};
+pub use bounded_ops::{INTERVAL_INFERENCE, generate_bounded_impls, generate_bounded_output_impls};
+pub use fused_ops::generate_mul_add_impls;
+pub use integer_ops::generate_integer_arithmetic_impls;
+pub use neg_ops::{generate_neg_impls, generate_neg_output_impls};
+pub use recip_ops::{generate_recip_impls, generate_recip_output_impls};
+pub use ref_ops::generate_ref_forwarding_impls;
+pub use saturating_ops::generate_saturating_impls;
@@ -0,0 +1,71 @@
+//! Compound assignment operations module
+
+use proc_macro2::{Ident, Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+
+use crate::config::get_standard_arithmetic_ops;
+
+/// Generates compound assignment operators and their fallible counterparts.
+///
+/// `OpAssign` is implemented for every pair of constraints whose operation is safe and
+/// closed, i.e. whose output trait resolves to `Strict<F, L>` itself. All other
+/// operations get a `try_op_assign` method that validates the result against the
+/// constraint of `self` and leaves the value untouched on error.
+pub fn generate_assign_impls() -> TokenStream2 {
+    let ops = get_standard_arithmetic_ops();
+
+    let assign_impls = ops.iter().map(|(_, trait_name, method_name, op_symbol)| {
+        let assign_trait = format_ident!("{}Assign", trait_name);
+        let assign_method = format_ident!("{}_assign", method_name);
+        let output_trait = format_ident!("{}Output", trait_name);
+
+        quote! {
+            impl<F: Float, L, R: Constraint> #assign_trait<Strict<F, R>> for Strict<F, L>
+            where
+                L: #output_trait<F, R, Output = Strict<F, L>>,
+            {
+                #[inline]
+                fn #assign_method(&mut self, rhs: Strict<F, R>) {
+                    *self = *self #op_symbol rhs;
+                }
+            }
+        }
+    });
+
+    let try_methods = ops.iter().map(|(_, trait_name, method_name, op_symbol)| {
+        let op_trait = Ident::new(trait_name, Span::call_site());
+        let try_method = format_ident!("try_{}_assign", method_name);
+        let doc = format!(
+            "Computes `self {op_symbol} rhs` and stores it if it satisfies the constraint of `self`"
+        );
+
+        quote! {
+            #[doc = #doc]
+            ///
+            /// Accepts any right-hand side supported by the operator, including
+            /// primitives and references. On error, `self` is left unchanged.
+            ///
+            /// # Errors
+            ///
+            /// Returns the error of the operation, such as `Err(FloatError::AboveRange)`
+            /// if the result is above the range of `self`.
+            #[inline]
+            pub fn #try_method<Rhs>(&mut self, rhs: Rhs) -> Result<(), FloatError>
+            where
+                Self: #op_trait<Rhs>,
+                <Self as #op_trait<Rhs>>::Output: OpOutput<F>,
+            {
+                *self = (*self #op_symbol rhs).into_result()?.try_convert()?;
+                Ok(())
+            }
+        }
+    });
+
+    quote! {
+        #(#assign_impls)*
+
+        impl<F: Float, C: Constraint> Strict<F, C> {
+            #(#try_methods)*
+        }
+    }
+}
@@ -1,222 +1,288 @@
 //! Binary arithmetic operations module
 
-use proc_macro2::{Ident, TokenStream as TokenStream2};
-use quote::quote;
+use proc_macro2::{Ident, Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
 
-use crate::config::{ArithmeticOp, ArithmeticResult, TypeConfig, get_standard_arithmetic_ops};
-use crate::generator::{
-    generate_arithmetic_for_all_types, generate_arithmetic_for_primitive_types,
-};
+use super::generate_ref_forwarding_impls;
+use crate::config::{ArithmeticOp, TypeConfig, get_euclid_ops, get_standard_arithmetic_ops};
+use crate::generator::generate_arithmetic_for_all_types;
+
+/// Applies an operation to two raw float expressions
+pub fn apply_op(
+    op: ArithmeticOp,
+    op_symbol: &TokenStream2,
+    lhs: &TokenStream2,
+    rhs: &TokenStream2,
+) -> TokenStream2 {
+    match op {
+        ArithmeticOp::RemEuclid | ArithmeticOp::DivEuclid => quote! { #op_symbol(#lhs, #rhs) },
+        ArithmeticOp::Add
+        | ArithmeticOp::Sub
+        | ArithmeticOp::Mul
+        | ArithmeticOp::Div
+        | ArithmeticOp::Rem
+        | ArithmeticOp::FusedAdd => quote! { #lhs #op_symbol #rhs },
+    }
+}
+
+/// Gets the `Operator` variant recorded in errors of an operation
+pub fn operator_variant(op: ArithmeticOp) -> TokenStream2 {
+    match op {
+        ArithmeticOp::Add => quote! { Operator::Add },
+        ArithmeticOp::Sub => quote! { Operator::Sub },
+        ArithmeticOp::Mul => quote! { Operator::Mul },
+        ArithmeticOp::Div => quote! { Operator::Div },
+        ArithmeticOp::Rem => quote! { Operator::Rem },
+        ArithmeticOp::RemEuclid => quote! { Operator::RemEuclid },
+        ArithmeticOp::DivEuclid => quote! { Operator::DivEuclid },
+        ArithmeticOp::FusedAdd => quote! { Operator::MulAdd },
+    }
+}
 
 /// Generates type-safe arithmetic operation implementations.
 ///
-/// This generates cross-type arithmetic operations with automatic output type inference.
-/// Safe operations return the result directly, while potentially failing operations return Option.
-pub fn generate_arithmetic_impls(config: &TypeConfig) -> TokenStream2 {
-    let ops = get_standard_arithmetic_ops();
-
-    // Generate implementations for constraint type arithmetic (existing)
-    let constraint_impls = generate_arithmetic_for_all_types(
-        config,
-        &ops,
-        |lhs_alias,
-         rhs_alias,
-         output_alias,
-         trait_ident,
-         method_ident,
-         op_symbol,
-         result,
-         op,
-         _| {
-            generate_arithmetic_impl(
-                &lhs_alias,
-                &rhs_alias,
-                &output_alias,
+/// Operators are implemented once for `Strict<F, L>` and resolve their output through
+/// the `AddOutput`, `SubOutput`, `MulOutput`, `DivOutput` and `RemOutput` traits of `L`.
+/// The Euclidean operations are implemented the same way through the `RemEuclid` and
+/// `DivEuclid` traits. Safe operations return the result directly, while potentially
+/// failing operations return `Result`. Every operator also accepts either operand by
+/// reference, and mixes F32 and F64 types by widening the F32 operand.
+pub fn generate_arithmetic_impls() -> TokenStream2 {
+    let ops = get_standard_arithmetic_ops()
+        .into_iter()
+        .chain(get_euclid_ops());
+    let impls = ops.map(|(op, trait_name, method_name, op_symbol)| {
+        let trait_ident = Ident::new(trait_name, Span::call_site());
+        let method_ident = Ident::new(method_name, Span::call_site());
+        let output_trait = format_ident!("{}Output", trait_name);
+        let constraint_impl = generate_constraint_arithmetic_impl(
+            &trait_ident,
+            &method_ident,
+            &output_trait,
+            &op_symbol,
+            op,
+        );
+        let constraint_ref_impls = generate_ref_forwarding_impls(
+            &trait_ident,
+            &method_ident,
+            &quote! { F: Float, L: Constraint, R: Constraint },
+            &quote! { Strict<F, L> },
+            &quote! { Strict<F, R> },
+        );
+        let mixed_width_impls =
+            generate_mixed_width_arithmetic_impls(&trait_ident, &method_ident, &output_trait);
+        let primitive_impls = ["f32", "f64"].iter().map(|float_type| {
+            let float_ident = Ident::new(float_type, Span::call_site());
+            let primitive_impl = generate_primitive_arithmetic_impl(
+                &float_ident,
                 &trait_ident,
                 &method_ident,
+                &output_trait,
                 &op_symbol,
-                result,
                 op,
-                false,
-            )
-        },
-    );
-
-    // Generate implementations for primitive type arithmetic (new)
-    let primitive_impls = generate_arithmetic_for_primitive_types(
-        config,
-        &ops,
-        |lhs_alias,
-         rhs_alias,
-         output_alias,
-         trait_ident,
-         method_ident,
-         op_symbol,
-         result,
-         op,
-         is_reversed| {
-            generate_arithmetic_impl(
-                &lhs_alias,
-                &rhs_alias,
-                &output_alias,
+            );
+            let rhs_ref_impls = generate_ref_forwarding_impls(
                 &trait_ident,
                 &method_ident,
-                &op_symbol,
-                result,
-                op,
-                is_reversed,
-            )
-        },
-    );
+                &quote! { L: Constraint },
+                &quote! { Strict<#float_ident, L> },
+                &quote! { #float_ident },
+            );
+            let lhs_ref_impls = generate_ref_forwarding_impls(
+                &trait_ident,
+                &method_ident,
+                &quote! { R: Constraint },
+                &quote! { #float_ident },
+                &quote! { Strict<#float_ident, R> },
+            );
+
+            quote! {
+                #primitive_impl
+                #rhs_ref_impls
+                #lhs_ref_impls
+            }
+        });
+
+        quote! {
+            #constraint_impl
+            #constraint_ref_impls
+            #mixed_width_impls
+            #(#primitive_impls)*
+        }
+    });
 
     quote! {
-        #constraint_impls
-        #primitive_impls
+        #(#impls)*
     }
 }
 
-/// Generates a single arithmetic operation implementation
-#[expect(clippy::too_many_lines)]
-#[allow(clippy::too_many_arguments)]
-fn generate_arithmetic_impl(
-    lhs_alias: &Ident,
-    rhs_alias: &Ident,
-    output_alias: &Ident,
+/// Generates the arithmetic between two constraint types of the same width
+fn generate_constraint_arithmetic_impl(
     trait_ident: &Ident,
     method_ident: &Ident,
+    output_trait: &Ident,
     op_symbol: &TokenStream2,
-    result: &ArithmeticResult,
     op: ArithmeticOp,
-    is_reversed: bool,
 ) -> TokenStream2 {
-    // Check if the operation involves a primitive type (f32 or f64)
-    let rhs_is_primitive = rhs_alias == "f32" || rhs_alias == "f64";
-    let lhs_is_primitive = lhs_alias == "f32" || lhs_alias == "f64";
+    let result = apply_op(op, op_symbol, &quote! { self.value }, &quote! { rhs.value });
+    let operator = operator_variant(op);
 
-    if result.is_safe && !rhs_is_primitive && !lhs_is_primitive {
-        // Safe operation between constraint types: return result directly
-        quote! {
-            impl #trait_ident<#rhs_alias> for #lhs_alias {
-                type Output = #output_alias;
-
-                fn #method_ident(self, rhs: #rhs_alias) -> Self::Output {
-                    let result = self.get() #op_symbol rhs.get();
-                    // SAFETY: The arithmetic configuration has proven at compile time that
-                    // for this specific combination of lhs_type and rhs_type, the result
-                    // is guaranteed to satisfy output_type's constraints. The validation
-                    // would be redundant since the type system already guarantees safety.
-                    unsafe { #output_alias::new_unchecked(result) }
-                }
+    quote! {
+        impl<F: Float, L: #output_trait<F, R>, R: Constraint> #trait_ident<Strict<F, R>> for Strict<F, L> {
+            type Output = <L as #output_trait<F, R>>::Output;
+
+            fn #method_ident(self, rhs: Strict<F, R>) -> Self::Output {
+                let result = #result;
+                // SAFETY: The arithmetic configuration has proven at compile time whether
+                // this combination of constraints always produces a valid result. Safe
+                // outputs skip validation, fallible outputs validate the result.
+                unsafe { <Self::Output as OpOutput<F>>::from_operation(Operation::new(#operator, self.value, rhs.value), result) }
             }
         }
-    } else if rhs_is_primitive || lhs_is_primitive {
-        // Operation with primitive type: must validate primitive value and return Result
-        if is_reversed {
-            // Primitive on left (e.g., f64 + FinF64)
-            let fin_type = if lhs_alias == "f32" {
-                quote! { FinF32 }
-            } else {
-                quote! { FinF64 }
-            };
-            // Division needs special handling to check for infinity
-            if op == ArithmeticOp::Div {
-                quote! {
-                    impl #trait_ident<#rhs_alias> for #lhs_alias {
-                        type Output = Result<#output_alias, FloatError>;
-
-                        fn #method_ident(self, rhs: #rhs_alias) -> Self::Output {
-                            // Validate that the primitive value is finite
-                            let lhs_fin = #fin_type::new(self).map_err(|_| FloatError::NaN)?;
-                            let result = lhs_fin.get() / rhs.get();
-                            // Division may produce infinity, uniformly return NaN error
-                            if !result.is_finite() {
-                                return Err(FloatError::NaN);
-                            }
-                            #output_alias::new(result)
-                        }
-                    }
-                }
-            } else {
-                quote! {
-                    impl #trait_ident<#rhs_alias> for #lhs_alias {
-                        type Output = Result<#output_alias, FloatError>;
-
-                        fn #method_ident(self, rhs: #rhs_alias) -> Self::Output {
-                            // Validate that the primitive value is finite
-                            let lhs_fin = #fin_type::new(self).map_err(|_| FloatError::NaN)?;
-                            let result = lhs_fin.get() #op_symbol rhs.get();
-                            #output_alias::new(result)
-                        }
-                    }
-                }
+    }
+}
+
+/// Generates the arithmetic between an F32 and an F64 constraint type
+///
+/// The F32 operand is widened with `as_f64_type`, so it must be `LosslessWidening`,
+/// and the output is the one inferred for the F64 versions of both types.
+fn generate_mixed_width_arithmetic_impls(
+    trait_ident: &Ident,
+    method_ident: &Ident,
+    output_trait: &Ident,
+) -> TokenStream2 {
+    let lhs_ref_impls = generate_ref_forwarding_impls(
+        trait_ident,
+        method_ident,
+        &quote! { L: Constraint, R: Constraint },
+        &quote! { Strict<f32, L> },
+        &quote! { Strict<f64, R> },
+    );
+    let rhs_ref_impls = generate_ref_forwarding_impls(
+        trait_ident,
+        method_ident,
+        &quote! { L: Constraint, R: Constraint },
+        &quote! { Strict<f64, L> },
+        &quote! { Strict<f32, R> },
+    );
+
+    quote! {
+        // F32 on left (e.g., FinF32 + FinF64)
+        impl<L: LosslessWidening + #output_trait<f64, R>, R: Constraint> #trait_ident<Strict<f64, R>> for Strict<f32, L> {
+            type Output = <L as #output_trait<f64, R>>::Output;
+
+            #[inline]
+            fn #method_ident(self, rhs: Strict<f64, R>) -> Self::Output {
+                #trait_ident::#method_ident(self.as_f64_type(), rhs)
             }
-        } else {
-            // Primitive on right (e.g., FinF64 + f64)
-            let fin_type = if rhs_alias == "f32" {
-                quote! { FinF32 }
-            } else {
-                quote! { FinF64 }
-            };
-            // Division needs special handling to check for infinity
-            if op == ArithmeticOp::Div {
-                quote! {
-                    impl #trait_ident<#rhs_alias> for #lhs_alias {
-                        type Output = Result<#output_alias, FloatError>;
-
-                        fn #method_ident(self, rhs: #rhs_alias) -> Self::Output {
-                            // Validate that the primitive value is finite
-                            let rhs_fin = #fin_type::new(rhs).map_err(|_| FloatError::NaN)?;
-                            let result = self.get() / rhs_fin.get();
-                            // Division may produce infinity, uniformly return NaN error
-                            if !result.is_finite() {
-                                return Err(FloatError::NaN);
-                            }
-                            #output_alias::new(result)
-                        }
-                    }
-                }
-            } else {
-                quote! {
-                    impl #trait_ident<#rhs_alias> for #lhs_alias {
-                        type Output = Result<#output_alias, FloatError>;
-
-                        fn #method_ident(self, rhs: #rhs_alias) -> Self::Output {
-                            // Validate that the primitive value is finite
-                            let rhs_fin = #fin_type::new(rhs).map_err(|_| FloatError::NaN)?;
-                            let result = self.get() #op_symbol rhs_fin.get();
-                            #output_alias::new(result)
-                        }
-                    }
-                }
+        }
+
+        // F32 on right (e.g., FinF64 + FinF32)
+        impl<L: #output_trait<f64, R>, R: LosslessWidening> #trait_ident<Strict<f32, R>> for Strict<f64, L> {
+            type Output = <L as #output_trait<f64, R>>::Output;
+
+            #[inline]
+            fn #method_ident(self, rhs: Strict<f32, R>) -> Self::Output {
+                #trait_ident::#method_ident(self, rhs.as_f64_type())
             }
         }
-    } else if op == ArithmeticOp::Div {
-        // Division between constraint types: result may be infinity, return NaN error
-        quote! {
-            impl #trait_ident<#rhs_alias> for #lhs_alias {
-                type Output = Result<#output_alias, FloatError>;
-
-                fn #method_ident(self, rhs: #rhs_alias) -> Self::Output {
-                    let result = self.get() / rhs.get();
-                    // Division may produce infinity, uniformly return NaN error
-                    if !result.is_finite() {
-                        return Err(FloatError::NaN);
-                    }
-                    #output_alias::new(result)
-                }
+
+        #lhs_ref_impls
+        #rhs_ref_impls
+    }
+}
+
+/// Generates the arithmetic between a constraint type and a primitive of its width
+///
+/// The primitive is treated as a `Fin` value, and the result type is determined by
+/// the same output traits. A NaN or infinite primitive is reported as
+/// `FloatError::InvalidOperand`.
+fn generate_primitive_arithmetic_impl(
+    float_type: &Ident,
+    trait_ident: &Ident,
+    method_ident: &Ident,
+    output_trait: &Ident,
+    op_symbol: &TokenStream2,
+    op: ArithmeticOp,
+) -> TokenStream2 {
+    let operator = operator_variant(op);
+    let rhs_result = apply_op(op, op_symbol, &quote! { self.value }, &quote! { rhs });
+    let lhs_result = apply_op(op, op_symbol, &quote! { self }, &quote! { rhs.value });
+
+    quote! {
+        // Primitive on right (e.g., FinF64 + f64)
+        impl<L: #output_trait<#float_type, Fin>> #trait_ident<#float_type> for Strict<#float_type, L> {
+            type Output = Result<
+                Strict<#float_type, <<L as #output_trait<#float_type, Fin>>::Output as OpOutput<#float_type>>::Output>,
+                FloatError,
+            >;
+
+            fn #method_ident(self, rhs: #float_type) -> Self::Output {
+                let result = #rhs_result;
+                // SAFETY: The output is a `Result`, which validates the primitive operand
+                // and the value
+                unsafe { <Self::Output as OpOutput<#float_type>>::from_operation(Operation::new(#operator, self.value, rhs), result) }
             }
         }
-    } else {
-        // Potentially failing operation between constraint types: return Result
-        quote! {
-            impl #trait_ident<#rhs_alias> for #lhs_alias {
-                type Output = Result<#output_alias, FloatError>;
 
-                fn #method_ident(self, rhs: #rhs_alias) -> Self::Output {
-                    let result = self.get() #op_symbol rhs.get();
-                    #output_alias::new(result)
-                }
+        // Primitive on left (e.g., f64 + FinF64)
+        impl<R: Constraint> #trait_ident<Strict<#float_type, R>> for #float_type
+        where
+            Fin: #output_trait<#float_type, R>,
+        {
+            type Output = Result<
+                Strict<#float_type, <<Fin as #output_trait<#float_type, R>>::Output as OpOutput<#float_type>>::Output>,
+                FloatError,
+            >;
+
+            fn #method_ident(self, rhs: Strict<#float_type, R>) -> Self::Output {
+                let result = #lhs_result;
+                // SAFETY: The output is a `Result`, which validates the primitive operand
+                // and the value
+                unsafe { <Self::Output as OpOutput<#float_type>>::from_operation(Operation::new(#operator, self, rhs.value), result) }
             }
         }
     }
 }
+
+/// Generates the output traits of every pair of constraint types.
+///
+/// For `Positive + Negative` at `f64`, generates code similar to:
+/// ```text
+/// impl AddOutput<f64, Negative> for Positive {
+///     type Output = Strict<f64, Fin>;
+/// }
+/// ```
+/// Operations that may fail resolve to `Result<Strict<f64, Output>, FloatError>`. The
+/// `FusedAddOutput` traits, from which `mul_add` is inferred, are generated the same way.
+pub fn generate_arithmetic_output_impls(config: &TypeConfig) -> TokenStream2 {
+    let ops: Vec<_> = get_standard_arithmetic_ops()
+        .into_iter()
+        .chain(get_euclid_ops())
+        .chain([(
+            ArithmeticOp::FusedAdd,
+            "FusedAdd",
+            "mul_add",
+            quote! { Float::mul_add },
+        )])
+        .collect();
+
+    generate_arithmetic_for_all_types(
+        config,
+        &ops,
+        |lhs, rhs, float_type, output_trait, result| {
+            let output_type = &result.output_type;
+            let output = if result.is_safe {
+                quote! { Strict<#float_type, #output_type> }
+            } else {
+                quote! { Result<Strict<#float_type, #output_type>, FloatError> }
+            };
+
+            quote! {
+                impl #output_trait<#float_type, #rhs> for #lhs {
+                    type Output = #output;
+                }
+            }
+        },
+    )
+}
@@ -0,0 +1,201 @@
+//! Bounded arithmetic operations module
+//!
+//! With the `interval-inference` feature, generates `add_bounded`, `sub_bounded`,
+//! `mul_bounded` and `div_bounded`. They are available for pairs of constraints whose
+//! results lie in a finite interval, and never fail: their output is inferred from that
+//! interval instead of the signs of the operands.
+
+use proc_macro2::{Ident, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+
+use crate::config::{
+    ArithmeticOp, ConstraintDef, FloatLimits, TypeConfig, get_standard_arithmetic_ops,
+    result_range, tightest_superset,
+};
+use crate::generator::find_float_constraint_def;
+
+/// Whether the bounded operations are generated, enabled by the `interval-inference`
+/// feature.
+pub const INTERVAL_INFERENCE: bool = cfg!(feature = "interval-inference");
+
+/// The operations with a bounded variant: (operator, output trait, method, operator symbol)
+fn bounded_ops() -> impl Iterator<Item = (ArithmeticOp, Ident, Ident, TokenStream2)> {
+    get_standard_arithmetic_ops()
+        .into_iter()
+        .filter(|(op, ..)| {
+            matches!(
+                op,
+                ArithmeticOp::Add | ArithmeticOp::Sub | ArithmeticOp::Mul | ArithmeticOp::Div
+            )
+        })
+        .map(|(op, trait_name, method_name, op_symbol)| {
+            (
+                op,
+                format_ident!("Bounded{}Output", trait_name),
+                format_ident!("{}_bounded", method_name),
+                op_symbol,
+            )
+        })
+}
+
+/// Doc examples of `add_bounded`, `sub_bounded`, `mul_bounded` and `div_bounded`
+const BOUNDED_EXAMPLES: [&str; 4] = [
+    "let a = NormalizedF64::new(0.75)?;
+let b = NormalizedF64::new(1.0)?;
+
+// [0, 1] + [0, 1] = [0, 2]
+let sum: NonNegativeF64 = a.add_bounded(b);
+assert_eq!(sum.get(), 1.75);",
+    "let a = SymmetricF64::new(-1.0)?;
+let b = NormalizedF64::new(0.5)?;
+
+// [-1, 1] - [0, 1] = [-2, 1]
+let difference: FinF64 = a.sub_bounded(b);
+assert_eq!(difference.get(), -1.5);",
+    "let angle = PiBoundedF64::new(-3.0)?;
+let factor = NormalizedF64::new(0.5)?;
+
+// [-PI, PI] * [0, 1] = [-PI, PI]
+let scaled: PiBoundedF64 = angle.mul_bounded(factor);
+assert_eq!(scaled.get(), -1.5);",
+    "define_constraints!([(Half, [\">= 0.5\", \"<= 1.0\"])]);
+
+let a = HalfF64::new(1.0)?;
+let b = HalfF64::new(0.5)?;
+
+// [0.5, 1] / [0.5, 1] = [0.5, 2]
+let quotient: PositiveF64 = a.div_bounded(b);
+assert_eq!(quotient.get(), 2.0);",
+];
+
+/// Infers the output of a bounded operation.
+///
+/// The output is the tightest type containing every result among the operand types and
+/// the types unbounded on at least one side, such as `NonNegative` for the sum of two
+/// `Normalized` values. Other bounded types are never picked, since a range that only
+/// happens to fit them (e.g. `[0, 2]` in `TauBounded`) would give them a meaning the
+/// result doesn't have. Returns `None` when some results are not finite.
+fn infer_bounded_output(
+    op: ArithmeticOp,
+    lhs: &ConstraintDef,
+    rhs: &ConstraintDef,
+    constraints: &[ConstraintDef],
+    limits: FloatLimits,
+) -> Option<Ident> {
+    let range = result_range(op, lhs, rhs, limits);
+    if !range.is_bounded() {
+        return None;
+    }
+
+    let candidates: Vec<_> = constraints
+        .iter()
+        .filter(|c| c.name == lhs.name || c.name == rhs.name || !c.bounds.is_bounded())
+        .cloned()
+        .collect();
+    tightest_superset(&range, false, &candidates, &[&lhs.name, &rhs.name]).map(|c| c.name.clone())
+}
+
+/// Generates the bounded output traits and methods
+pub fn generate_bounded_impls() -> TokenStream2 {
+    if !INTERVAL_INFERENCE {
+        return quote! {};
+    }
+
+    let (traits, methods): (Vec<_>, Vec<_>) = bounded_ops()
+        .zip(BOUNDED_EXAMPLES)
+        .map(|((_, output_trait, method, op_symbol), example)| {
+            let trait_doc = format!(
+                "Output constraint of [`Strict::{method}`] with a `Strict<F, Rhs>` operand"
+            );
+            let method_doc = format!("Computes `self {op_symbol} rhs`, which never fails");
+            let example = format!(
+                "```\nuse strict_num_extended::*;\n\n{example}\n# Ok::<(), FloatError>(())\n```"
+            );
+
+            let output_trait_def = quote! {
+                #[doc = #trait_doc]
+                ///
+                /// Only implemented when every result is finite. The output is the
+                /// tightest operand type or type unbounded on one side that contains
+                /// them all.
+                pub trait #output_trait<F: Float, Rhs: Constraint>: Constraint {
+                    /// Constraint of the result
+                    type Output: Constraint;
+                }
+            };
+            let method_def = quote! {
+                #[doc = #method_doc]
+                ///
+                /// Available when the results of the operands' constraints lie in a
+                /// finite interval. The output type is inferred from that interval, so
+                /// it may differ from the one of the operator.
+                ///
+                /// # Examples
+                ///
+                #[doc = #example]
+                #[inline]
+                #[must_use]
+                pub fn #method<R: Constraint>(
+                    self,
+                    rhs: Strict<F, R>,
+                ) -> Strict<F, <C as #output_trait<F, R>>::Output>
+                where
+                    C: #output_trait<F, R>,
+                {
+                    // SAFETY: the output trait is only implemented when the interval of
+                    // results is finite and admitted by its output constraint
+                    unsafe { Strict::new_unchecked(self.value #op_symbol rhs.value) }
+                }
+            };
+            (output_trait_def, method_def)
+        })
+        .unzip();
+
+    quote! {
+        #(#traits)*
+
+        impl<F: Float, C: Constraint> Strict<F, C> {
+            #(#methods)*
+        }
+    }
+}
+
+/// Generates the bounded output implementations of every pair of constraint types
+pub fn generate_bounded_output_impls(config: &TypeConfig) -> TokenStream2 {
+    if !INTERVAL_INFERENCE {
+        return quote! {};
+    }
+
+    let ops: Vec<_> = bounded_ops().collect();
+    let mut impls = Vec::new();
+
+    for lhs_type in &config.constraint_types {
+        for rhs_type in &config.constraint_types {
+            if !lhs_type.is_local && !rhs_type.is_local {
+                continue;
+            }
+            for float_type in &lhs_type.float_types {
+                let constraints = config.constraints_for(float_type);
+                let limits = FloatLimits::of(float_type);
+                let lhs = find_float_constraint_def(config, &lhs_type.constraint_name, float_type);
+                let rhs = find_float_constraint_def(config, &rhs_type.constraint_name, float_type);
+                for (op, output_trait, ..) in &ops {
+                    let Some(output) = infer_bounded_output(*op, lhs, rhs, constraints, limits)
+                    else {
+                        continue;
+                    };
+                    let (lhs_name, rhs_name) = (&lhs.name, &rhs.name);
+                    impls.push(quote! {
+                        impl #output_trait<#float_type, #rhs_name> for #lhs_name {
+                            type Output = #output;
+                        }
+                    });
+                }
+            }
+        }
+    }
+
+    quote! {
+        #(#impls)*
+    }
+}
@@ -0,0 +1,62 @@
+//! Fused arithmetic operations module
+
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+
+/// Generates `mul_add`.
+///
+/// The output is resolved through `MulAddOutput`, which composes the inference of `*`
+/// with that of the addition of the unrounded product. The raw value is computed with
+/// `Float::mul_add`, so it matches hardware FMA and is validated at most once.
+pub fn generate_mul_add_impls() -> TokenStream2 {
+    quote! {
+        impl<F: Float, L: Constraint> Strict<F, L> {
+            /// Computes `(self * a) + b` with a single rounding
+            ///
+            /// The output type composes the inference of `self * a` and of adding `b` to
+            /// the product. It is a `Result` only when the combination can fail: bounded
+            /// operands whose every fused result is finite need no validation.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use strict_num_extended::*;
+            ///
+            /// let a = NormalizedF64::new(0.5)?;
+            /// let b = NormalizedF64::new(0.5)?;
+            /// let c = NormalizedF64::new(1.0)?;
+            ///
+            /// // Normalized * Normalized + Normalized is bounded, so it cannot fail
+            /// let fused: NonNegativeF64 = a.mul_add(b, c);
+            /// assert_eq!(fused.get(), 1.25);
+            ///
+            /// // Unbounded operands may overflow
+            /// let max = PositiveF64::new(f64::MAX)?;
+            /// let overflow: Result<PositiveF64, FloatError> = max.mul_add(max, max);
+            /// assert_eq!(overflow, Err(FloatError::Overflow));
+            /// # Ok::<(), FloatError>(())
+            /// ```
+            #[inline]
+            #[must_use]
+            pub fn mul_add<A: Constraint, B: Constraint>(
+                self,
+                a: Strict<F, A>,
+                b: Strict<F, B>,
+            ) -> <L as MulAddOutput<F, A, B>>::Output
+            where
+                L: MulAddOutput<F, A, B>,
+            {
+                let result = self.value.mul_add(a.value, b.value);
+                // SAFETY: The arithmetic configuration has proven at compile time whether
+                // both steps always produce a valid result. Safe outputs skip validation,
+                // fallible outputs validate the result.
+                unsafe {
+                    <<L as MulAddOutput<F, A, B>>::Output as OpOutput<F>>::from_operation(
+                        Operation::mul_add(self.value, a.value, b.value),
+                        result,
+                    )
+                }
+            }
+        }
+    }
+}
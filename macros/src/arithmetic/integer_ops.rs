@@ -0,0 +1,264 @@
+//! Integer operand arithmetic module
+//!
+//! Generates the operators between constraint types and integer primitives, including
+//! the `NonZero` integers. An integer acts as the constraint its sign implies, so the
+//! output is inferred by the same output traits as for two constraint types.
+
+use proc_macro2::{Ident, Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+
+use super::{apply_op, generate_ref_forwarding_impls, operator_variant};
+use crate::config::{
+    ArithmeticOp, Bounds, Sign, TypeConfig, get_euclid_ops, get_standard_arithmetic_ops,
+};
+
+/// Integer primitives and their width in bits; pointer-sized ones count as 64 bits
+const INTEGER_TYPES: [(&str, u32); 10] = [
+    ("i8", 8),
+    ("i16", 16),
+    ("i32", 32),
+    ("i64", 64),
+    ("isize", 64),
+    ("u8", 8),
+    ("u16", 16),
+    ("u32", 32),
+    ("u64", 64),
+    ("usize", 64),
+];
+
+/// Float types and the number of significant bits of their mantissa
+const FLOAT_TYPES: [(&str, u32); 2] = [("f32", 24), ("f64", 53)];
+
+/// Gets the `NonZero` type of an integer primitive, e.g. `NonZeroU32` for `u32`
+fn non_zero_ident(name: &str) -> Ident {
+    let (first, rest) = name.split_at(1);
+    format_ident!("NonZero{}{}", first.to_uppercase(), rest)
+}
+
+/// Integer operand type, as seen by the generated operators
+struct IntegerOperand {
+    /// Operand type, e.g. `u32` or `core::num::NonZeroU32`
+    ty: TokenStream2,
+    /// Constraint the operand acts as
+    constraint: Ident,
+    /// Expression converting `value` to the primitive integer
+    primitive: TokenStream2,
+    /// Whether every value converts exactly to the float type
+    exact: bool,
+}
+
+/// Finds the constraint an integer acts as: `[0, +∞)` or `(0, +∞)` for unsigned
+/// integers, and the finite or non-zero values for signed ones
+fn find_integer_constraint(config: &TypeConfig, signed: bool, non_zero: bool) -> Option<Ident> {
+    let sign = if signed { Sign::Any } else { Sign::Positive };
+    let bounds = if signed {
+        Bounds::UNBOUNDED
+    } else {
+        Bounds {
+            lower_inclusive: !non_zero,
+            ..Bounds::closed(Some(0.0), None)
+        }
+    };
+    config.find_type_by_constraints(sign, &bounds, non_zero)
+}
+
+impl IntegerOperand {
+    /// Lists the plain and `NonZero` operands of an integer primitive whose constraint
+    /// is defined by the config
+    fn variants(config: &TypeConfig, name: &str, bits: u32, mantissa_bits: u32) -> Vec<Self> {
+        let signed = name.starts_with('i');
+        let magnitude_bits = if signed { bits - 1 } else { bits };
+        let exact = magnitude_bits <= mantissa_bits;
+        let ident = Ident::new(name, Span::call_site());
+        let non_zero = non_zero_ident(name);
+
+        let plain = find_integer_constraint(config, signed, false).map(|constraint| Self {
+            ty: quote! { #ident },
+            constraint,
+            primitive: quote! { value },
+            exact,
+        });
+        let non_zero = find_integer_constraint(config, signed, true).map(|constraint| Self {
+            ty: quote! { core::num::#non_zero },
+            constraint,
+            primitive: quote! { value.get() },
+            exact,
+        });
+        plain.into_iter().chain(non_zero).collect()
+    }
+
+    /// Generates the conversion of `value` to `float_type`, returning early with a
+    /// `PrecisionLoss` error for inexact conversions
+    fn conversion(&self, float_type: &Ident) -> TokenStream2 {
+        let primitive = &self.primitive;
+        if self.exact {
+            quote! { let operand = #float_type::from(#primitive); }
+        } else {
+            quote! {
+                let operand = #primitive as #float_type;
+                // Both sides fit i128 exactly, including a value rounded up to 2^64
+                if operand as i128 != #primitive as i128 {
+                    return Err(FloatError::PrecisionLoss);
+                }
+            }
+        }
+    }
+}
+
+/// Generates the operators between constraint types and integer primitives.
+///
+/// Unsigned integers act as `NonNegative`, signed ones as `Fin`, and their `NonZero`
+/// counterparts as `Positive` and `NonZero`, when the config defines them. Integers
+/// whose every value is exactly representable keep the inferred output; wider ones
+/// return a `Result` that reports `FloatError::PrecisionLoss` for an inexact operand.
+/// Operands are accepted by reference.
+pub fn generate_integer_arithmetic_impls(config: &TypeConfig) -> TokenStream2 {
+    let ops: Vec<_> = get_standard_arithmetic_ops()
+        .into_iter()
+        .chain(get_euclid_ops())
+        .collect();
+
+    let impls = FLOAT_TYPES.iter().flat_map(|&(float_type, mantissa_bits)| {
+        let float_ident = Ident::new(float_type, Span::call_site());
+        let ops = &ops;
+        INTEGER_TYPES.iter().flat_map(move |&(name, bits)| {
+            let float_ident = float_ident.clone();
+            IntegerOperand::variants(config, name, bits, mantissa_bits)
+                .into_iter()
+                .map(move |operand| {
+                    let op_impls = ops.iter().map(|(op, trait_name, method_name, op_symbol)| {
+                        generate_integer_op_impls(
+                            &float_ident,
+                            &operand,
+                            *op,
+                            trait_name,
+                            method_name,
+                            op_symbol,
+                        )
+                    });
+                    quote! {
+                        #(#op_impls)*
+                    }
+                })
+        })
+    });
+
+    quote! {
+        #(#impls)*
+    }
+}
+
+/// Generates one operator between a float width and an integer operand, on both sides
+fn generate_integer_op_impls(
+    float_type: &Ident,
+    operand: &IntegerOperand,
+    op: ArithmeticOp,
+    trait_name: &str,
+    method_name: &str,
+    op_symbol: &TokenStream2,
+) -> TokenStream2 {
+    let trait_ident = Ident::new(trait_name, Span::call_site());
+    let method_ident = Ident::new(method_name, Span::call_site());
+    let output_trait = format_ident!("{}Output", trait_name);
+    let operator = operator_variant(op);
+    let integer = &operand.ty;
+    let constraint = &operand.constraint;
+
+    let rhs_output = quote! { <L as #output_trait<#float_type, #constraint>>::Output };
+    let lhs_output = quote! { <#constraint as #output_trait<#float_type, R>>::Output };
+    let rhs_operation = quote! { Operation::new(#operator, self.value, operand) };
+    let lhs_operation = quote! { Operation::new(#operator, operand, rhs.value) };
+    let rhs_result = apply_op(op, op_symbol, &quote! { self.value }, &quote! { operand });
+    let lhs_result = apply_op(op, op_symbol, &quote! { operand }, &quote! { rhs.value });
+
+    let (rhs_type, rhs_body) = integer_op_body(
+        float_type,
+        operand,
+        &rhs_output,
+        &quote! { rhs },
+        &rhs_operation,
+        &rhs_result,
+    );
+    let (lhs_type, lhs_body) = integer_op_body(
+        float_type,
+        operand,
+        &lhs_output,
+        &quote! { self },
+        &lhs_operation,
+        &lhs_result,
+    );
+
+    let rhs_ref_impls = generate_ref_forwarding_impls(
+        &trait_ident,
+        &method_ident,
+        &quote! { L: Constraint },
+        &quote! { Strict<#float_type, L> },
+        integer,
+    );
+    let lhs_ref_impls = generate_ref_forwarding_impls(
+        &trait_ident,
+        &method_ident,
+        &quote! { R: Constraint },
+        integer,
+        &quote! { Strict<#float_type, R> },
+    );
+
+    quote! {
+        // Integer on right (e.g., FinF64 * u32)
+        impl<L: #output_trait<#float_type, #constraint>> #trait_ident<#integer> for Strict<#float_type, L> {
+            type Output = #rhs_type;
+
+            fn #method_ident(self, rhs: #integer) -> Self::Output {
+                #rhs_body
+            }
+        }
+
+        // Integer on left (e.g., u32 * FinF64)
+        impl<R: Constraint> #trait_ident<Strict<#float_type, R>> for #integer
+        where
+            #constraint: #output_trait<#float_type, R>,
+        {
+            type Output = #lhs_type;
+
+            fn #method_ident(self, rhs: Strict<#float_type, R>) -> Self::Output {
+                #lhs_body
+            }
+        }
+
+        #rhs_ref_impls
+        #lhs_ref_impls
+    }
+}
+
+/// Generates the output type and the body of an integer operator
+///
+/// `integer` is the expression of the integer operand, and `output` the output inferred
+/// for the constraint the integer acts as.
+fn integer_op_body(
+    float_type: &Ident,
+    operand: &IntegerOperand,
+    output: &TokenStream2,
+    integer: &TokenStream2,
+    operation: &TokenStream2,
+    result: &TokenStream2,
+) -> (TokenStream2, TokenStream2) {
+    let output_constraint = quote! { <#output as OpOutput<#float_type>>::Output };
+    let conversion = operand.conversion(float_type);
+    let body = quote! {
+        let value = #integer;
+        #conversion
+        let result = #result;
+        // SAFETY: The operand is an exact conversion of the integer, which satisfies
+        // the constraint it acts as, so the output traits apply as for constraint types
+        unsafe { <Self::Output as OpOutput<#float_type>>::from_operation(#operation, result) }
+    };
+
+    if operand.exact {
+        (output.clone(), body)
+    } else {
+        (
+            quote! { Result<Strict<#float_type, #output_constraint>, FloatError> },
+            body,
+        )
+    }
+}
@@ -4,15 +4,31 @@ use proc_macro2::TokenStream as TokenStream2;
 use quote::quote;
 
 use crate::config::TypeConfig;
-use crate::generator::{find_constraint_def, make_type_alias};
+use crate::generator::find_constraint_def;
 
-/// Generates unary negation operation implementations.
-pub fn generate_neg_impls(config: &TypeConfig) -> TokenStream2 {
-    let mut impls = Vec::new();
+/// Generates the unary negation operation for every constraint with a `NegOutput`.
+pub fn generate_neg_impls() -> TokenStream2 {
+    quote! {
+        impl<F: Float, C: NegOutput> Neg for Strict<F, C> {
+            type Output = Strict<F, <C as NegOutput>::Output>;
+
+            fn neg(self) -> Self::Output {
+                let result = -self.value;
+                // SAFETY: The negation constraint was computed at compile time by
+                // negating the source constraint's conditions and finding a matching
+                // constraint. Since it was found through condition matching, the result
+                // is mathematically guaranteed to satisfy the target constraint.
+                unsafe { Strict::new_unchecked(result) }
+            }
+        }
+    }
+}
 
-    for type_def in &config.constraint_types {
-        let type_name = &type_def.type_name;
+/// Generates the `NegOutput` implementations of all constraint types.
+pub fn generate_neg_output_impls(config: &TypeConfig) -> TokenStream2 {
+    let mut impls = Vec::new();
 
+    for type_def in config.local_types() {
         // Use helper function to find constraint definition
         let constraint_def = find_constraint_def(config, &type_def.constraint_name);
 
@@ -21,26 +37,12 @@ pub fn generate_neg_impls(config: &TypeConfig) -> TokenStream2 {
             continue;
         };
 
-        for float_type in &type_def.float_types {
-            let type_alias = make_type_alias(type_name, float_type);
-            let neg_type_alias = make_type_alias(neg_constraint_name, float_type);
-
-            impls.push(quote! {
-                impl Neg for #type_alias {
-                    type Output = #neg_type_alias;
-
-                    fn neg(self) -> Self::Output {
-                        let result = -self.get();
-                        // SAFETY: The negation constraint was computed at compile time by
-                        // negating the source constraint's conditions and finding a matching
-                        // constraint. Since neg_constraint_name was found through condition
-                        // matching, the result is mathematically guaranteed to satisfy the
-                        // target constraint. The runtime validation would be redundant.
-                        unsafe { #neg_type_alias::new_unchecked(result) }
-                    }
-                }
-            });
-        }
+        let constraint_name = &type_def.constraint_name;
+        impls.push(quote! {
+            impl NegOutput for #constraint_name {
+                type Output = #neg_constraint_name;
+            }
+        });
     }
 
     quote! {
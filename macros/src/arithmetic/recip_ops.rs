@@ -0,0 +1,94 @@
+//! Reciprocal operation module
+
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+
+use crate::config::TypeConfig;
+use crate::generator::for_all_constraint_float_types;
+use crate::unary_ops::infer_recip_output;
+
+/// Generates the `RecipOutput` trait and `recip`.
+pub fn generate_recip_impls() -> TokenStream2 {
+    quote! {
+        /// Output of [`Strict::recip`] at width `F`
+        ///
+        /// The reciprocal keeps the sign of the value and is never zero. It is a
+        /// `Result` unless the magnitude is bounded away from zero, since `1 / 0` and
+        /// the reciprocals of subnormal values are infinite.
+        pub trait RecipOutput<F: Float>: Constraint {
+            /// `Strict<F, _>` when the reciprocal cannot fail, otherwise a `Result`
+            type Output: OpOutput<F>;
+        }
+
+        impl<F: Float, C: RecipOutput<F>> Strict<F, C> {
+            /// Computes the reciprocal, `1 / self`
+            ///
+            /// The output type is inferred from the bounds of `C`: the sign is kept and
+            /// zero is excluded, so `NonZero` stays `NonZero` and `[1, ∞)` maps into
+            /// `(0, 1]`.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use strict_num_extended::*;
+            ///
+            /// let half: Result<PositiveF64, FloatError> = PositiveF64::new(2.0)?.recip();
+            /// assert_eq!(half?.get(), 0.5);
+            ///
+            /// let flipped: Result<NonZeroF64, FloatError> = NonZeroF64::new(-4.0)?.recip();
+            /// assert_eq!(flipped?.get(), -0.25);
+            ///
+            /// assert_eq!(FinF64::new(0.0)?.recip(), Err(FloatError::DivisionByZero));
+            /// # Ok::<(), FloatError>(())
+            /// ```
+            ///
+            /// # Errors
+            ///
+            /// Fallible outputs return `Err(FloatError::DivisionByZero)` for zero and
+            /// `Err(FloatError::Overflow)` if the reciprocal is infinite.
+            #[inline]
+            #[must_use = "Return value may contain an error and should not be ignored"]
+            pub fn recip(self) -> <C as RecipOutput<F>>::Output {
+                let one = F::from_f64(1.0);
+                let result = one / self.value;
+                // SAFETY: The reciprocal configuration has proven at compile time whether
+                // every reciprocal is finite and admitted by the output. Safe outputs
+                // skip validation, fallible outputs validate the result.
+                unsafe {
+                    <<C as RecipOutput<F>>::Output as OpOutput<F>>::from_operation(
+                        Operation::new(Operator::Div, one, self.value),
+                        result,
+                    )
+                }
+            }
+        }
+    }
+}
+
+/// Generates the `RecipOutput` implementations of all constraint types.
+pub fn generate_recip_output_impls(config: &TypeConfig) -> TokenStream2 {
+    let impls = for_all_constraint_float_types(config, |_, float_type, constraint_def| {
+        let constraint_name = &constraint_def.name;
+        let result = infer_recip_output(
+            constraint_def,
+            config.constraints_for(float_type),
+            float_type,
+        );
+        let output_type = &result.output_type;
+        let output = if result.is_safe {
+            quote! { Strict<#float_type, #output_type> }
+        } else {
+            quote! { Result<Strict<#float_type, #output_type>, FloatError> }
+        };
+
+        quote! {
+            impl RecipOutput<#float_type> for #constraint_name {
+                type Output = #output;
+            }
+        }
+    });
+
+    quote! {
+        #(#impls)*
+    }
+}
@@ -0,0 +1,55 @@
+//! By-reference arithmetic operations module
+
+use proc_macro2::{Ident, TokenStream as TokenStream2};
+use quote::quote;
+
+/// Generates `&Lhs op Rhs`, `Lhs op &Rhs` and `&Lhs op &Rhs` for an operand pair.
+///
+/// Each impl copies its operands and forwards to the by-value impl `Lhs op Rhs`, so the
+/// output type and validation are exactly those of the by-value operation. `generics`
+/// lists the type parameters used by `lhs` and `rhs`.
+pub fn generate_ref_forwarding_impls(
+    trait_ident: &Ident,
+    method_ident: &Ident,
+    generics: &TokenStream2,
+    lhs: &TokenStream2,
+    rhs: &TokenStream2,
+) -> TokenStream2 {
+    quote! {
+        impl<'a, #generics> #trait_ident<#rhs> for &'a #lhs
+        where
+            #lhs: #trait_ident<#rhs>,
+        {
+            type Output = <#lhs as #trait_ident<#rhs>>::Output;
+
+            #[inline]
+            fn #method_ident(self, rhs: #rhs) -> Self::Output {
+                #trait_ident::#method_ident(*self, rhs)
+            }
+        }
+
+        impl<'a, #generics> #trait_ident<&'a #rhs> for #lhs
+        where
+            #lhs: #trait_ident<#rhs>,
+        {
+            type Output = <#lhs as #trait_ident<#rhs>>::Output;
+
+            #[inline]
+            fn #method_ident(self, rhs: &'a #rhs) -> Self::Output {
+                #trait_ident::#method_ident(self, *rhs)
+            }
+        }
+
+        impl<'a, 'b, #generics> #trait_ident<&'a #rhs> for &'b #lhs
+        where
+            #lhs: #trait_ident<#rhs>,
+        {
+            type Output = <#lhs as #trait_ident<#rhs>>::Output;
+
+            #[inline]
+            fn #method_ident(self, rhs: &'a #rhs) -> Self::Output {
+                #trait_ident::#method_ident(*self, *rhs)
+            }
+        }
+    }
+}
@@ -0,0 +1,58 @@
+//! Saturating arithmetic operations module
+
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+
+use crate::config::{ArithmeticOp, get_standard_arithmetic_ops};
+
+/// Generates `saturating_add`, `saturating_sub`, `saturating_mul` and `saturating_div`.
+///
+/// Each method returns the constraint that inference picks for the operator, and
+/// clamps the raw result to its bounds instead of returning an error.
+pub fn generate_saturating_impls() -> TokenStream2 {
+    let methods = get_standard_arithmetic_ops()
+        .into_iter()
+        .filter(|(op, ..)| {
+            matches!(
+                op,
+                ArithmeticOp::Add | ArithmeticOp::Sub | ArithmeticOp::Mul | ArithmeticOp::Div
+            )
+        })
+        .map(|(_, trait_name, method_name, op_symbol)| {
+            let output_trait = format_ident!("{}Output", trait_name);
+            let saturating_method = format_ident!("saturating_{}", method_name);
+            let doc = format!(
+                "Computes `self {op_symbol} rhs`, clamping the result to the bounds of the inferred output type"
+            );
+
+            quote! {
+                #[doc = #doc]
+                ///
+                /// Overflow saturates to the nearest bound (or `MAX`/`MIN` when unbounded),
+                /// and a result that is zero where zero is excluded becomes `MIN_POSITIVE`
+                /// with the matching sign. `0 / 0` saturates as zero.
+                #[inline]
+                #[must_use]
+                pub fn #saturating_method<R: Constraint>(
+                    self,
+                    rhs: Strict<F, R>,
+                ) -> Strict<F, <<C as #output_trait<F, R>>::Output as OpOutput<F>>::Output>
+                where
+                    C: #output_trait<F, R>,
+                {
+                    let result = (self.value #op_symbol rhs.value).saturate::<
+                        <<C as #output_trait<F, R>>::Output as OpOutput<F>>::Output,
+                    >();
+                    // SAFETY: `saturate` always returns a value within the bounds of the
+                    // output constraint at this width
+                    unsafe { Strict::new_unchecked(result) }
+                }
+            }
+        });
+
+    quote! {
+        impl<F: Float, C: Constraint> Strict<F, C> {
+            #(#methods)*
+        }
+    }
+}
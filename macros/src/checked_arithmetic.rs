@@ -0,0 +1,447 @@
+//! `Checked` wrapper generation module
+//!
+//! Generates `Checked<T>`, a crate-owned carrier of either a value or the first
+//! error of a computation, kept as a `DetailedError`. Unlike `Result`, it is not restricted by the orphan
+//! rule, so it supports `Checked op Checked`, negation and unary math, which lets long
+//! formulas be written without handling errors after every step.
+
+use proc_macro2::{Ident, Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+
+use crate::arithmetic::{apply_op, operator_variant};
+use crate::config::{ArithmeticOp, TypeConfig, get_euclid_ops, get_standard_arithmetic_ops};
+use crate::unary_ops::infer_sin_cos_output_type;
+
+/// Generates the `Checked` struct and its combinators
+fn generate_checked_struct() -> TokenStream2 {
+    quote! {
+        /// A value of type `T`, or the first error met while computing it
+        ///
+        /// Every operator accepts `Checked` operands, [`Strict`] values and primitives
+        /// on either side, and produces the same output type as the underlying
+        /// operation. Errors propagate without further checks until the formula is
+        /// resolved with [`into_result`](Self::into_result), or with
+        /// [`into_detailed_result`](Self::into_detailed_result) to learn which value
+        /// and operation failed.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use strict_num_extended::*;
+        ///
+        /// let a = PositiveF64::new(3.0)?;
+        /// let b = PositiveF64::new(4.0)?;
+        /// let c = NegativeF64::new(-2.0)?;
+        ///
+        /// // (a * b - c) / a, checked once at the end
+        /// let result: Result<PositiveF64, FloatError> = ((Checked::new(a) * b - c) / a).into_result();
+        /// assert_eq!(result?.get(), 14.0 / 3.0);
+        ///
+        /// // The first error is kept
+        /// let failed = Checked::new(a) / FinF64::new(0.0)? + b;
+        /// assert_eq!(failed.error(), Some(FloatError::DivisionByZero));
+        /// assert_eq!(
+        ///     failed.detailed_error().unwrap().to_string(),
+        ///     "NonZeroF64: 3.0 / 0.0 divides by zero"
+        /// );
+        /// # Ok::<(), FloatError>(())
+        /// ```
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        #[must_use]
+        pub struct Checked<T>(Result<T, DetailedError>);
+
+        impl<T> Checked<T> {
+            /// Wraps a valid value
+            #[inline]
+            pub const fn new(value: T) -> Self {
+                Self(Ok(value))
+            }
+
+            /// Wraps the result of a computation
+            #[inline]
+            pub fn from_result(result: Result<T, FloatError>) -> Self {
+                Self(result.map_err(DetailedError::from))
+            }
+
+            /// Resolves the computation
+            ///
+            /// # Errors
+            ///
+            /// Returns the first error met while computing the value.
+            #[inline]
+            pub fn into_result(self) -> Result<T, FloatError> {
+                self.0.map_err(FloatError::from)
+            }
+
+            /// Resolves the computation, keeping the context of an error
+            ///
+            /// # Errors
+            ///
+            /// Returns the first error met while computing the value, with the value,
+            /// the type and the operation that caused it.
+            #[inline]
+            pub fn into_detailed_result(self) -> Result<T, DetailedError> {
+                self.0
+            }
+
+            /// Returns `true` if the computation has succeeded so far
+            #[inline]
+            #[must_use]
+            pub const fn is_ok(&self) -> bool {
+                self.0.is_ok()
+            }
+
+            /// Returns `true` if the computation has failed
+            #[inline]
+            #[must_use]
+            pub const fn is_err(&self) -> bool {
+                self.0.is_err()
+            }
+
+            /// Returns the first error met, if any
+            #[inline]
+            #[must_use]
+            pub fn error(&self) -> Option<FloatError> {
+                self.0.as_ref().err().map(DetailedError::kind)
+            }
+
+            /// Returns the first error met, if any, with its context
+            #[inline]
+            #[must_use]
+            pub fn detailed_error(&self) -> Option<DetailedError> {
+                self.0.as_ref().err().copied()
+            }
+
+            /// Applies an infallible step to the value
+            #[inline]
+            pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Checked<U> {
+                Checked(self.0.map(f))
+            }
+
+            /// Applies a fallible step to the value
+            #[inline]
+            pub fn and_then<U>(self, f: impl FnOnce(T) -> Result<U, FloatError>) -> Checked<U> {
+                Checked(self.0.and_then(|value| f(value).map_err(DetailedError::from)))
+            }
+
+            /// Applies a fallible step that records the context of its error
+            #[inline]
+            fn and_then_detailed<U>(
+                self,
+                f: impl FnOnce(T) -> Result<U, DetailedError>,
+            ) -> Checked<U> {
+                Checked(self.0.and_then(f))
+            }
+        }
+
+        impl<F: Float, C: Constraint> From<Strict<F, C>> for Checked<Strict<F, C>> {
+            #[inline]
+            fn from(value: Strict<F, C>) -> Self {
+                Self::new(value)
+            }
+        }
+
+        impl<F: Float, C: Constraint> From<Result<Strict<F, C>, FloatError>> for Checked<Strict<F, C>> {
+            #[inline]
+            fn from(result: Result<Strict<F, C>, FloatError>) -> Self {
+                Self::from_result(result)
+            }
+        }
+
+        impl<T> From<Checked<T>> for Result<T, FloatError> {
+            #[inline]
+            fn from(checked: Checked<T>) -> Self {
+                checked.into_result()
+            }
+        }
+
+        impl<T: Neg> Neg for Checked<T> {
+            type Output = Checked<T::Output>;
+
+            #[inline]
+            fn neg(self) -> Self::Output {
+                self.map(Neg::neg)
+            }
+        }
+    }
+}
+
+/// Generates the unary math methods of `Checked<Strict<F, C>>`
+fn generate_checked_unary_methods(config: &TypeConfig) -> TokenStream2 {
+    let sin_cos_output = infer_sin_cos_output_type(config);
+
+    quote! {
+        impl<F: Float, C: Constraint> Checked<Strict<F, C>> {
+            /// Computes the absolute value, as [`Strict::abs`]
+            #[inline]
+            pub fn abs(self) -> Checked<Strict<F, C::Abs>> {
+                self.map(Strict::abs)
+            }
+
+            /// Computes the sign function, as [`Strict::signum`]
+            #[inline]
+            pub fn signum(self) -> Checked<Strict<F, C::Signum>> {
+                self.map(Strict::signum)
+            }
+
+            /// Computes the reciprocal, as [`Strict::recip`]
+            #[inline]
+            pub fn recip(self) -> Checked<Strict<F, <<C as RecipOutput<F>>::Output as OpOutput<F>>::Output>>
+            where
+                C: RecipOutput<F>,
+            {
+                self.and_then_detailed(|value| {
+                    let one = F::from_f64(1.0);
+                    // SAFETY: `RecipOutput` was inferred for the reciprocal of `C`
+                    unsafe {
+                        <<C as RecipOutput<F>>::Output as OpOutput<F>>::from_operation_detailed(
+                            Operation::new(Operator::Div, one, value.value),
+                            one / value.value,
+                        )
+                    }
+                })
+            }
+
+            /// Computes `(self * a) + b` with a single rounding, as [`Strict::mul_add`]
+            #[inline]
+            pub fn mul_add<A: Constraint, B: Constraint>(
+                self,
+                a: Strict<F, A>,
+                b: Strict<F, B>,
+            ) -> Checked<Strict<F, <<C as MulAddOutput<F, A, B>>::Output as OpOutput<F>>::Output>>
+            where
+                C: MulAddOutput<F, A, B>,
+            {
+                self.and_then_detailed(|value| {
+                    // SAFETY: `MulAddOutput` was inferred for the fused operation of these
+                    // constraints
+                    unsafe {
+                        <<C as MulAddOutput<F, A, B>>::Output as OpOutput<F>>::from_operation_detailed(
+                            Operation::mul_add(value.value, a.value, b.value),
+                            value.value.mul_add(a.value, b.value),
+                        )
+                    }
+                })
+            }
+
+            /// Computes the square root, as [`Strict::sqrt`]
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use strict_num_extended::*;
+            ///
+            /// let side: Checked<PositiveF64> = Checked::new(PositiveF64::new(16.0).unwrap()).sqrt();
+            /// assert_eq!(side.into_result().unwrap().get(), 4.0);
+            ///
+            /// let invalid = Checked::new(FinF64::new(-1.0).unwrap()).sqrt();
+            /// assert_eq!(invalid.error(), Some(FloatError::NaN));
+            /// ```
+            #[inline]
+            pub fn sqrt(self) -> Checked<Strict<F, <<C as SqrtOutput<F>>::Output as OpOutput<F>>::Output>>
+            where
+                C: SqrtOutput<F>,
+            {
+                self.and_then_detailed(|value| {
+                    // SAFETY: `SqrtOutput` was inferred for the square root of `C`
+                    unsafe {
+                        <<C as SqrtOutput<F>>::Output as OpOutput<F>>::from_value_detailed(
+                            value.value.sqrt(),
+                        )
+                    }
+                })
+            }
+
+            /// Computes the cube root, as [`Strict::cbrt`]
+            #[inline]
+            pub fn cbrt(self) -> Checked<Strict<F, <C as CbrtOutput<F>>::Output>>
+            where
+                C: CbrtOutput<F>,
+            {
+                self.map(Strict::cbrt)
+            }
+
+            /// Computes `sqrt(self² + other²)`, as [`Strict::hypot`]
+            #[inline]
+            pub fn hypot<R: Constraint>(
+                self,
+                other: Strict<F, R>,
+            ) -> Checked<Strict<F, <<C as HypotOutput<F, R>>::Output as OpOutput<F>>::Output>>
+            where
+                C: HypotOutput<F, R>,
+            {
+                self.and_then_detailed(|value| {
+                    // SAFETY: `HypotOutput` was inferred for the hypotenuse of `C` and `R`
+                    unsafe {
+                        <<C as HypotOutput<F, R>>::Output as OpOutput<F>>::from_operation_detailed(
+                            Operation::new(Operator::Hypot, value.value, other.value),
+                            value.value.hypot(other.value),
+                        )
+                    }
+                })
+            }
+
+            /// Computes the sine, as [`Strict::sin`]
+            #[cfg(feature = "std")]
+            #[inline]
+            pub fn sin(self) -> Checked<Strict<F, #sin_cos_output>> {
+                self.map(Strict::sin)
+            }
+
+            /// Computes the cosine, as [`Strict::cos`]
+            #[cfg(feature = "std")]
+            #[inline]
+            pub fn cos(self) -> Checked<Strict<F, #sin_cos_output>> {
+                self.map(Strict::cos)
+            }
+
+            /// Computes the tangent, as [`Strict::tan`]
+            #[cfg(feature = "std")]
+            #[inline]
+            pub fn tan(self) -> Checked<Strict<F, Fin>> {
+                self.and_then(Strict::tan)
+            }
+        }
+    }
+}
+
+/// Generates the fallible computation of `lhs op rhs`, recording the context of an error
+fn detailed_op(
+    op: ArithmeticOp,
+    op_symbol: &TokenStream2,
+    float_type: &TokenStream2,
+    output: &TokenStream2,
+    lhs: &TokenStream2,
+    rhs: &TokenStream2,
+) -> TokenStream2 {
+    let operator = operator_variant(op);
+    let result = apply_op(op, op_symbol, lhs, rhs);
+
+    quote! {
+        // SAFETY: The output was inferred for this operation, exactly as for the
+        // operator of the underlying values
+        unsafe {
+            <#output as OpOutput<#float_type>>::from_operation_detailed(
+                Operation::new(#operator, #lhs, #rhs),
+                #result,
+            )
+        }
+    }
+}
+
+/// Generates `Checked` arithmetic against `Checked`, `Strict` and primitive operands
+///
+/// Each operation is computed like the operator of the underlying values, but keeps the
+/// context of its error.
+fn generate_checked_binary_impls() -> TokenStream2 {
+    let ops = get_standard_arithmetic_ops()
+        .into_iter()
+        .chain(get_euclid_ops());
+
+    let impls = ops.map(|(op, trait_name, method_name, op_symbol)| {
+        let trait_ident = Ident::new(trait_name, Span::call_site());
+        let method_ident = Ident::new(method_name, Span::call_site());
+        let output_trait = format_ident!("{}Output", trait_name);
+        let generic = quote! { F };
+        let output = quote! { <L as #output_trait<F, R>>::Output };
+        let strict_op = detailed_op(
+            op,
+            &op_symbol,
+            &generic,
+            &output,
+            &quote! { a.value },
+            &quote! { b.value },
+        );
+
+        let primitive_impls = ["f32", "f64"].iter().map(|float_name| {
+            let float_ident = Ident::new(float_name, Span::call_site());
+            let float_type = quote! { #float_ident };
+            let rhs_op = detailed_op(
+                op,
+                &op_symbol,
+                &float_type,
+                &quote! { <L as #output_trait<#float_ident, Fin>>::Output },
+                &quote! { a.value },
+                &quote! { rhs },
+            );
+            let lhs_op = detailed_op(
+                op,
+                &op_symbol,
+                &float_type,
+                &quote! { <Fin as #output_trait<#float_ident, R>>::Output },
+                &quote! { self },
+                &quote! { b.value },
+            );
+
+            quote! {
+                impl<L: #output_trait<#float_ident, Fin>> #trait_ident<#float_ident> for Checked<Strict<#float_ident, L>> {
+                    type Output = Checked<Strict<#float_ident, <<L as #output_trait<#float_ident, Fin>>::Output as OpOutput<#float_ident>>::Output>>;
+
+                    #[inline]
+                    fn #method_ident(self, rhs: #float_ident) -> Self::Output {
+                        self.and_then_detailed(|a| #rhs_op)
+                    }
+                }
+
+                impl<R: Constraint> #trait_ident<Checked<Strict<#float_ident, R>>> for #float_ident
+                where
+                    Fin: #output_trait<#float_ident, R>,
+                {
+                    type Output = Checked<Strict<#float_ident, <<Fin as #output_trait<#float_ident, R>>::Output as OpOutput<#float_ident>>::Output>>;
+
+                    #[inline]
+                    fn #method_ident(self, rhs: Checked<Strict<#float_ident, R>>) -> Self::Output {
+                        rhs.and_then_detailed(|b| #lhs_op)
+                    }
+                }
+            }
+        });
+
+        quote! {
+            impl<F: Float, L: #output_trait<F, R>, R: Constraint> #trait_ident<Checked<Strict<F, R>>> for Checked<Strict<F, L>> {
+                type Output = Checked<Strict<F, <<L as #output_trait<F, R>>::Output as OpOutput<F>>::Output>>;
+
+                #[inline]
+                fn #method_ident(self, rhs: Checked<Strict<F, R>>) -> Self::Output {
+                    self.and_then_detailed(|a| rhs.0.and_then(|b| #strict_op))
+                }
+            }
+
+            impl<F: Float, L: #output_trait<F, R>, R: Constraint> #trait_ident<Strict<F, R>> for Checked<Strict<F, L>> {
+                type Output = Checked<Strict<F, <<L as #output_trait<F, R>>::Output as OpOutput<F>>::Output>>;
+
+                #[inline]
+                fn #method_ident(self, b: Strict<F, R>) -> Self::Output {
+                    self.and_then_detailed(|a| #strict_op)
+                }
+            }
+
+            impl<F: Float, L: #output_trait<F, R>, R: Constraint> #trait_ident<Checked<Strict<F, R>>> for Strict<F, L> {
+                type Output = Checked<Strict<F, <<L as #output_trait<F, R>>::Output as OpOutput<F>>::Output>>;
+
+                #[inline]
+                fn #method_ident(self, rhs: Checked<Strict<F, R>>) -> Self::Output {
+                    let a = self;
+                    rhs.and_then_detailed(|b| #strict_op)
+                }
+            }
+
+            #(#primitive_impls)*
+        }
+    });
+
+    quote! { #(#impls)* }
+}
+
+/// Generates the `Checked` wrapper, its arithmetic and its unary math
+pub fn generate_checked_impls(config: &TypeConfig) -> TokenStream2 {
+    let checked_struct = generate_checked_struct();
+    let unary_methods = generate_checked_unary_methods(config);
+    let binary_impls = generate_checked_binary_impls();
+
+    quote! {
+        #checked_struct
+        #unary_methods
+        #binary_impls
+    }
+}
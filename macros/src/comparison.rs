@@ -2,59 +2,41 @@
 
 use quote::quote;
 
-use crate::config::TypeConfig;
-use crate::generator::{for_all_constraint_float_types, make_type_alias};
-
-/// Generates comparison and formatting trait implementations for concrete types.
+/// Generates comparison and formatting trait implementations for `Strict`.
 pub fn generate_comparison_traits() -> proc_macro2::TokenStream {
-    // This is now a placeholder that returns empty code
-    // Comparison traits are generated for each concrete type in arithmetic.rs and other modules
-    quote! {}
-}
-
-/// Generates comparison and formatting traits for all concrete types
-pub fn generate_concrete_comparison_traits(config: &TypeConfig) -> proc_macro2::TokenStream {
-    let impls = for_all_constraint_float_types(config, |type_name, float_type, _| {
-        let struct_name = make_type_alias(type_name, float_type);
-
-        quote! {
-            impl PartialEq for #struct_name {
-                fn eq(&self, other: &Self) -> bool {
-                    self.value == other.value
-                }
+    quote! {
+        impl<F: Float, C: Constraint> PartialEq for Strict<F, C> {
+            fn eq(&self, other: &Self) -> bool {
+                self.value == other.value
             }
+        }
 
-            impl Eq for #struct_name {}
+        impl<F: Float, C: Constraint> Eq for Strict<F, C> {}
 
-            impl Ord for #struct_name {
-                fn cmp(&self, other: &Self) -> core::cmp::Ordering {
-                    self.value
-                        .partial_cmp(&other.value)
-                        .expect("values should always be comparable")
-                }
+        impl<F: Float, C: Constraint> Ord for Strict<F, C> {
+            fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+                self.value
+                    .partial_cmp(&other.value)
+                    .expect("values should always be comparable")
             }
+        }
 
-            impl PartialOrd for #struct_name {
-                fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
-                    Some(self.cmp(other))
-                }
+        impl<F: Float, C: Constraint> PartialOrd for Strict<F, C> {
+            fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+                Some(self.cmp(other))
             }
+        }
 
-            impl core::fmt::Display for #struct_name {
-                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-                    write!(f, "{}", self.value)
-                }
+        impl<F: Float, C: Constraint> core::fmt::Display for Strict<F, C> {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(f, "{}", self.value)
             }
+        }
 
-            impl core::fmt::Debug for #struct_name {
-                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-                    write!(f, "FiniteFloat({:?})", self.value)
-                }
+        impl<F: Float, C: Constraint> core::fmt::Debug for Strict<F, C> {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(f, "FiniteFloat({:?})", self.value)
             }
         }
-    });
-
-    quote! {
-        #(#impls)*
     }
 }
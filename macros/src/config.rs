@@ -3,29 +3,81 @@
 //! Defines configuration structures for procedural macros and `TokenStream` parsing logic.
 
 mod config_arithmetic;
+mod config_expr;
 mod config_types;
 
 // Re-export all types
 pub use config_arithmetic::*;
+pub use config_expr::*;
 pub use config_types::*;
 
-use proc_macro2::{Ident, Span};
-use syn::{Expr, Lit, parse::Parse, parse::ParseStream};
+use proc_macro2::{Ident, Span, TokenStream, TokenTree};
+use quote::ToTokens;
+use syn::{Expr, Lit, LitStr, parse::Parse, parse::ParseStream};
 
 // ============================================================================
 // Parse trait implementations
 // ============================================================================
 
-#[expect(clippy::too_many_lines)]
 impl Parse for TypeConfig {
     fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut parsed = ParsedTypes::default();
+        parsed.parse_types(input, true)?;
+
+        // Parse second parameter: alias definitions (optional)
+        if input.parse::<syn::Token![,]>().is_ok() {
+            parsed.parse_aliases(input, true)?;
+        }
+
+        Ok(parsed.finish(None))
+    }
+}
+
+/// Input of the hidden macro behind `define_constraints!`.
+///
+/// Format: `$crate; [built-in types], [built-in aliases]; [custom types], [custom aliases]`.
+/// The built-in lists are forwarded by the main crate so that custom types can be
+/// inferred and converted against them; only the custom types are generated.
+pub struct CustomTypeConfig(pub TypeConfig);
+
+impl Parse for CustomTypeConfig {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        // `$crate` arrives as a single token
+        let crate_path: TokenTree = input.parse()?;
+        input.parse::<syn::Token![;]>()?;
+
+        let mut parsed = ParsedTypes::default();
+        parsed.parse_types(input, false)?;
+        if input.parse::<syn::Token![,]>().is_ok() {
+            parsed.parse_aliases(input, false)?;
+        }
+        input.parse::<syn::Token![;]>()?;
+
+        parsed.parse_types(input, true)?;
+        if input.parse::<syn::Token![,]>().is_ok() && !input.is_empty() {
+            parsed.parse_aliases(input, true)?;
+        }
+
+        Ok(Self(parsed.finish(Some(crate_path.into_token_stream()))))
+    }
+}
+
+/// Type and alias definitions collected before inference runs.
+#[derive(Default)]
+struct ParsedTypes {
+    constraints: Vec<ConstraintDef>,
+    constraint_types: Vec<TypeDef>,
+    type_aliases: Vec<TypeAliasDef>,
+}
+
+impl ParsedTypes {
+    /// Parses a bracketed type list: `[(TypeName, ["condition", ...]), ...]`
+    #[expect(clippy::too_many_lines)]
+    fn parse_types(&mut self, input: ParseStream, is_local: bool) -> syn::Result<()> {
         // Parse bracketed content: [ ... ]
         let content;
         syn::bracketed!(content in input);
 
-        let mut constraints = Vec::new();
-        let mut constraint_types = Vec::new();
-
         // Parse each type definition
         while !content.is_empty() {
             // Parse (TypeName, ["condition1", "condition2", ...])
@@ -38,22 +90,31 @@ impl Parse for TypeConfig {
             let bracket_content;
             syn::bracketed!(bracket_content in &paren_content);
 
+            if let Some(existing) = self
+                .constraints
+                .iter()
+                .find(|c: &&ConstraintDef| c.name == type_name)
+            {
+                return Err(syn::Error::new_spanned(
+                    &type_name,
+                    format!("Duplicate type name '{}'", existing.name),
+                ));
+            }
+
             // Parse condition list
-            let mut conditions = Vec::new();
+            let mut parsed_conditions = Vec::new();
             while !bracket_content.is_empty() {
                 let expr: Expr = bracket_content.parse()?;
-                let condition = if let Expr::Lit(syn::ExprLit {
-                    lit: Lit::Str(s), ..
+                let Expr::Lit(syn::ExprLit {
+                    lit: Lit::Str(lit), ..
                 }) = &expr
-                {
-                    normalize_condition(&s.value())
-                } else {
+                else {
                     return Err(syn::Error::new_spanned(
                         expr,
                         "Expected string literal for validation condition",
                     ));
                 };
-                conditions.push(condition);
+                parsed_conditions.push(parse_condition(lit)?);
 
                 // If not the last one, parse comma
                 if !bracket_content.is_empty() {
@@ -62,303 +123,314 @@ impl Parse for TypeConfig {
             }
 
             // Parse sign and bounds from conditions
-            let (sign, bounds, excludes_zero) = parse_type_properties(&conditions);
+            let (sign, bounds, excludes_zero) = parse_type_properties(&parsed_conditions);
 
-            // Generate constraint definition
-            constraints.push(ConstraintDef {
+            let constraint = ConstraintDef {
                 name: type_name.clone(),
                 neg_constraint_name: None, // Will be calculated later
-                raw_conditions: conditions,
                 sign,
+                lower_expr: bound_expr(
+                    &parsed_conditions,
+                    bounds.lower,
+                    bounds.lower_inclusive,
+                    true,
+                ),
+                upper_expr: bound_expr(
+                    &parsed_conditions,
+                    bounds.upper,
+                    bounds.upper_inclusive,
+                    false,
+                ),
                 bounds,
                 excludes_zero,
-            });
+            };
+
+            // Reject conditions that no value can satisfy (in either width)
+            let f32_ident = Ident::new("f32", Span::call_site());
+            if constraint.is_unsatisfiable() {
+                return Err(syn::Error::new_spanned(
+                    &type_name,
+                    format!("Conditions of type '{type_name}' cannot be satisfied by any value"),
+                ));
+            }
+            if constraint.for_float(&f32_ident).is_unsatisfiable() {
+                return Err(syn::Error::new_spanned(
+                    &type_name,
+                    format!(
+                        "Conditions of type '{type_name}' cannot be satisfied by any f32 value"
+                    ),
+                ));
+            }
+
+            // Reject types that are indistinguishable from an earlier one of the same
+            // invocation (a custom type may mirror a built-in one)
+            if let Some(existing) = self
+                .constraints
+                .iter()
+                .zip(&self.constraint_types)
+                .find(|(c, t)| {
+                    t.is_local == is_local && c.bounds == bounds && c.excludes_zero == excludes_zero
+                })
+                .map(|(c, _)| c)
+            {
+                return Err(syn::Error::new_spanned(
+                    &type_name,
+                    format!(
+                        "Type '{type_name}' has the same constraints as '{}'; use a type alias instead",
+                        existing.name
+                    ),
+                ));
+            }
+
+            self.constraints.push(constraint);
 
             // Generate type definition (automatically add f32 and f64)
             let type_name_clone = type_name.clone();
-            constraint_types.push(TypeDef {
+            self.constraint_types.push(TypeDef {
                 type_name,
                 float_types: vec![
                     Ident::new("f32", Span::call_site()),
                     Ident::new("f64", Span::call_site()),
                 ],
                 constraint_name: type_name_clone,
+                is_local,
             });
 
             let _ = content.parse::<syn::Token![,]>();
         }
 
-        // Calculate negation mappings for all constraints
-        // Build a list of (name, raw_conditions) first to avoid borrow checker issues
-        let constraints_data: Vec<_> = constraints
-            .iter()
-            .map(|c| (c.name.clone(), c.raw_conditions.clone()))
-            .collect();
-
-        // Now calculate negation mappings
-        for constraint in &mut constraints {
-            let negated_conditions = negate_conditions(&constraint.raw_conditions);
-
-            // Find a matching constraint in the list
-            for (other_name, other_raw) in &constraints_data {
-                if conditions_match(&negated_conditions, other_raw) {
-                    constraint.neg_constraint_name = Some(other_name.clone());
-                    break;
-                }
-            }
-        }
+        Ok(())
+    }
 
-        // Calculate arithmetic operation results
-        let arithmetic_results = compute_all_arithmetic_results(&constraints);
+    /// Parses a bracketed alias list: `[(OriginalName, AliasName), ...]`
+    fn parse_aliases(&mut self, input: ParseStream, is_local: bool) -> syn::Result<()> {
+        let alias_content;
+        syn::bracketed!(alias_content in input);
 
-        // Parse second parameter: alias definitions (optional)
-        let mut type_aliases = Vec::new();
+        let first_new = self.type_aliases.len();
+        while !alias_content.is_empty() {
+            let paren_content;
+            syn::parenthesized!(paren_content in alias_content);
 
-        if input.parse::<syn::Token![,]>().is_ok() {
-            // Has second parameter, parse alias list
-            let alias_content;
-            syn::bracketed!(alias_content in input);
+            let original_name: Ident = paren_content.parse()?;
+            paren_content.parse::<syn::Token![,]>()?;
+            let alias_name: Ident = paren_content.parse()?;
 
-            while !alias_content.is_empty() {
-                let paren_content;
-                syn::parenthesized!(paren_content in alias_content);
+            self.type_aliases.push(TypeAliasDef {
+                original_name,
+                alias_name,
+                is_local,
+            });
 
-                let original_name: Ident = paren_content.parse()?;
-                paren_content.parse::<syn::Token![,]>()?;
-                let alias_name: Ident = paren_content.parse()?;
+            if !alias_content.is_empty() {
+                alias_content.parse::<syn::Token![,]>()?;
+            }
+        }
 
-                type_aliases.push(TypeAliasDef {
-                    original_name,
-                    alias_name,
-                });
+        // Validate aliases
+        for alias_def in self.type_aliases.iter().skip(first_new) {
+            // Check if original type exists
+            let Some(original) = self
+                .constraint_types
+                .iter()
+                .find(|t| t.type_name == alias_def.original_name)
+            else {
+                return Err(syn::Error::new_spanned(
+                    &alias_def.original_name,
+                    format!(
+                        "Alias references non-existent type '{}'",
+                        alias_def.original_name
+                    ),
+                ));
+            };
+
+            // Aliases of built-in types already exist in the main crate
+            if alias_def.is_local && !original.is_local {
+                return Err(syn::Error::new_spanned(
+                    &alias_def.original_name,
+                    format!(
+                        "Alias must reference a type defined in the same invocation, found built-in type '{}'",
+                        alias_def.original_name
+                    ),
+                ));
+            }
 
-                if !alias_content.is_empty() {
-                    alias_content.parse::<syn::Token![,]>()?;
-                }
+            // Check if alias name conflicts with existing types
+            let alias_conflicts = self
+                .constraints
+                .iter()
+                .any(|c| c.name == alias_def.alias_name);
+
+            if alias_conflicts {
+                return Err(syn::Error::new_spanned(
+                    &alias_def.alias_name,
+                    format!(
+                        "Alias name '{}' conflicts with existing type",
+                        alias_def.alias_name
+                    ),
+                ));
             }
+        }
 
-            // Validate aliases
-            for alias_def in &type_aliases {
-                // Check if original type exists
-                let original_exists = constraints
-                    .iter()
-                    .any(|c| c.name == alias_def.original_name);
+        Ok(())
+    }
 
-                if !original_exists {
-                    return Err(syn::Error::new_spanned(
-                        &alias_def.original_name,
-                        format!(
-                            "Alias references non-existent type '{}'",
-                            alias_def.original_name
-                        ),
-                    ));
-                }
+    /// Computes negations, f32 bounds and arithmetic results for all parsed types
+    fn finish(self, crate_path: Option<TokenStream>) -> TypeConfig {
+        let Self {
+            mut constraints,
+            constraint_types,
+            type_aliases,
+        } = self;
 
-                // Check if alias name conflicts with existing types
-                let alias_conflicts = constraints.iter().any(|c| c.name == alias_def.alias_name);
+        // Round bounds to f32
+        let f32_ident = Ident::new("f32", Span::call_site());
+        let f64_ident = Ident::new("f64", Span::call_site());
+        let mut f32_constraints: Vec<_> = constraints
+            .iter()
+            .map(|c| c.for_float(&f32_ident))
+            .collect();
 
-                if alias_conflicts {
-                    return Err(syn::Error::new_spanned(
-                        &alias_def.alias_name,
-                        format!(
-                            "Alias name '{}' conflicts with existing type",
-                            alias_def.alias_name
-                        ),
-                    ));
-                }
-            }
+        // Pair each constraint with the one mirroring it around zero in both widths
+        let neg_names: Vec<_> = constraints
+            .iter()
+            .zip(&f32_constraints)
+            .map(|(c64, c32)| {
+                constraints
+                    .iter()
+                    .zip(&f32_constraints)
+                    .find(|(other64, other32)| {
+                        c64.is_negation_of(other64) && c32.is_negation_of(other32)
+                    })
+                    .map(|(other, _)| other.name.clone())
+            })
+            .collect();
+        for ((c64, c32), neg_name) in constraints
+            .iter_mut()
+            .zip(&mut f32_constraints)
+            .zip(neg_names)
+        {
+            c64.neg_constraint_name.clone_from(&neg_name);
+            c32.neg_constraint_name = neg_name;
         }
 
-        Ok(TypeConfig {
+        // Calculate arithmetic operation results per width
+        let mut arithmetic_results = compute_all_arithmetic_results(&constraints, &f64_ident);
+        arithmetic_results.extend(compute_all_arithmetic_results(&f32_constraints, &f32_ident));
+
+        TypeConfig {
             constraints,
+            f32_constraints,
             constraint_types,
             arithmetic_results,
             type_aliases,
-        })
+            crate_path,
+        }
     }
 }
 
 // ============================================================================
-// Simplified format helpers
-// ============================================================================
-
-/// Normalize validation condition string by automatically adding `value` prefix
-fn normalize_condition(condition: &str) -> String {
-    format!("value {}", condition.trim())
-}
-
-// ============================================================================
-// Negation constraint matching helpers
+// Type property parsing helpers
 // ============================================================================
 
-/// Apply negation to a list of constraint conditions.
-///
-/// # Rules
-/// - `">= x"` becomes `"<= -x"`
-/// - `"<= x"` becomes `">= -x"`
-/// - `"> x"` becomes `"< -x"`
-/// - `"< x"` becomes `"> -x"`
-/// - `"!= x"` stays `"!= x"` (reflexive)
+/// Parse a single condition string such as `"> 0.0"` into operator and value.
 ///
-/// # Examples
-/// - `">= 0.0"` → `"<= 0.0"` (since -0.0 == 0.0)
-/// - `">= -1.0"` → `"<= 1.0"` (double negation)
-/// - `"<= 0.0"` → `">= 0.0"` (since -0.0 == 0.0)
-#[expect(clippy::option_if_let_else)] // if-let-else is clearer here
-fn negate_conditions(conditions: &[String]) -> Vec<String> {
-    conditions
-        .iter()
-        .map(|cond| {
-            // Remove "value " prefix if present
-            let cond = cond.strip_prefix("value ").unwrap_or(cond);
-
-            // Apply negation rules with double negation handling
-            if let Some(rest) = cond.strip_prefix(">=") {
-                let rest = rest.trim();
-                // Handle double negation: >= -x becomes <= x
-                if let Some(num) = rest.strip_prefix('-') {
-                    format!("<= {num}")
-                } else {
-                    format!("<= -{rest}")
-                }
-            } else if let Some(rest) = cond.strip_prefix("<=") {
-                let rest = rest.trim();
-                // Handle double negation: <= -x becomes >= x
-                if let Some(num) = rest.strip_prefix('-') {
-                    format!(">= {num}")
-                } else {
-                    format!(">= -{rest}")
-                }
-            } else if let Some(rest) = cond.strip_prefix(">") {
-                let rest = rest.trim();
-                // Handle double negation: > -x becomes < x
-                if let Some(num) = rest.strip_prefix('-') {
-                    format!("< {num}")
-                } else {
-                    format!("< -{rest}")
-                }
-            } else if let Some(rest) = cond.strip_prefix("<") {
-                let rest = rest.trim();
-                // Handle double negation: < -x becomes > x
-                if let Some(num) = rest.strip_prefix('-') {
-                    format!("> {num}")
-                } else {
-                    format!("> -{rest}")
-                }
-            } else if cond.starts_with("!=") {
-                // Inequality stays the same
-                cond.to_string()
-            } else {
-                // Unknown condition, keep as-is
-                cond.to_string()
-            }
-        })
-        .map(|s| normalize_condition(&s)) // Re-add "value " prefix
-        .collect()
-}
-
-/// Normalize floating-point representation (handle -0.0 == 0.0).
-fn normalize_float_repr(s: &str) -> String {
-    // Replace -0.0 with 0.0 in various contexts
-    let s = s.replace("-0.0", "0.0");
-    // Also handle cases with extra spaces like ">= - 0.0"
-    let s = s.replace("- 0.0", "0.0");
-    // Also handle "= -0.0" -> "= 0.0"
-
-    s.replace("= -0.0", "= 0.0")
-}
-
-/// Check if two constraint condition lists match (order-insensitive).
-fn conditions_match(a: &[String], b: &[String]) -> bool {
-    // Normalize and compare
-    let a_normalized: Vec<String> = a.iter().map(|s| normalize_float_repr(s)).collect();
-
-    let b_normalized: Vec<String> = b.iter().map(|s| normalize_float_repr(s)).collect();
+/// Errors are spanned at the string literal so typos point at the offending condition.
+fn parse_condition(lit: &LitStr) -> syn::Result<Condition> {
+    let text = lit.value();
+    let cond = text.trim();
+
+    // Two-character operators must be checked before their one-character prefixes
+    let (op, rest) = [
+        (">=", CompareOp::Ge),
+        ("<=", CompareOp::Le),
+        ("!=", CompareOp::Ne),
+        (">", CompareOp::Gt),
+        ("<", CompareOp::Lt),
+    ]
+    .into_iter()
+    .find_map(|(prefix, op)| cond.strip_prefix(prefix).map(|rest| (op, rest.trim())))
+    .ok_or_else(|| {
+        syn::Error::new_spanned(
+            lit,
+            format!(
+                "Unknown operator in condition '{cond}'; expected one of '>=', '<=', '>', '<', '!='"
+            ),
+        )
+    })?;
+
+    let expr = BoundExpr::parse(rest)
+        .map_err(|msg| syn::Error::new_spanned(lit, format!("{msg} in condition '{cond}'")))?;
+    let value = expr.eval();
+
+    if !value.is_finite() {
+        return Err(syn::Error::new_spanned(
+            lit,
+            format!("Bound in condition '{cond}' must be finite"),
+        ));
+    }
 
-    // Sort and compare (ignore order)
-    let mut a_sorted = a_normalized;
-    let mut b_sorted = b_normalized;
-    a_sorted.sort();
-    b_sorted.sort();
+    if op == CompareOp::Ne && value != 0.0 {
+        return Err(syn::Error::new_spanned(
+            lit,
+            format!("Only '!= 0.0' is supported, found '{cond}'"),
+        ));
+    }
 
-    a_sorted == b_sorted
+    Ok(Condition { op, value, expr })
 }
 
-// ============================================================================
-// Type property parsing helpers
-// ============================================================================
-
 /// Parse type properties (sign, bounds, `excludes_zero`) from conditions.
-fn parse_type_properties(conditions: &[String]) -> (Sign, Bounds, bool) {
-    let mut lower_bound: Option<f64> = None;
-    let mut upper_bound: Option<f64> = None;
-    let mut excludes_zero = false;
-    let mut has_positive_constraint = false;
-    let mut has_negative_constraint = false;
+///
+/// `>`/`<` produce exclusive bounds and `>=`/`<=` inclusive ones. A type excludes
+/// zero when its interval does not admit zero or when it carries an explicit `!= 0`.
+fn parse_type_properties(conditions: &[Condition]) -> (Sign, Bounds, bool) {
+    let mut bounds = Bounds::UNBOUNDED;
+    let mut has_nonzero_condition = false;
 
     for cond in conditions {
-        let cond = cond.strip_prefix("value ").unwrap_or(cond);
-
-        if let Some(rest) = cond.strip_prefix(">=") {
-            let val = parse_float_value(rest.trim());
-            lower_bound = Some(lower_bound.map_or(val, |v| v.max(val)));
-            if val >= 0.0 {
-                has_positive_constraint = true;
-            }
-        } else if let Some(rest) = cond.strip_prefix("<=") {
-            let val = parse_float_value(rest.trim());
-            upper_bound = Some(upper_bound.map_or(val, |v| v.min(val)));
-            if val <= 0.0 {
-                has_negative_constraint = true;
-            }
-        } else if let Some(rest) = cond.strip_prefix(">") {
-            let val = parse_float_value(rest.trim());
-            // > x means lower bound is x (exclusive)
-            lower_bound = Some(lower_bound.map_or(val, |v| v.max(val)));
-            if val >= 0.0 {
-                has_positive_constraint = true;
-                excludes_zero = true;
-            }
-        } else if let Some(rest) = cond.strip_prefix("<") {
-            let val = parse_float_value(rest.trim());
-            // < x means upper bound is x (exclusive)
-            upper_bound = Some(upper_bound.map_or(val, |v| v.min(val)));
-            if val <= 0.0 {
-                has_negative_constraint = true;
-                excludes_zero = true;
-            }
-        } else if let Some(rest) = cond.strip_prefix("!=") {
-            let val = parse_float_value(rest.trim());
-            if val == 0.0 {
-                excludes_zero = true;
-            }
+        match cond.op {
+            CompareOp::Ge => bounds.tighten_lower(cond.value, true),
+            CompareOp::Gt => bounds.tighten_lower(cond.value, false),
+            CompareOp::Le => bounds.tighten_upper(cond.value, true),
+            CompareOp::Lt => bounds.tighten_upper(cond.value, false),
+            CompareOp::Ne => has_nonzero_condition = true,
         }
     }
 
-    // Determine sign based on constraints
-    let sign = if has_positive_constraint && !has_negative_constraint {
-        Sign::Positive
-    } else if has_negative_constraint && !has_positive_constraint {
-        Sign::Negative
-    } else {
-        Sign::Any
-    };
-
-    let bounds = Bounds {
-        lower: lower_bound,
-        upper: upper_bound,
-    };
+    // Determine sign based on which side of zero the interval lies
+    let sign = bounds.sign();
+    let excludes_zero = has_nonzero_condition || !bounds.contains(0.0);
 
     (sign, bounds, excludes_zero)
 }
 
-/// Parse a float value from a string.
-fn parse_float_value(s: &str) -> f64 {
-    match s {
-        "PI" => core::f64::consts::PI,
-        "-PI" => -core::f64::consts::PI,
-        _ => s.parse().unwrap_or(0.0),
-    }
+/// Find the expression of the condition that produced a final bound.
+///
+/// # Arguments
+///
+/// * `conditions` - Parsed conditions of the type
+/// * `bound` - Final value of the bound side
+/// * `inclusive` - Whether the final bound side is inclusive
+/// * `is_lower` - Whether to look at the lower (`>=`/`>`) or upper (`<=`/`<`) side
+#[expect(clippy::float_cmp)] // the bound is one of the condition values, compared exactly
+fn bound_expr(
+    conditions: &[Condition],
+    bound: Option<f64>,
+    inclusive: bool,
+    is_lower: bool,
+) -> Option<BoundExpr> {
+    let bound = bound?;
+    let op = match (is_lower, inclusive) {
+        (true, true) => CompareOp::Ge,
+        (true, false) => CompareOp::Gt,
+        (false, true) => CompareOp::Le,
+        (false, false) => CompareOp::Lt,
+    };
+    conditions
+        .iter()
+        .find(|c| c.op == op && c.value == bound)
+        .map(|c| c.expr.clone())
 }
 
 // ============================================================================
@@ -381,4 +453,18 @@ impl TypeConfig {
             .find(|c| c.sign == sign && c.bounds == *bounds && c.excludes_zero == excludes_zero)
             .map(|c| c.name.clone())
     }
+
+    /// Get the constraint definitions as seen by `float_type` (`f32` or `f64`).
+    pub fn constraints_for(&self, float_type: &Ident) -> &[ConstraintDef] {
+        if *float_type == "f32" {
+            &self.f32_constraints
+        } else {
+            &self.constraints
+        }
+    }
+
+    /// Iterates over the types defined by this invocation.
+    pub fn local_types(&self) -> impl Iterator<Item = &TypeDef> {
+        self.constraint_types.iter().filter(|t| t.is_local)
+    }
 }
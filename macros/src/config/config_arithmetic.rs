@@ -6,7 +6,9 @@ use std::collections::HashMap;
 
 use proc_macro2::{Ident, Span};
 
-use super::config_types::{ArithmeticOp, ArithmeticResult, Bounds, ConstraintDef, Sign};
+use super::config_types::{
+    ArithmeticOp, ArithmeticResult, Bounds, ConstraintDef, FloatLimits, Sign,
+};
 
 // ============================================================================
 // Arithmetic operation inference
@@ -15,12 +17,18 @@ use super::config_types::{ArithmeticOp, ArithmeticResult, Bounds, ConstraintDef,
 /// Checks if all boundary operation results are finite.
 ///
 /// Performs actual operations on the boundary values of lhs and rhs,
-/// checking if all possible extreme value combinations produce finite results.
-pub fn bounds_op_is_finite(lhs: &Bounds, rhs: &Bounds, op: impl Fn(f64, f64) -> f64) -> bool {
-    let l_min = lhs.lower.unwrap_or(f64::MIN);
-    let l_max = lhs.upper.unwrap_or(f64::MAX);
-    let r_min = rhs.lower.unwrap_or(f64::MIN);
-    let r_max = rhs.upper.unwrap_or(f64::MAX);
+/// checking if all possible extreme value combinations stay within the
+/// limits of the float width.
+pub fn bounds_op_is_finite(
+    lhs: &Bounds,
+    rhs: &Bounds,
+    limits: FloatLimits,
+    op: impl Fn(f64, f64) -> f64,
+) -> bool {
+    let l_min = lhs.lower.unwrap_or(-limits.max);
+    let l_max = lhs.upper.unwrap_or(limits.max);
+    let r_min = rhs.lower.unwrap_or(-limits.max);
+    let r_max = rhs.upper.unwrap_or(limits.max);
 
     // Compute all possible extreme value combinations
     let results: [f64; 4] = [
@@ -30,52 +38,80 @@ pub fn bounds_op_is_finite(lhs: &Bounds, rhs: &Bounds, op: impl Fn(f64, f64) ->
         op(l_max, r_max),
     ];
 
-    results.iter().all(|r: &f64| r.is_finite())
+    results.iter().all(|&r| limits.is_finite(r))
 }
 
 /// Checks if all boundary division results are finite.
 ///
-/// Division requires special handling: if the divisor excludes zero,
-/// use the minimum positive number as the divisor's lower bound.
-pub fn bounds_div_is_finite(lhs: &Bounds, rhs: &Bounds, rhs_excludes_zero: bool) -> bool {
-    let l_min = lhs.lower.unwrap_or(f64::MIN);
-    let l_max = lhs.upper.unwrap_or(f64::MAX);
+/// Division requires special handling: if the divisor excludes zero, use the smallest
+/// subnormal number as the divisor's bound at zero. A divisor spanning zero admits
+/// values arbitrarily close to it on both sides, so its quotients are never bounded.
+pub fn bounds_div_is_finite(
+    lhs: &Bounds,
+    rhs: &Bounds,
+    rhs_excludes_zero: bool,
+    limits: FloatLimits,
+) -> bool {
+    let l_min = lhs.lower.unwrap_or(-limits.max);
+    let l_max = lhs.upper.unwrap_or(limits.max);
+    if rhs.lower.is_none_or(|lower| lower < 0.0) && rhs.upper.is_none_or(|upper| upper > 0.0) {
+        return false;
+    }
 
     // For divisor, if it excludes zero, avoid using 0 as the boundary value
     let r_min = if rhs_excludes_zero && rhs.lower == Some(0.0) {
-        f64::MIN_POSITIVE
+        limits.min_subnormal
     } else {
-        rhs.lower.unwrap_or(f64::MIN)
+        rhs.lower.unwrap_or(-limits.max)
     };
     let r_max = if rhs_excludes_zero && rhs.upper == Some(0.0) {
-        -f64::MIN_POSITIVE
+        -limits.min_subnormal
     } else {
-        rhs.upper.unwrap_or(f64::MAX)
+        rhs.upper.unwrap_or(limits.max)
     };
 
     // Compute all possible extreme value combinations
     let results = [l_min / r_min, l_min / r_max, l_max / r_min, l_max / r_max];
 
-    results.iter().all(|r| r.is_finite())
+    results.iter().all(|&r| limits.is_finite(r))
 }
 
-/// Compute arithmetic results for all constraint combinations.
+/// Compute arithmetic results for all constraint combinations of one float width.
+///
+/// # Arguments
+///
+/// * `constraints` - Constraint definitions with bounds rounded to `float_type`
+/// * `float_type` - Float width the results are computed for (`f32` or `f64`)
 pub fn compute_all_arithmetic_results(
     constraints: &[ConstraintDef],
-) -> HashMap<(ArithmeticOp, String, String), ArithmeticResult> {
+    float_type: &Ident,
+) -> HashMap<(ArithmeticOp, String, String, String), ArithmeticResult> {
     let mut results = HashMap::new();
+    let limits = FloatLimits::of(float_type);
     let ops = [
         ArithmeticOp::Add,
         ArithmeticOp::Sub,
         ArithmeticOp::Mul,
         ArithmeticOp::Div,
+        ArithmeticOp::Rem,
+        ArithmeticOp::RemEuclid,
+        ArithmeticOp::DivEuclid,
+        ArithmeticOp::FusedAdd,
     ];
 
     for lhs in constraints {
         for rhs in constraints {
             for &op in &ops {
-                let result = compute_arithmetic_result(op, lhs, rhs, constraints);
-                results.insert((op, lhs.name.to_string(), rhs.name.to_string()), result);
+                let result = compute_arithmetic_result(op, lhs, rhs, constraints, limits);
+                results.insert(
+                    (
+                        op,
+                        lhs.name.to_string(),
+                        rhs.name.to_string(),
+                        float_type.to_string(),
+                    ),
+                    result,
+                );
             }
         }
     }
@@ -89,12 +125,17 @@ fn compute_arithmetic_result(
     lhs: &ConstraintDef,
     rhs: &ConstraintDef,
     all_constraints: &[ConstraintDef],
+    limits: FloatLimits,
 ) -> ArithmeticResult {
     let (output_sign, output_excludes_zero, is_safe) = match op {
-        ArithmeticOp::Add => compute_add_properties(lhs, rhs),
-        ArithmeticOp::Sub => compute_sub_properties(lhs, rhs),
-        ArithmeticOp::Mul => compute_mul_properties(lhs, rhs),
-        ArithmeticOp::Div => compute_div_properties(lhs, rhs),
+        ArithmeticOp::Add => compute_add_properties(lhs, rhs, limits),
+        ArithmeticOp::Sub => compute_sub_properties(lhs, rhs, limits),
+        ArithmeticOp::Mul => compute_mul_properties(lhs, rhs, limits),
+        ArithmeticOp::Div => compute_div_properties(lhs, rhs, limits),
+        ArithmeticOp::Rem => compute_rem_properties(lhs, rhs),
+        ArithmeticOp::RemEuclid => compute_rem_euclid_properties(rhs),
+        ArithmeticOp::DivEuclid => compute_div_euclid_properties(lhs, rhs, limits),
+        ArithmeticOp::FusedAdd => compute_fused_add_properties(lhs, rhs, limits),
     };
 
     // Find the best matching constraint type for the output
@@ -105,23 +146,199 @@ fn compute_arithmetic_result(
         lhs,
         rhs,
         all_constraints,
+        limits,
     );
 
+    // A safe operation skips runtime validation, so every possible result must be
+    // finite, and the chosen output type must provably admit it (including underflow
+    // to zero). An unbounded side of the range would otherwise fit unbounded types.
+    let is_safe = is_safe
+        && !result_may_overflow(op, lhs, rhs, limits)
+        && all_constraints
+            .iter()
+            .find(|c| c.name == output_type)
+            .is_some_and(|output| {
+                range_fits_constraint(&result_range(op, lhs, rhs, limits), output)
+            });
+
     ArithmeticResult {
         output_type,
         is_safe,
     }
 }
 
+/// Finds the declared constraint admitting every value of `range` with the fewest
+/// values: bounded before half-bounded before unbounded, then the narrowest, then
+/// one excluding zero, then one with the sign of the range, then one of `preferred`.
+/// Ties keep the declaration order.
+///
+/// `excludes_zero` tells that zero is not a possible value even if `range` contains it.
+pub fn tightest_superset<'a>(
+    range: &Bounds,
+    excludes_zero: bool,
+    constraints: &'a [ConstraintDef],
+    preferred: &[&Ident],
+) -> Option<&'a ConstraintDef> {
+    let range_sign = match (range.lower, range.upper) {
+        (Some(lower), _) if lower >= 0.0 => Sign::Positive,
+        (_, Some(upper)) if upper <= 0.0 => Sign::Negative,
+        _ => Sign::Any,
+    };
+    let unbounded_sides = |c: &ConstraintDef| {
+        usize::from(c.bounds.lower.is_none()) + usize::from(c.bounds.upper.is_none())
+    };
+    let width = |c: &ConstraintDef| match (c.bounds.lower, c.bounds.upper) {
+        (Some(lower), Some(upper)) => upper - lower,
+        _ => 0.0,
+    };
+
+    constraints
+        .iter()
+        .filter(|c| {
+            range_fits_constraint(range, c) || (excludes_zero && range.is_subset_of(&c.bounds))
+        })
+        .min_by(|a, b| {
+            unbounded_sides(a)
+                .cmp(&unbounded_sides(b))
+                .then(width(a).total_cmp(&width(b)))
+                .then(b.excludes_zero.cmp(&a.excludes_zero))
+                .then((b.sign == range_sign).cmp(&(a.sign == range_sign)))
+                .then(
+                    preferred
+                        .contains(&&b.name)
+                        .cmp(&preferred.contains(&&a.name)),
+                )
+        })
+}
+
+/// Computes a conservative closed range for `lhs op rhs`.
+///
+/// Exclusive sides are treated as inclusive because rounding (e.g. underflow of
+/// `(0, 1) * (0, 1)` to `0.0`) can reach the boundary itself. Results beyond
+/// the width's range become unbounded sides.
+pub fn result_range(
+    op: ArithmeticOp,
+    lhs: &ConstraintDef,
+    rhs: &ConstraintDef,
+    limits: FloatLimits,
+) -> Bounds {
+    let (min, max) = side_results(op, lhs, rhs, f64::INFINITY);
+
+    Bounds::closed(
+        limits.is_finite(min).then_some(min),
+        limits.is_finite(max).then_some(max),
+    )
+}
+
+/// Checks whether `lhs op rhs` may give an infinite result.
+///
+/// Unlike [`result_range`], unbounded operands are taken at the largest finite value
+/// of the width, so only results that overflow reach infinity.
+fn result_may_overflow(
+    op: ArithmeticOp,
+    lhs: &ConstraintDef,
+    rhs: &ConstraintDef,
+    limits: FloatLimits,
+) -> bool {
+    let (min, max) = side_results(op, lhs, rhs, limits.max);
+    !(limits.is_finite(min) && limits.is_finite(max))
+}
+
+/// Computes the extreme results of `lhs op rhs`, taking `unbounded` as the magnitude
+/// of unbounded sides of the operands
+fn side_results(
+    op: ArithmeticOp,
+    lhs: &ConstraintDef,
+    rhs: &ConstraintDef,
+    unbounded: f64,
+) -> (f64, f64) {
+    let l_min = lhs.bounds.lower.unwrap_or(-unbounded);
+    let l_max = lhs.bounds.upper.unwrap_or(unbounded);
+    let r_min = rhs.bounds.lower.unwrap_or(-unbounded);
+    let r_max = rhs.bounds.upper.unwrap_or(unbounded);
+
+    match op {
+        ArithmeticOp::Add | ArithmeticOp::FusedAdd => (l_min + r_min, l_max + r_max),
+        ArithmeticOp::Sub => (l_min - r_max, l_max - r_min),
+        ArithmeticOp::Mul => interval_mul(l_min, l_max, r_min, r_max),
+        ArithmeticOp::Div => interval_div(l_min, l_max, r_min, r_max, rhs.excludes_zero),
+        ArithmeticOp::Rem => {
+            // Sign of the dividend, magnitude below both the dividend's and the divisor's
+            let r_abs = r_min.abs().max(r_max.abs());
+            (l_min.max(-r_abs).min(0.0), l_max.min(r_abs).max(0.0))
+        }
+        ArithmeticOp::RemEuclid => {
+            // `r + |b|` may round up to `|b|` itself, so the upper side is inclusive
+            (0.0, r_min.abs().max(r_max.abs()))
+        }
+        ArithmeticOp::DivEuclid => {
+            // The quotient is rounded towards negative infinity for a positive divisor
+            // and towards positive infinity for a negative one
+            let (min, max) = interval_div(l_min, l_max, r_min, r_max, rhs.excludes_zero);
+            let min = if min >= 0.0 {
+                min.floor()
+            } else {
+                min.floor() - 1.0
+            };
+            let max = if max <= 0.0 {
+                max.ceil()
+            } else {
+                max.ceil() + 1.0
+            };
+            (min, max)
+        }
+    }
+}
+
+/// Divides two closed intervals, unbounded unless the divisor excludes zero.
+fn interval_div(l_min: f64, l_max: f64, r_min: f64, r_max: f64, excludes_zero: bool) -> (f64, f64) {
+    // Divisor entirely on one side of zero: multiply by its reciprocal range
+    // (`abs()` keeps the sign of a zero endpoint so that 1/0 has the right sign)
+    if excludes_zero && r_min >= 0.0 {
+        interval_mul(l_min, l_max, 1.0 / r_max, 1.0 / r_min.abs())
+    } else if excludes_zero && r_max <= 0.0 {
+        interval_mul(l_min, l_max, 1.0 / -r_max.abs(), 1.0 / r_min)
+    } else {
+        (f64::NEG_INFINITY, f64::INFINITY)
+    }
+}
+
+/// Multiplies two closed intervals, treating `0 * ∞` as `0`.
+fn interval_mul(l_min: f64, l_max: f64, r_min: f64, r_max: f64) -> (f64, f64) {
+    let mul = |a: f64, b: f64| if a == 0.0 || b == 0.0 { 0.0 } else { a * b };
+    let products = [
+        mul(l_min, r_min),
+        mul(l_min, r_max),
+        mul(l_max, r_min),
+        mul(l_max, r_max),
+    ];
+    products
+        .iter()
+        .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), &p| {
+            (min.min(p), max.max(p))
+        })
+}
+
+/// Checks whether every value of `range` is admitted by `constraint`.
+fn range_fits_constraint(range: &Bounds, constraint: &ConstraintDef) -> bool {
+    let zero_ok = !constraint.excludes_zero || !range.contains(0.0);
+    range.is_subset_of(&constraint.bounds) && zero_ok
+}
+
 /// Compute output properties for addition.
-fn compute_add_properties(lhs: &ConstraintDef, rhs: &ConstraintDef) -> (Sign, bool, bool) {
+fn compute_add_properties(
+    lhs: &ConstraintDef,
+    rhs: &ConstraintDef,
+    limits: FloatLimits,
+) -> (Sign, bool, bool) {
     // Safe when signs differ (Positive + Negative or Negative + Positive)
     // and all boundary operation results are finite
     let signs_differ = matches!(
         (lhs.sign, rhs.sign),
         (Sign::Positive, Sign::Negative) | (Sign::Negative, Sign::Positive)
     );
-    let is_safe = signs_differ && bounds_op_is_finite(&lhs.bounds, &rhs.bounds, |a, b| a + b);
+    let is_safe =
+        signs_differ && bounds_op_is_finite(&lhs.bounds, &rhs.bounds, limits, |a, b| a + b);
 
     // Sign rules for addition:
     // Positive + Positive = Positive
@@ -140,12 +357,32 @@ fn compute_add_properties(lhs: &ConstraintDef, rhs: &ConstraintDef) -> (Sign, bo
     (output_sign, output_excludes_zero, is_safe)
 }
 
+/// Compute output properties for the addition step of `mul_add`.
+///
+/// The output type is the one inferred for `+`, but the step is safe whenever the
+/// bounds of the product and of the addend keep every sum finite, so bounded sums of
+/// the same sign such as `[0, 1] + [0, 1]` need no validation.
+fn compute_fused_add_properties(
+    lhs: &ConstraintDef,
+    rhs: &ConstraintDef,
+    limits: FloatLimits,
+) -> (Sign, bool, bool) {
+    let (output_sign, output_excludes_zero, _) = compute_add_properties(lhs, rhs, limits);
+    let is_safe = bounds_op_is_finite(&lhs.bounds, &rhs.bounds, limits, |a, b| a + b);
+
+    (output_sign, output_excludes_zero, is_safe)
+}
+
 /// Compute output properties for subtraction.
-fn compute_sub_properties(lhs: &ConstraintDef, rhs: &ConstraintDef) -> (Sign, bool, bool) {
+fn compute_sub_properties(
+    lhs: &ConstraintDef,
+    rhs: &ConstraintDef,
+    limits: FloatLimits,
+) -> (Sign, bool, bool) {
     // Safe when signs are the same (Positive - Positive or Negative - Negative)
     // and all boundary operation results are finite
     let signs_same = lhs.sign == rhs.sign && lhs.sign != Sign::Any;
-    let is_safe = signs_same && bounds_op_is_finite(&lhs.bounds, &rhs.bounds, |a, b| a - b);
+    let is_safe = signs_same && bounds_op_is_finite(&lhs.bounds, &rhs.bounds, limits, |a, b| a - b);
 
     // a - b: negate rhs sign
     let rhs_negated_sign = match rhs.sign {
@@ -189,10 +426,7 @@ const fn max_abs_value(bounds: Bounds) -> f64 {
 }
 
 /// Compute multiplication result bounds based on operand signs and bounds.
-fn compute_mul_result_bounds(
-    lhs: &ConstraintDef,
-    rhs: &ConstraintDef,
-) -> (Option<f64>, Option<f64>) {
+fn compute_mul_result_bounds(lhs: &ConstraintDef, rhs: &ConstraintDef) -> Bounds {
     let max_abs_lhs = max_abs_value(lhs.bounds);
     let max_abs_rhs = max_abs_value(rhs.bounds);
     let max_abs_result = max_abs_lhs * max_abs_rhs;
@@ -200,14 +434,14 @@ fn compute_mul_result_bounds(
     match (lhs.sign, rhs.sign) {
         // Both positive or both negative → positive result [0, max]
         (Sign::Positive, Sign::Positive) | (Sign::Negative, Sign::Negative) => {
-            (Some(0.0), Some(max_abs_result))
+            Bounds::closed(Some(0.0), Some(max_abs_result))
         }
         // Different signs → negative result [-max, 0]
         (Sign::Positive, Sign::Negative) | (Sign::Negative, Sign::Positive) => {
-            (Some(-max_abs_result), Some(0.0))
+            Bounds::closed(Some(-max_abs_result), Some(0.0))
         }
         // Any sign → symmetric bounds [-max, max]
-        _ => (Some(-max_abs_result), Some(max_abs_result)),
+        _ => Bounds::closed(Some(-max_abs_result), Some(max_abs_result)),
     }
 }
 
@@ -229,10 +463,15 @@ fn filter_constraints_by_sign(constraints: &[ConstraintDef], sign: Sign) -> Vec<
 }
 
 /// Compute output properties for multiplication.
-fn compute_mul_properties(lhs: &ConstraintDef, rhs: &ConstraintDef) -> (Sign, bool, bool) {
+fn compute_mul_properties(
+    lhs: &ConstraintDef,
+    rhs: &ConstraintDef,
+    limits: FloatLimits,
+) -> (Sign, bool, bool) {
     // Safe when both operands are bounded and all boundary operation results are finite
     let both_bounded = lhs.bounds.is_bounded() && rhs.bounds.is_bounded();
-    let is_safe = both_bounded && bounds_op_is_finite(&lhs.bounds, &rhs.bounds, |a, b| a * b);
+    let is_safe =
+        both_bounded && bounds_op_is_finite(&lhs.bounds, &rhs.bounds, limits, |a, b| a * b);
 
     // Sign rules for multiplication:
     // Positive × Positive = Positive
@@ -252,7 +491,11 @@ fn compute_mul_properties(lhs: &ConstraintDef, rhs: &ConstraintDef) -> (Sign, bo
 }
 
 /// Compute output properties for division.
-fn compute_div_properties(lhs: &ConstraintDef, rhs: &ConstraintDef) -> (Sign, bool, bool) {
+fn compute_div_properties(
+    lhs: &ConstraintDef,
+    rhs: &ConstraintDef,
+    limits: FloatLimits,
+) -> (Sign, bool, bool) {
     // Safe when dividend is in [-1.0, 1.0], divisor is non-zero,
     // and all boundary operation results are finite
     let lhs_in_unit_range = if let (Some(lower), Some(upper)) = (lhs.bounds.lower, lhs.bounds.upper)
@@ -263,7 +506,7 @@ fn compute_div_properties(lhs: &ConstraintDef, rhs: &ConstraintDef) -> (Sign, bo
     };
     let is_safe = lhs_in_unit_range
         && rhs.excludes_zero
-        && bounds_div_is_finite(&lhs.bounds, &rhs.bounds, rhs.excludes_zero);
+        && bounds_div_is_finite(&lhs.bounds, &rhs.bounds, rhs.excludes_zero, limits);
 
     // Sign rules for division (same as multiplication):
     let output_sign = match (lhs.sign, rhs.sign) {
@@ -278,6 +521,56 @@ fn compute_div_properties(lhs: &ConstraintDef, rhs: &ConstraintDef) -> (Sign, bo
     (output_sign, output_excludes_zero, is_safe)
 }
 
+/// Compute output properties for the remainder (`%`).
+const fn compute_rem_properties(lhs: &ConstraintDef, rhs: &ConstraintDef) -> (Sign, bool, bool) {
+    // The remainder has the sign of the dividend and a smaller magnitude than the
+    // divisor, so it is always finite; only a zero divisor produces NaN.
+    // Exact multiples of the divisor leave a zero remainder.
+    (lhs.sign, false, rhs.excludes_zero)
+}
+
+/// Compute output properties for the Euclidean remainder.
+const fn compute_rem_euclid_properties(rhs: &ConstraintDef) -> (Sign, bool, bool) {
+    // The Euclidean remainder is never negative, and like `%` only fails on a zero divisor
+    (Sign::Positive, false, rhs.excludes_zero)
+}
+
+/// Compute output properties for Euclidean division.
+fn compute_div_euclid_properties(
+    lhs: &ConstraintDef,
+    rhs: &ConstraintDef,
+    limits: FloatLimits,
+) -> (Sign, bool, bool) {
+    // The rounded quotient keeps the sign of the division, but may round to zero
+    let (output_sign, ..) = compute_div_properties(lhs, rhs, limits);
+
+    // Safe when the divisor excludes zero and every rounded quotient is finite
+    let is_safe =
+        rhs.excludes_zero && !result_may_overflow(ArithmeticOp::DivEuclid, lhs, rhs, limits);
+    (output_sign, false, is_safe)
+}
+
+/// Computes the bounds that select a bounded output type, if the operation has them
+fn bounded_result_bounds(
+    op: ArithmeticOp,
+    lhs: &ConstraintDef,
+    rhs: &ConstraintDef,
+    limits: FloatLimits,
+) -> Option<Bounds> {
+    match op {
+        ArithmeticOp::Mul => (lhs.bounds.is_bounded() && rhs.bounds.is_bounded())
+            .then(|| compute_mul_result_bounds(lhs, rhs)),
+        ArithmeticOp::Rem | ArithmeticOp::RemEuclid => {
+            Some(result_range(op, lhs, rhs, limits)).filter(Bounds::is_bounded)
+        }
+        ArithmeticOp::Add
+        | ArithmeticOp::Sub
+        | ArithmeticOp::Div
+        | ArithmeticOp::DivEuclid
+        | ArithmeticOp::FusedAdd => None,
+    }
+}
+
 /// Find the best matching constraint type for given output properties.
 fn find_matching_constraint(
     op: ArithmeticOp,
@@ -286,40 +579,30 @@ fn find_matching_constraint(
     lhs: &ConstraintDef,
     rhs: &ConstraintDef,
     constraints: &[ConstraintDef],
+    limits: FloatLimits,
 ) -> Ident {
     // Check if both operands have the same bounded range
-    let operands_have_same_bounds = lhs.bounds.is_bounded()
-        && rhs.bounds.is_bounded()
-        && lhs.bounds.lower == rhs.bounds.lower
-        && lhs.bounds.upper == rhs.bounds.upper;
+    let operands_have_same_bounds = lhs.bounds.is_bounded() && lhs.bounds == rhs.bounds;
 
     // Collect all matching constraints (sign + excludes_zero)
     let matches = filter_constraints_by_properties(constraints, sign, excludes_zero);
 
     // If we found exact matches
     if !matches.is_empty() {
-        // For multiplication, compute the actual result bounds and match them
-        if matches!(op, ArithmeticOp::Mul) {
-            // Check if both operands are bounded (even if bounds are different)
-            if lhs.bounds.is_bounded() && rhs.bounds.is_bounded() {
-                // Compute result bounds for bounded multiplication
-                let (result_lower, result_upper) = compute_mul_result_bounds(lhs, rhs);
-
-                // First, try to find a bounded type with the computed result bounds
-                for c in &matches {
-                    if c.bounds.is_bounded()
-                        && c.bounds.lower == result_lower
-                        && c.bounds.upper == result_upper
-                    {
-                        return c.name.clone();
-                    }
+        // For multiplication and remainders, compute the actual result bounds and match them
+        if let Some(result_bounds) = bounded_result_bounds(op, lhs, rhs, limits) {
+            // First, try to find a bounded type with the computed result bounds
+            for c in &matches {
+                if c.bounds.is_bounded() && c.bounds == result_bounds {
+                    return c.name.clone();
                 }
+            }
 
-                // If exact bounds match not found, prefer bounded types over unbounded
-                for c in &matches {
-                    if c.bounds.is_bounded() {
-                        return c.name.clone();
-                    }
+            // If exact bounds match not found, prefer bounded types that still
+            // contain the computed result bounds over unbounded ones
+            for c in &matches {
+                if c.bounds.is_bounded() && result_bounds.is_subset_of(&c.bounds) {
+                    return c.name.clone();
                 }
             }
         }
@@ -346,15 +629,9 @@ fn find_matching_constraint(
     if !sign_matches.is_empty() {
         // When operands have same bounds, prefer bounded types that match those bounds
         if operands_have_same_bounds {
-            let operand_lower = lhs.bounds.lower;
-            let operand_upper = lhs.bounds.upper;
-
             // Try to find a bounded type with the same bounds
             for c in &sign_matches {
-                if c.bounds.is_bounded()
-                    && c.bounds.lower == operand_lower
-                    && c.bounds.upper == operand_upper
-                {
+                if c.bounds.is_bounded() && c.bounds == lhs.bounds {
                     return c.name.clone();
                 }
             }
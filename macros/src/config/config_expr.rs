@@ -0,0 +1,386 @@
+//! Bound expression parsing and evaluation
+//!
+//! Bounds in conditions may be numeric literals, named constants (`PI`, `TAU`,
+//! `f32::MAX`, ...) or simple arithmetic on them (`2 * PI`, `-1e-3`). The expression
+//! is evaluated at expansion time for type inference and re-emitted per float width,
+//! so f32 and f64 types each compare against their own correctly rounded constant.
+
+use proc_macro2::{Ident, Literal, TokenStream};
+use quote::{format_ident, quote};
+use syn::{BinOp, Expr, Lit, UnOp};
+
+/// Mathematical constants from `core::{f32,f64}::consts`: (name, f32 value, f64 value)
+const MATH_CONSTANTS: &[(&str, f32, f64)] = &[
+    ("PI", core::f32::consts::PI, core::f64::consts::PI),
+    ("TAU", core::f32::consts::TAU, core::f64::consts::TAU),
+    ("E", core::f32::consts::E, core::f64::consts::E),
+    (
+        "FRAC_PI_2",
+        core::f32::consts::FRAC_PI_2,
+        core::f64::consts::FRAC_PI_2,
+    ),
+    (
+        "FRAC_PI_3",
+        core::f32::consts::FRAC_PI_3,
+        core::f64::consts::FRAC_PI_3,
+    ),
+    (
+        "FRAC_PI_4",
+        core::f32::consts::FRAC_PI_4,
+        core::f64::consts::FRAC_PI_4,
+    ),
+    (
+        "FRAC_PI_6",
+        core::f32::consts::FRAC_PI_6,
+        core::f64::consts::FRAC_PI_6,
+    ),
+    (
+        "FRAC_PI_8",
+        core::f32::consts::FRAC_PI_8,
+        core::f64::consts::FRAC_PI_8,
+    ),
+    (
+        "FRAC_1_PI",
+        core::f32::consts::FRAC_1_PI,
+        core::f64::consts::FRAC_1_PI,
+    ),
+    (
+        "FRAC_2_PI",
+        core::f32::consts::FRAC_2_PI,
+        core::f64::consts::FRAC_2_PI,
+    ),
+    (
+        "FRAC_2_SQRT_PI",
+        core::f32::consts::FRAC_2_SQRT_PI,
+        core::f64::consts::FRAC_2_SQRT_PI,
+    ),
+    (
+        "SQRT_2",
+        core::f32::consts::SQRT_2,
+        core::f64::consts::SQRT_2,
+    ),
+    (
+        "FRAC_1_SQRT_2",
+        core::f32::consts::FRAC_1_SQRT_2,
+        core::f64::consts::FRAC_1_SQRT_2,
+    ),
+    ("LN_2", core::f32::consts::LN_2, core::f64::consts::LN_2),
+    ("LN_10", core::f32::consts::LN_10, core::f64::consts::LN_10),
+    (
+        "LOG2_E",
+        core::f32::consts::LOG2_E,
+        core::f64::consts::LOG2_E,
+    ),
+    (
+        "LOG2_10",
+        core::f32::consts::LOG2_10,
+        core::f64::consts::LOG2_10,
+    ),
+    (
+        "LOG10_E",
+        core::f32::consts::LOG10_E,
+        core::f64::consts::LOG10_E,
+    ),
+    (
+        "LOG10_2",
+        core::f32::consts::LOG10_2,
+        core::f64::consts::LOG10_2,
+    ),
+];
+
+/// Associated limit constants of `f32`/`f64`: (name, f32 value, f64 value)
+const LIMIT_CONSTANTS: &[(&str, f32, f64)] = &[
+    ("MAX", f32::MAX, f64::MAX),
+    ("MIN", f32::MIN, f64::MIN),
+    ("MIN_POSITIVE", f32::MIN_POSITIVE, f64::MIN_POSITIVE),
+    ("EPSILON", f32::EPSILON, f64::EPSILON),
+];
+
+/// Binary operator of a bound expression.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BoundOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+/// A bound value expression from the constraint DSL.
+#[derive(Debug, Clone)]
+pub enum BoundExpr {
+    /// Numeric literal, rounded separately for each width
+    Literal {
+        /// Literal rounded to f32
+        f32_value: f32,
+        /// Literal rounded to f64
+        f64_value: f64,
+    },
+    /// Named constant such as `PI` or `f32::MAX`
+    Constant {
+        /// Constant name (e.g. `"FRAC_PI_2"`)
+        name: &'static str,
+        /// Whether the constant lives in `consts` (`PI`) or on the type itself (`MAX`)
+        is_math: bool,
+        /// Width the constant is pinned to (`f32::MAX`), `None` for bare names
+        pinned: Option<&'static str>,
+        /// Constant value as emitted for f32
+        f32_value: f32,
+        /// Constant value as emitted for f64
+        f64_value: f64,
+    },
+    /// Negation (`-expr`)
+    Neg(Box<BoundExpr>),
+    /// Binary arithmetic (`lhs op rhs`)
+    Binary {
+        /// Operator
+        op: BoundOp,
+        /// Left-hand side
+        lhs: Box<BoundExpr>,
+        /// Right-hand side
+        rhs: Box<BoundExpr>,
+    },
+}
+
+impl BoundExpr {
+    /// Parses a bound expression from the operand text of a condition.
+    ///
+    /// # Returns
+    ///
+    /// The parsed expression, or a message describing why it is not supported
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let expr: Expr = syn::parse_str(text)
+            .map_err(|_| format!("Cannot parse '{text}' as a number or constant"))?;
+        Self::from_expr(&expr)
+    }
+
+    /// Converts a `syn` expression into a bound expression.
+    fn from_expr(expr: &Expr) -> Result<Self, String> {
+        if let Expr::Lit(lit) = expr {
+            return Self::from_lit(&lit.lit);
+        }
+        if let Expr::Paren(paren) = expr {
+            return Self::from_expr(&paren.expr);
+        }
+        if let Expr::Group(group) = expr {
+            return Self::from_expr(&group.expr);
+        }
+        if let Expr::Unary(unary) = expr {
+            if let UnOp::Neg(_) = unary.op {
+                return Ok(Self::Neg(Box::new(Self::from_expr(&unary.expr)?)));
+            }
+        }
+        if let Expr::Binary(binary) = expr {
+            let op = if let BinOp::Add(_) = binary.op {
+                BoundOp::Add
+            } else if let BinOp::Sub(_) = binary.op {
+                BoundOp::Sub
+            } else if let BinOp::Mul(_) = binary.op {
+                BoundOp::Mul
+            } else if let BinOp::Div(_) = binary.op {
+                BoundOp::Div
+            } else {
+                return Err("Only '+', '-', '*' and '/' are supported in bounds".into());
+            };
+            return Ok(Self::Binary {
+                op,
+                lhs: Box::new(Self::from_expr(&binary.left)?),
+                rhs: Box::new(Self::from_expr(&binary.right)?),
+            });
+        }
+        if let Expr::Path(path) = expr {
+            if path.qself.is_some() {
+                return Err(format!("Unsupported bound expression '{}'", quote!(#expr)));
+            }
+            let segments: Vec<String> = path
+                .path
+                .segments
+                .iter()
+                .map(|s| s.ident.to_string())
+                .collect();
+            let segments: Vec<&str> = segments.iter().map(String::as_str).collect();
+            return Self::from_path(&segments);
+        }
+        Err(format!("Unsupported bound expression '{}'", quote!(#expr)))
+    }
+
+    /// Converts a numeric literal.
+    fn from_lit(lit: &Lit) -> Result<Self, String> {
+        let (digits, suffix) = if let Lit::Float(f) = lit {
+            (f.base10_digits(), f.suffix())
+        } else if let Lit::Int(i) = lit {
+            (i.base10_digits(), i.suffix())
+        } else {
+            return Err("Bounds must be numeric literals or constants".into());
+        };
+        if !suffix.is_empty() {
+            return Err(format!(
+                "Literal suffix '{suffix}' is not supported; bounds are emitted for both f32 and f64"
+            ));
+        }
+        match (digits.parse(), digits.parse()) {
+            (Ok(f32_value), Ok(f64_value)) => Ok(Self::Literal {
+                f32_value,
+                f64_value,
+            }),
+            _ => Err(format!("Cannot parse '{digits}' as a number")),
+        }
+    }
+
+    /// Resolves a constant path such as `PI`, `f32::consts::PI`, `MAX` or `f64::MAX`.
+    fn from_path(segments: &[&str]) -> Result<Self, String> {
+        // Allow an optional `core::`/`std::` prefix
+        let segments = match segments {
+            ["core" | "std", rest @ ..] => rest,
+            _ => segments,
+        };
+
+        let (pinned, ident, in_consts) = match *segments {
+            [ident] => (None, ident, None),
+            [width @ ("f32" | "f64"), "consts", ident] => (Some(width), ident, Some(true)),
+            [width @ ("f32" | "f64"), ident] => (Some(width), ident, Some(false)),
+            _ => return Err(format!("Unknown constant '{}'", segments.join("::"))),
+        };
+        let pinned = pinned.map(|w| if w == "f32" { "f32" } else { "f64" });
+
+        let lookup = |table: &'static [(&'static str, f32, f64)]| {
+            table.iter().find(|(n, _, _)| *n == ident).copied()
+        };
+        let found = match in_consts {
+            Some(true) => lookup(MATH_CONSTANTS).map(|c| (c, true)),
+            Some(false) => lookup(LIMIT_CONSTANTS).map(|c| (c, false)),
+            None => lookup(MATH_CONSTANTS)
+                .map(|c| (c, true))
+                .or_else(|| lookup(LIMIT_CONSTANTS).map(|c| (c, false))),
+        };
+
+        let ((name, f32_value, f64_value), is_math) =
+            found.ok_or_else(|| format!("Unknown constant '{}'", segments.join("::")))?;
+
+        // A constant pinned to one width is cast to the other one
+        let (f32_value, f64_value) = match pinned {
+            #[expect(clippy::cast_possible_truncation)] // f64 limits become ±∞ in f32
+            Some("f64") => (f64_value as f32, f64_value),
+            Some(_) => (f32_value, f64::from(f32_value)),
+            None => (f32_value, f64_value),
+        };
+
+        Ok(Self::Constant {
+            name,
+            is_math,
+            pinned,
+            f32_value,
+            f64_value,
+        })
+    }
+
+    /// Evaluates the expression in f64 arithmetic.
+    pub fn eval(&self) -> f64 {
+        match self {
+            Self::Literal { f64_value, .. } | Self::Constant { f64_value, .. } => *f64_value,
+            Self::Neg(inner) => -inner.eval(),
+            Self::Binary { op, lhs, rhs } => {
+                let (l, r) = (lhs.eval(), rhs.eval());
+                match op {
+                    BoundOp::Add => l + r,
+                    BoundOp::Sub => l - r,
+                    BoundOp::Mul => l * r,
+                    BoundOp::Div => l / r,
+                }
+            }
+        }
+    }
+
+    /// Evaluates the expression in f32 arithmetic, as the emitted f32 code does.
+    pub fn eval_f32(&self) -> f32 {
+        match self {
+            Self::Literal { f32_value, .. } | Self::Constant { f32_value, .. } => *f32_value,
+            Self::Neg(inner) => -inner.eval_f32(),
+            Self::Binary { op, lhs, rhs } => {
+                let (l, r) = (lhs.eval_f32(), rhs.eval_f32());
+                match op {
+                    BoundOp::Add => l + r,
+                    BoundOp::Sub => l - r,
+                    BoundOp::Mul => l * r,
+                    BoundOp::Div => l / r,
+                }
+            }
+        }
+    }
+
+    /// Evaluates the expression as seen by `float_type`, widened to f64.
+    pub fn eval_for(&self, float_type: &Ident) -> f64 {
+        if *float_type == "f32" {
+            f64::from(self.eval_f32())
+        } else {
+            self.eval()
+        }
+    }
+
+    /// Emits the expression as a constant expression of `float_type`.
+    ///
+    /// Constants are emitted by path (e.g. `core::f32::consts::PI`) and literals are
+    /// rounded to the target width, so no precision is lost through f64.
+    pub fn to_tokens(&self, float_type: &Ident) -> TokenStream {
+        let is_f32 = *float_type == "f32";
+        match self {
+            Self::Literal {
+                f32_value,
+                f64_value,
+            } => {
+                let value = if is_f32 {
+                    f64::from(*f32_value)
+                } else {
+                    *f64_value
+                };
+                // Literals beyond the width's range round to infinity
+                if value.is_finite() {
+                    let literal = if is_f32 {
+                        Literal::f32_suffixed(*f32_value)
+                    } else {
+                        Literal::f64_suffixed(*f64_value)
+                    };
+                    quote! { #literal }
+                } else if value > 0.0 {
+                    quote! { #float_type::INFINITY }
+                } else {
+                    quote! { #float_type::NEG_INFINITY }
+                }
+            }
+            Self::Constant {
+                name,
+                is_math,
+                pinned,
+                ..
+            } => {
+                let width =
+                    format_ident!("{}", pinned.unwrap_or(if is_f32 { "f32" } else { "f64" }));
+                let name = format_ident!("{}", name);
+                let path = if *is_math {
+                    quote! { core::#width::consts::#name }
+                } else {
+                    quote! { #width::#name }
+                };
+                if width == *float_type {
+                    path
+                } else {
+                    quote! { (#path as #float_type) }
+                }
+            }
+            Self::Neg(inner) => {
+                let inner = inner.to_tokens(float_type);
+                quote! { -#inner }
+            }
+            Self::Binary { op, lhs, rhs } => {
+                let lhs = lhs.to_tokens(float_type);
+                let rhs = rhs.to_tokens(float_type);
+                let op = match op {
+                    BoundOp::Add => quote! { + },
+                    BoundOp::Sub => quote! { - },
+                    BoundOp::Mul => quote! { * },
+                    BoundOp::Div => quote! { / },
+                };
+                quote! { (#lhs #op #rhs) }
+            }
+        }
+    }
+}
@@ -2,9 +2,11 @@
 //!
 //! Contains all type definitions used in configuration parsing and arithmetic inference.
 
-use proc_macro2::Ident;
+use proc_macro2::{Ident, TokenStream};
 use std::collections::HashMap;
 
+use super::BoundExpr;
+
 // ============================================================================
 // Sign and bound type definitions for arithmetic operations
 // ============================================================================
@@ -21,39 +23,226 @@ pub enum Sign {
 }
 
 /// Bound information for a constraint type.
+///
+/// Each side carries an inclusive/exclusive flag so that open intervals such as
+/// `(0, 1)` (`["> 0.0", "< 1.0"]`) can be represented. The flag of an unbounded
+/// side is always `true`, which keeps structural equality meaningful.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Bounds {
     /// Lower bound (None means -∞)
     pub lower: Option<f64>,
     /// Upper bound (None means +∞)
     pub upper: Option<f64>,
+    /// Whether the lower bound itself is admissible (`>=` vs `>`)
+    pub lower_inclusive: bool,
+    /// Whether the upper bound itself is admissible (`<=` vs `<`)
+    pub upper_inclusive: bool,
 }
 
 impl Bounds {
     /// Epsilon for floating-point comparison
     const EPSILON: f64 = 1e-10;
 
+    /// The unbounded interval (-∞, +∞)
+    pub const UNBOUNDED: Self = Self::closed(None, None);
+
+    /// Creates bounds where both existing sides are inclusive
+    pub const fn closed(lower: Option<f64>, upper: Option<f64>) -> Self {
+        Self {
+            lower,
+            upper,
+            lower_inclusive: true,
+            upper_inclusive: true,
+        }
+    }
+
     /// Check if this type is bounded (has both upper and lower bounds)
     pub const fn is_bounded(&self) -> bool {
         self.lower.is_some() && self.upper.is_some()
     }
 
+    /// Check if both sides of the interval are inclusive
+    pub const fn is_closed(&self) -> bool {
+        self.lower_inclusive && self.upper_inclusive
+    }
+
     /// Check if this is the standard normalized range [0, 1]
     pub fn is_normalized(&self) -> bool {
-        matches!(self, Bounds { lower: Some(l), upper: Some(u) }
-            if (*l - 0.0).abs() < Self::EPSILON && (*u - 1.0).abs() < Self::EPSILON)
+        self.is_closed()
+            && matches!(self, Bounds { lower: Some(l), upper: Some(u), .. }
+                if (*l - 0.0).abs() < Self::EPSILON && (*u - 1.0).abs() < Self::EPSILON)
     }
 
     /// Check if this is the symmetric range [-1, 1]
     pub fn is_symmetric(&self) -> bool {
-        matches!(self, Bounds { lower: Some(l), upper: Some(u) }
-            if (*l - (-1.0)).abs() < Self::EPSILON && (*u - 1.0).abs() < Self::EPSILON)
+        self.is_closed()
+            && matches!(self, Bounds { lower: Some(l), upper: Some(u), .. }
+                if (*l - (-1.0)).abs() < Self::EPSILON && (*u - 1.0).abs() < Self::EPSILON)
     }
 
     /// Check if this is the negative normalized range [-1, 0]
     pub fn is_negative_normalized(&self) -> bool {
-        matches!(self, Bounds { lower: Some(l), upper: Some(u) }
-            if (*l - (-1.0)).abs() < Self::EPSILON && (*u - 0.0).abs() < Self::EPSILON)
+        self.is_closed()
+            && matches!(self, Bounds { lower: Some(l), upper: Some(u), .. }
+                if (*l - (-1.0)).abs() < Self::EPSILON && (*u - 0.0).abs() < Self::EPSILON)
+    }
+
+    /// Check if a value lies within the interval (honoring exclusive sides)
+    pub fn contains(&self, value: f64) -> bool {
+        let above_lower = self.lower.is_none_or(|l| {
+            if self.lower_inclusive {
+                value >= l
+            } else {
+                value > l
+            }
+        });
+        let below_upper = self.upper.is_none_or(|u| {
+            if self.upper_inclusive {
+                value <= u
+            } else {
+                value < u
+            }
+        });
+        above_lower && below_upper
+    }
+
+    /// Check if every value of `self` is also admitted by `other`
+    #[expect(clippy::float_cmp)] // bounds are compared exactly, not within a tolerance
+    pub fn is_subset_of(&self, other: &Self) -> bool {
+        let lower_contained = match (self.lower, other.lower) {
+            (_, None) => true,
+            (None, Some(_)) => false,
+            (Some(src), Some(dst)) => {
+                src > dst || (src == dst && (other.lower_inclusive || !self.lower_inclusive))
+            }
+        };
+        let upper_contained = match (self.upper, other.upper) {
+            (_, None) => true,
+            (None, Some(_)) => false,
+            (Some(src), Some(dst)) => {
+                src < dst || (src == dst && (other.upper_inclusive || !self.upper_inclusive))
+            }
+        };
+        lower_contained && upper_contained
+    }
+
+    /// Mirrors the interval around zero: `[a, b)` becomes `(-b, -a]`
+    pub fn negated(&self) -> Self {
+        Self {
+            lower: self.upper.map(|u| -u),
+            upper: self.lower.map(|l| -l),
+            lower_inclusive: self.upper_inclusive,
+            upper_inclusive: self.lower_inclusive,
+        }
+    }
+
+    /// Sign of the values admitted by the interval
+    pub fn sign(&self) -> Sign {
+        let non_negative = self.lower.is_some_and(|l| l >= 0.0);
+        let non_positive = self.upper.is_some_and(|u| u <= 0.0);
+        match (non_negative, non_positive) {
+            (true, false) => Sign::Positive,
+            (false, true) => Sign::Negative,
+            _ => Sign::Any,
+        }
+    }
+
+    /// Check if no value can satisfy the interval (e.g. `[2, 1]` or `(1, 1]`)
+    #[expect(clippy::float_cmp)] // bounds are compared exactly, not within a tolerance
+    pub fn is_empty(&self) -> bool {
+        match (self.lower, self.upper) {
+            (Some(l), Some(u)) => l > u || (l == u && !self.is_closed()),
+            _ => false,
+        }
+    }
+
+    /// Intersects the lower side with `value` (exclusive wins on ties)
+    #[expect(clippy::float_cmp)] // bounds are compared exactly, not within a tolerance
+    pub fn tighten_lower(&mut self, value: f64, inclusive: bool) {
+        match self.lower {
+            Some(current) if current > value => {}
+            Some(current) if current == value => self.lower_inclusive &= inclusive,
+            _ => {
+                self.lower = Some(value);
+                self.lower_inclusive = inclusive;
+            }
+        }
+    }
+
+    /// Intersects the upper side with `value` (exclusive wins on ties)
+    #[expect(clippy::float_cmp)] // bounds are compared exactly, not within a tolerance
+    pub fn tighten_upper(&mut self, value: f64, inclusive: bool) {
+        match self.upper {
+            Some(current) if current < value => {}
+            Some(current) if current == value => self.upper_inclusive &= inclusive,
+            _ => {
+                self.upper = Some(value);
+                self.upper_inclusive = inclusive;
+            }
+        }
+    }
+}
+
+/// Comparison operator of a single validation condition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompareOp {
+    /// `>=`
+    Ge,
+    /// `<=`
+    Le,
+    /// `>`
+    Gt,
+    /// `<`
+    Lt,
+    /// `!=` (only `!= 0` is supported)
+    Ne,
+}
+
+/// A single parsed validation condition (e.g. `"> 0.0"`).
+#[derive(Debug, Clone)]
+pub struct Condition {
+    /// Comparison operator
+    pub op: CompareOp,
+    /// Right-hand side value evaluated in f64
+    pub value: f64,
+    /// Right-hand side expression as written
+    pub expr: BoundExpr,
+}
+
+/// Numeric limits of a float width, used to judge overflow during type inference.
+#[derive(Debug, Clone, Copy)]
+pub struct FloatLimits {
+    /// Largest finite value of the width
+    pub max: f64,
+    /// Smallest positive subnormal value of the width
+    pub min_subnormal: f64,
+}
+
+impl FloatLimits {
+    /// Limits of `f32`
+    pub const F32: Self = Self {
+        max: f32::MAX as f64,
+        min_subnormal: f32::from_bits(1) as f64,
+    };
+
+    /// Limits of `f64`
+    pub const F64: Self = Self {
+        max: f64::MAX,
+        min_subnormal: f64::from_bits(1),
+    };
+
+    /// Gets the limits of `float_type` (`f32` or `f64`)
+    pub fn of(float_type: &Ident) -> Self {
+        if *float_type == "f32" {
+            Self::F32
+        } else {
+            Self::F64
+        }
+    }
+
+    /// Check if a result computed in f64 stays finite in this width
+    pub fn is_finite(self, value: f64) -> bool {
+        value.abs() <= self.max
     }
 }
 
@@ -64,6 +253,11 @@ pub enum ArithmeticOp {
     Sub,
     Mul,
     Div,
+    Rem,
+    RemEuclid,
+    DivEuclid,
+    /// Addition of a value to an unrounded product, the second step of `mul_add`
+    FusedAdd,
 }
 
 /// Result of arithmetic operation type inference.
@@ -81,32 +275,82 @@ pub struct ArithmeticResult {
 
 /// Main configuration structure.
 pub struct TypeConfig {
-    /// List of constraint definitions.
+    /// List of constraint definitions (bounds evaluated in f64).
     pub constraints: Vec<ConstraintDef>,
+    /// Constraint definitions with bounds rounded to f32.
+    pub f32_constraints: Vec<ConstraintDef>,
     /// List of constraint type definitions.
     pub constraint_types: Vec<TypeDef>,
-    /// Arithmetic operation results: (op, `lhs_name`, `rhs_name`) -> `ArithmeticResult`
-    pub arithmetic_results: HashMap<(ArithmeticOp, String, String), ArithmeticResult>,
+    /// Arithmetic operation results: (op, `lhs_name`, `rhs_name`, `float_type`) -> `ArithmeticResult`
+    pub arithmetic_results: HashMap<(ArithmeticOp, String, String, String), ArithmeticResult>,
     /// Type aliases: [(`OriginalName`, `AliasName`), ...]
     pub type_aliases: Vec<TypeAliasDef>,
+    /// Path of the main crate (`$crate`) when defining custom types in a downstream
+    /// crate, or `None` when generating the built-in types.
+    pub crate_path: Option<TokenStream>,
 }
 
 /// Single constraint definition.
+#[derive(Clone)]
 pub struct ConstraintDef {
     /// Constraint name.
     pub name: Ident,
     /// Name of the constraint type after negation (e.g., Positive -> Negative).
     pub neg_constraint_name: Option<Ident>,
-    /// Raw conditions before adding `is_finite()` check (used for negation calculation).
-    pub raw_conditions: Vec<String>,
     /// Sign property of this constraint.
     pub sign: Sign,
     /// Bound information of this constraint.
     pub bounds: Bounds,
+    /// Expression of the lower bound as written, emitted into validation code.
+    pub lower_expr: Option<BoundExpr>,
+    /// Expression of the upper bound as written, emitted into validation code.
+    pub upper_expr: Option<BoundExpr>,
     /// Whether this constraint excludes zero.
     pub excludes_zero: bool,
 }
 
+impl ConstraintDef {
+    /// Gets this constraint as seen by `float_type`, with its bounds rounded to that width.
+    ///
+    /// Sign and zero exclusion are re-derived, since rounding may move a bound onto zero.
+    pub fn for_float(&self, float_type: &Ident) -> Self {
+        let bounds = Bounds {
+            lower: self.lower_expr.as_ref().map(|e| e.eval_for(float_type)),
+            upper: self.upper_expr.as_ref().map(|e| e.eval_for(float_type)),
+            ..self.bounds
+        };
+        let has_nonzero_condition = self.excludes_zero && self.bounds.contains(0.0);
+        Self {
+            sign: bounds.sign(),
+            excludes_zero: has_nonzero_condition || !bounds.contains(0.0),
+            bounds,
+            ..self.clone()
+        }
+    }
+
+    /// Check if every value admitted by `self` is also admitted by `other`
+    ///
+    /// Exclusive bounds are honored: `(0, 1)` is a subset of `[0, 1]`, but not vice versa.
+    pub fn is_subset_of(&self, other: &Self) -> bool {
+        // Zero exclusion requirements must be compatible
+        let zero_compatible = !other.excludes_zero || self.excludes_zero;
+        self.bounds.is_subset_of(&other.bounds) && zero_compatible
+    }
+
+    /// Check if `other` admits exactly the negations of the values admitted by `self`
+    ///
+    /// Bounds are compared exactly, so `-PI` only mirrors `PI` and not `3.14159`.
+    pub fn is_negation_of(&self, other: &Self) -> bool {
+        self.bounds.negated() == other.bounds && self.excludes_zero == other.excludes_zero
+    }
+
+    /// Check if no value can satisfy this constraint
+    pub fn is_unsatisfiable(&self) -> bool {
+        let only_zero = self.bounds.lower == Some(0.0) && self.bounds.upper == Some(0.0);
+        self.bounds.is_empty() || (self.excludes_zero && only_zero)
+    }
+}
+
 /// Type definition (single constraint).
 pub struct TypeDef {
     /// Type name.
@@ -115,12 +359,15 @@ pub struct TypeDef {
     pub float_types: Vec<Ident>,
     /// Constraint name.
     pub constraint_name: Ident,
+    /// Whether this type is defined by the current invocation (as opposed to a
+    /// built-in type that custom types interoperate with).
+    pub is_local: bool,
 }
 
 /// Gets standard arithmetic operator definition array
 ///
-/// Contains four basic arithmetic operations (addition, subtraction, multiplication, division)
-/// and their corresponding:
+/// Contains the five arithmetic operators (addition, subtraction, multiplication, division,
+/// remainder) and their corresponding:
 /// - Operator enum
 /// - Trait name (e.g., "Add")
 /// - Method name (e.g., "add")
@@ -134,7 +381,7 @@ pub fn get_standard_arithmetic_ops() -> [(
     &'static str,
     &'static str,
     proc_macro2::TokenStream,
-); 4] {
+); 5] {
     use quote::quote;
 
     [
@@ -142,6 +389,35 @@ pub fn get_standard_arithmetic_ops() -> [(
         (ArithmeticOp::Sub, "Sub", "sub", quote! { - }),
         (ArithmeticOp::Mul, "Mul", "mul", quote! { * }),
         (ArithmeticOp::Div, "Div", "div", quote! { / }),
+        (ArithmeticOp::Rem, "Rem", "rem", quote! { % }),
+    ]
+}
+
+/// Gets Euclidean division operation definition array
+///
+/// Same format as [`get_standard_arithmetic_ops`], except that the last element is the
+/// path of the `Float` method computing the operation instead of an operator symbol.
+pub fn get_euclid_ops() -> [(
+    ArithmeticOp,
+    &'static str,
+    &'static str,
+    proc_macro2::TokenStream,
+); 2] {
+    use quote::quote;
+
+    [
+        (
+            ArithmeticOp::RemEuclid,
+            "RemEuclid",
+            "rem_euclid",
+            quote! { Float::rem_euclid },
+        ),
+        (
+            ArithmeticOp::DivEuclid,
+            "DivEuclid",
+            "div_euclid",
+            quote! { Float::div_euclid },
+        ),
     ]
 }
 
@@ -156,4 +432,6 @@ pub struct TypeAliasDef {
     pub original_name: Ident,
     /// Alias name (e.g., Pos)
     pub alias_name: Ident,
+    /// Whether this alias is defined by the current invocation.
+    pub is_local: bool,
 }
@@ -5,9 +5,6 @@
 use proc_macro2::{Ident, Span};
 use quote::quote;
 
-use crate::config::{ConstraintDef, TypeConfig};
-use crate::generator::for_all_constraint_float_types;
-
 /// Constant definition structure
 struct ConstantDef {
     /// Constant name (e.g., "ZERO", "PI")
@@ -18,7 +15,7 @@ struct ConstantDef {
     f32_expr: Option<&'static str>,
     /// f64 value expression (None means use literal value)
     f64_expr: Option<&'static str>,
-    /// Literal value (for constraint checking)
+    /// Literal value (used when no expression is given)
     literal_value: f64,
 }
 
@@ -155,50 +152,16 @@ const ALL_CONSTANTS: &[ConstantDef] = &[
     },
 ];
 
-/// Checks if a constant value matches the type constraint
-fn constant_matches_constraint(value: f64, constraint_def: &ConstraintDef) -> bool {
-    // 1. Check if finite (all constants are finite, this check always passes)
-    if !value.is_finite() {
-        return false;
-    }
-
-    // 2. Check bounds
-    if let Some(lower) = constraint_def.bounds.lower {
-        if value < lower {
-            return false;
-        }
-    }
-    if let Some(upper) = constraint_def.bounds.upper {
-        if value > upper {
-            return false;
-        }
-    }
-
-    // 3. Check zero exclusion
-    if constraint_def.excludes_zero && value == 0.0 {
-        return false;
-    }
-
-    true
-}
-
 /// Generates constants for all types
-pub fn generate_constants(config: &TypeConfig) -> proc_macro2::TokenStream {
-    let impls = for_all_constraint_float_types(config, |type_name, float_type, constraint_def| {
-        let struct_name = crate::generator::make_type_alias(type_name, float_type);
-
-        // Filter constants applicable to this type
-        let applicable_constants: Vec<_> = ALL_CONSTANTS
-            .iter()
-            .filter(|c| constant_matches_constraint(c.literal_value, constraint_def))
-            .collect();
-
-        if applicable_constants.is_empty() {
-            return quote! {};
-        }
+///
+/// Each constant is defined through `new_const`, so using one that does not satisfy
+/// the constraint (e.g. `PositiveF64::ZERO`) fails at compile time.
+pub fn generate_constants() -> proc_macro2::TokenStream {
+    let impls = ["f32", "f64"].iter().map(|float_type| {
+        let float_ident = Ident::new(float_type, Span::call_site());
 
         // Generate code for each constant
-        let constant_defs = applicable_constants.iter().map(|const_def| {
+        let constant_defs = ALL_CONSTANTS.iter().map(|const_def| {
             let name = Ident::new(const_def.name, Span::call_site());
             let doc = const_def.doc;
 
@@ -232,14 +195,13 @@ pub fn generate_constants(config: &TypeConfig) -> proc_macro2::TokenStream {
 
             quote! {
                 #[doc = #doc]
-                #[must_use]
-                pub const #name: Self = unsafe { Self::new_unchecked(#value_expr) };
+                pub const #name: Self = Self::new_const(#value_expr);
             }
         });
 
         quote! {
             #[expect(clippy::approx_constant)]
-            impl #struct_name {
+            impl<C: Constraint> Strict<#float_ident, C> {
                 #(#constant_defs)*
             }
         }
@@ -0,0 +1,135 @@
+//! Constraint marker generation module
+//!
+//! Generates the zero-sized marker of each constraint type and its `Constraint`
+//! implementation, which carries the bounds of the type as associated consts.
+
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+
+use crate::config::{ConstraintDef, TypeConfig};
+use crate::doc_generator::generate_constraint_description;
+use crate::float_conversion::widens_losslessly;
+use crate::generator::{find_constraint_def, find_float_constraint_def, make_type_alias};
+use crate::unary_ops::{infer_abs_output_type, infer_signum_output_type};
+
+/// Path to the `Sealed` supertrait as seen from the generated code
+fn sealed_path(config: &TypeConfig) -> TokenStream {
+    config.crate_path.as_ref().map_or_else(
+        || quote! { __private::Sealed },
+        |krate| quote! { #krate::__private::Sealed },
+    )
+}
+
+/// Generates zero-sized constraint marker types dynamically from config
+pub fn generate_constraint_markers(config: &TypeConfig) -> TokenStream {
+    let markers = config.local_types().map(|type_def| {
+        let name = &type_def.constraint_name;
+        let constraint_def = find_constraint_def(config, name);
+        let f32_alias = make_type_alias(name, &format_ident!("f32"));
+        let f64_alias = make_type_alias(name, &format_ident!("f64"));
+        let doc = format!(
+            "Constraint of [`{f32_alias}`] and [`{f64_alias}`]: {} values",
+            generate_constraint_description(constraint_def)
+        );
+
+        quote! {
+            #[doc = #doc]
+            #[derive(Debug, Clone, Copy)]
+            pub struct #name;
+        }
+    });
+
+    quote! {
+        #(#markers)*
+    }
+}
+
+/// Builds the `Bounds` of a constraint as seen by `float_type`
+fn build_bounds(config: &TypeConfig, name: &Ident, float_type: &Ident) -> TokenStream {
+    let constraint_def: &ConstraintDef = find_float_constraint_def(config, name, float_type);
+    let bounds = &constraint_def.bounds;
+
+    let lower = constraint_def.lower_expr.as_ref().map_or_else(
+        || quote! { None },
+        |expr| {
+            let value = expr.to_tokens(float_type);
+            quote! { Some(#value) }
+        },
+    );
+    let upper = constraint_def.upper_expr.as_ref().map_or_else(
+        || quote! { None },
+        |expr| {
+            let value = expr.to_tokens(float_type);
+            quote! { Some(#value) }
+        },
+    );
+    let lower_inclusive = bounds.lower_inclusive;
+    let upper_inclusive = bounds.upper_inclusive;
+    let excludes_zero = constraint_def.excludes_zero;
+
+    quote! {
+        Bounds {
+            lower: #lower,
+            upper: #upper,
+            lower_inclusive: #lower_inclusive,
+            upper_inclusive: #upper_inclusive,
+            excludes_zero: #excludes_zero,
+        }
+    }
+}
+
+/// Generates the `Constraint` implementation of every constraint type
+///
+/// Constraints whose f32 values all fit the f64 bounds also get `LosslessWidening`,
+/// and constraints defined with `define_constraints!` get `Custom`.
+pub fn generate_constraint_impls(config: &TypeConfig) -> TokenStream {
+    let sealed = sealed_path(config);
+    let f32_ident = format_ident!("f32");
+    let f64_ident = format_ident!("f64");
+
+    let impls = config.local_types().map(|type_def| {
+        let name = &type_def.constraint_name;
+        let name_str = name.to_string();
+        let constraint_def = find_constraint_def(config, name);
+        let description = generate_constraint_description(constraint_def);
+        let f32_type_name = make_type_alias(name, &f32_ident).to_string();
+        let f64_type_name = make_type_alias(name, &f64_ident).to_string();
+        let f32_bounds = build_bounds(config, name, &f32_ident);
+        let f64_bounds = build_bounds(config, name, &f64_ident);
+        let abs_output = infer_abs_output_type(constraint_def, config);
+        let signum_output = infer_signum_output_type(constraint_def, config);
+
+        let lossless_impl =
+            if widens_losslessly(config, find_float_constraint_def(config, name, &f32_ident)) {
+                quote! { impl LosslessWidening for #name {} }
+            } else {
+                quote! {}
+            };
+
+        let custom_impl = config.crate_path.as_ref().map(|krate| {
+            quote! { impl #krate::__private::Custom for #name {} }
+        });
+
+        quote! {
+            impl #sealed for #name {}
+            #custom_impl
+
+            impl Constraint for #name {
+                const NAME: &'static str = #name_str;
+                const DESCRIPTION: &'static str = #description;
+                const F32_TYPE_NAME: &'static str = #f32_type_name;
+                const F64_TYPE_NAME: &'static str = #f64_type_name;
+                const F32_BOUNDS: Bounds<f32> = #f32_bounds;
+                const F64_BOUNDS: Bounds<f64> = #f64_bounds;
+                type Abs = #abs_output;
+                type Signum = #signum_output;
+            }
+
+            #lossless_impl
+        }
+    });
+
+    quote! {
+        #(#impls)*
+    }
+}
@@ -0,0 +1,103 @@
+//! `ConstraintInfo` trait generation module
+//!
+//! Generates the `ConstraintInfo` trait, which exposes the bounds of every generated
+//! type as associated consts, and its implementations for both float widths.
+
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+
+/// Generates the `ConstraintInfo` trait definition
+fn generate_constraint_info_trait() -> TokenStream {
+    quote! {
+        /// Compile-time metadata of a constrained float type
+        ///
+        /// Exposes the bounds of a concrete type such as [`NormalizedF32`], rounded to its
+        /// float width, for UI sliders, validation messages and schema export.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use strict_num_extended::{ConstraintInfo, NormalizedF64, PositiveF32};
+        ///
+        /// assert_eq!(NormalizedF64::NAME, "Normalized");
+        /// assert_eq!(NormalizedF64::LOWER, Some(0.0));
+        /// assert_eq!(NormalizedF64::UPPER, Some(1.0));
+        /// assert!(NormalizedF64::contains(0.5));
+        ///
+        /// assert_eq!(PositiveF32::UPPER, None);
+        /// assert!(!PositiveF32::LOWER_INCLUSIVE);
+        /// assert!(!PositiveF32::contains(0.0));
+        /// ```
+        pub trait ConstraintInfo {
+            /// Underlying primitive type
+            type Raw: Float;
+
+            /// Name of the constraint, e.g. `"Positive"`
+            const NAME: &'static str;
+
+            /// Human-readable description of the admitted values, e.g. `"range [0, 1]"`
+            const DESCRIPTION: &'static str;
+
+            /// Lower bound, or `None` if unbounded below
+            const LOWER: Option<Self::Raw>;
+
+            /// Upper bound, or `None` if unbounded above
+            const UPPER: Option<Self::Raw>;
+
+            /// Whether the lower bound itself is admitted
+            const LOWER_INCLUSIVE: bool;
+
+            /// Whether the upper bound itself is admitted
+            const UPPER_INCLUSIVE: bool;
+
+            /// Whether zero (and negative zero) is rejected
+            const EXCLUDES_ZERO: bool;
+
+            /// Checks whether a raw value would be accepted by `new`
+            fn contains(raw: Self::Raw) -> bool;
+        }
+    }
+}
+
+/// Generates the `ConstraintInfo` implementations for `f32` and `f64`
+fn generate_constraint_info_impls() -> TokenStream {
+    let impls = ["f32", "f64"].iter().map(|float_type| {
+        let float_ident = Ident::new(float_type, Span::call_site());
+        let bounds = Ident::new(
+            &format!("{}_BOUNDS", float_type.to_uppercase()),
+            Span::call_site(),
+        );
+
+        quote! {
+            impl<C: Constraint> ConstraintInfo for Strict<#float_ident, C> {
+                type Raw = #float_ident;
+
+                const NAME: &'static str = C::NAME;
+                const DESCRIPTION: &'static str = C::DESCRIPTION;
+                const LOWER: Option<#float_ident> = C::#bounds.lower;
+                const UPPER: Option<#float_ident> = C::#bounds.upper;
+                const LOWER_INCLUSIVE: bool = C::#bounds.lower_inclusive;
+                const UPPER_INCLUSIVE: bool = C::#bounds.upper_inclusive;
+                const EXCLUDES_ZERO: bool = C::#bounds.excludes_zero;
+
+                #[inline]
+                fn contains(raw: #float_ident) -> bool {
+                    C::#bounds.contains(raw)
+                }
+            }
+        }
+    });
+
+    quote! { #(#impls)* }
+}
+
+/// Generates the `ConstraintInfo` trait and its implementations
+pub fn generate_constraint_info() -> TokenStream {
+    let info_trait = generate_constraint_info_trait();
+    let info_impls = generate_constraint_info_impls();
+
+    quote! {
+        #info_trait
+        #info_impls
+    }
+}
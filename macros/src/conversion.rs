@@ -3,8 +3,9 @@
 //! Automatically generates standard library trait implementations for all type conversions
 
 use crate::config::TypeConfig;
+use crate::float_conversion::widens_losslessly;
 use crate::generator::{
-    filter_constraint_types_by_float, find_constraint_def, for_all_constraint_float_types,
+    filter_constraint_types_by_float, find_float_constraint_def, for_all_constraint_float_types,
 };
 use proc_macro2::Ident;
 use quote::{format_ident, quote};
@@ -14,67 +15,35 @@ pub fn generate_conversion_traits(config: &TypeConfig) -> proc_macro2::TokenStre
     let mut all_code = vec![];
 
     // 1. Constraint type → Primitive (From)
-    all_code.push(generate_constraint_to_primitive_from(config));
+    all_code.push(generate_constraint_to_primitive_from());
 
-    // 2. Primitive → Constraint type (TryFrom)
-    all_code.push(generate_primitive_to_constraint_tryfrom(config));
+    // 2. Primitive → Constraint type (TryFrom, including f32 → F64 and f64 → F32)
+    all_code.push(generate_primitive_to_constraint_tryfrom());
 
     // 3. Constraint type → Constraint type (From/TryFrom)
     all_code.push(generate_constraint_to_constraint_traits(config));
 
-    // 4. F32 → F64 (From)
-    all_code.push(generate_f32_to_f64_from(config));
+    // 4. Custom type ↔ Built-in type (From)
+    all_code.push(generate_custom_from(config));
 
-    // 5. F64 → F32 (TryFrom)
-    all_code.push(generate_f64_to_f32_tryfrom(config));
+    // 5. F32 → F64 (From, or TryFrom when rounding widens the f32 bounds)
+    all_code.push(generate_f32_to_f64_traits(config));
 
-    // 6. f32 → F64 constraint type (TryFrom)
-    all_code.push(generate_f32_to_f64_constraint_tryfrom(config));
-
-    // 7. f64 → F32 constraint type (TryFrom)
-    all_code.push(generate_f64_to_f32_constraint_tryfrom(config));
+    // 6. F64 → F32 (TryFrom)
+    all_code.push(generate_f64_to_f32_tryfrom());
 
     quote! { #(#all_code)* }
 }
 
-/// Check if source constraint is contained in target constraint (always safe)
-fn is_subset_constraint(
-    src_lower: Option<f64>,
-    src_upper: Option<f64>,
-    src_excludes_zero: bool,
-    dst_lower: Option<f64>,
-    dst_upper: Option<f64>,
-    dst_excludes_zero: bool,
-) -> bool {
-    // 1. Target lower bound must be <= source lower bound
-    let lower_contains = match (src_lower, dst_lower) {
-        (Some(src), Some(dst)) => src >= dst,
-        (None, Some(_)) => false,
-        _ => true,
-    };
-
-    // 2. Target upper bound must be >= source upper bound
-    let upper_contains = match (src_upper, dst_upper) {
-        (Some(src), Some(dst)) => src <= dst,
-        (None, Some(_)) => false,
-        _ => true,
-    };
-
-    // 3. Zero exclusion requirements must be compatible
-    let zero_compatible = !dst_excludes_zero || src_excludes_zero;
-
-    lower_contains && upper_contains && zero_compatible
-}
-
 /// Generate: Constraint type → Primitive (From)
-fn generate_constraint_to_primitive_from(config: &TypeConfig) -> proc_macro2::TokenStream {
-    let impls = for_all_constraint_float_types(config, |type_name, float_type, _| {
-        let alias = format_ident!("{}{}", type_name, float_type.to_string().to_uppercase());
+fn generate_constraint_to_primitive_from() -> proc_macro2::TokenStream {
+    let impls = ["f32", "f64"].iter().map(|float_type| {
+        let float_ident = Ident::new(float_type, proc_macro2::Span::call_site());
 
         quote! {
-            impl From<#alias> for #float_type {
+            impl<C: Constraint> From<Strict<#float_ident, C>> for #float_ident {
                 #[inline]
-                fn from(value: #alias) -> Self {
+                fn from(value: Strict<#float_ident, C>) -> Self {
                     value.get()
                 }
             }
@@ -85,29 +54,39 @@ fn generate_constraint_to_primitive_from(config: &TypeConfig) -> proc_macro2::To
 }
 
 /// Generate: Primitive → Constraint type (`TryFrom`)
-fn generate_primitive_to_constraint_tryfrom(config: &TypeConfig) -> proc_macro2::TokenStream {
-    let impls = for_all_constraint_float_types(config, |type_name, float_type, _| {
-        let alias = format_ident!("{}{}", type_name, float_type.to_string().to_uppercase());
-
-        quote! {
-            impl TryFrom<#float_type> for #alias {
-                type Error = FloatError;
-
-                #[inline]
-                fn try_from(value: #float_type) -> Result<Self, Self::Error> {
-                    Self::new(value)
+fn generate_primitive_to_constraint_tryfrom() -> proc_macro2::TokenStream {
+    let impls = ["f32", "f64"].iter().flat_map(|src_type| {
+        ["f32", "f64"].iter().map(move |dst_type| {
+            let src_ident = Ident::new(src_type, proc_macro2::Span::call_site());
+            let dst_ident = Ident::new(dst_type, proc_macro2::Span::call_site());
+
+            quote! {
+                impl<C: Constraint> TryFrom<#src_ident> for Strict<#dst_ident, C> {
+                    type Error = FloatError;
+
+                    #[inline]
+                    fn try_from(value: #src_ident) -> Result<Self, Self::Error> {
+                        Self::new(value as #dst_ident)
+                    }
                 }
             }
-        }
+        })
     });
 
     quote! { #(#impls)* }
 }
 
 /// Generate: Constraint type → Constraint type (From/TryFrom)
+///
+/// Only built-in types get these impls: the orphan rule forbids them in the crate
+/// defining custom types, which get the generic impls of [`generate_custom_from`].
 fn generate_constraint_to_constraint_traits(config: &TypeConfig) -> proc_macro2::TokenStream {
     let mut all_impls = vec![];
 
+    if config.crate_path.is_some() {
+        return quote! {};
+    }
+
     // Generate conversions for each float type
     for float_type in &["f32", "f64"] {
         let float_ident = Ident::new(float_type, proc_macro2::Span::call_site());
@@ -133,19 +112,14 @@ fn generate_constraint_to_constraint_traits(config: &TypeConfig) -> proc_macro2:
                     float_type.to_string().to_uppercase()
                 );
 
-                // Use helper function to find constraint definitions
-                let src_constraint = find_constraint_def(config, &src_type.constraint_name);
-                let dst_constraint = find_constraint_def(config, &dst_type.constraint_name);
+                // Use helper function to find constraint definitions (rounded to this width)
+                let src_constraint =
+                    find_float_constraint_def(config, &src_type.constraint_name, &float_ident);
+                let dst_constraint =
+                    find_float_constraint_def(config, &dst_type.constraint_name, &float_ident);
 
                 // Check if subset relationship
-                let is_safe = is_subset_constraint(
-                    src_constraint.bounds.lower,
-                    src_constraint.bounds.upper,
-                    src_constraint.excludes_zero,
-                    dst_constraint.bounds.lower,
-                    dst_constraint.bounds.upper,
-                    dst_constraint.excludes_zero,
-                );
+                let is_safe = src_constraint.is_subset_of(dst_constraint);
 
                 if is_safe {
                     // From implementation
@@ -153,7 +127,7 @@ fn generate_constraint_to_constraint_traits(config: &TypeConfig) -> proc_macro2:
                         impl From<#src_alias> for #dst_alias {
                             #[inline]
                             fn from(value: #src_alias) -> Self {
-                                unsafe { Self::new_unchecked(value.get()) }
+                                value.convert()
                             }
                         }
                     });
@@ -165,7 +139,7 @@ fn generate_constraint_to_constraint_traits(config: &TypeConfig) -> proc_macro2:
 
                             #[inline]
                             fn try_from(value: #src_alias) -> Result<Self, Self::Error> {
-                                Self::new(value.get())
+                                value.try_convert()
                             }
                         }
                     });
@@ -177,46 +151,36 @@ fn generate_constraint_to_constraint_traits(config: &TypeConfig) -> proc_macro2:
     quote! { #(#all_impls)* }
 }
 
-/// Generate: F32 → F64 (From)
-fn generate_f32_to_f64_from(config: &TypeConfig) -> proc_macro2::TokenStream {
-    let impls = for_all_constraint_float_types(config, |type_name, float_type, _| {
-        if *float_type != "f32" {
-            return quote! {};
-        }
-
-        let f32_alias = format_ident!("{}F32", type_name);
-        let f64_alias = format_ident!("{}F64", type_name);
+/// Generate: Custom type ↔ Built-in type (From)
+///
+/// Generic over the custom constraint, with one impl per direction and built-in type.
+/// They can't overlap with the reflexive `From` since built-in constraints are never
+/// `Custom`. Fallible conversions have no `TryFrom`: it would overlap with the blanket
+/// impl derived from `From`, so custom types use `try_convert()` for them.
+fn generate_custom_from(config: &TypeConfig) -> proc_macro2::TokenStream {
+    if config.crate_path.is_some() {
+        return quote! {};
+    }
 
+    let impls = config.constraint_types.iter().map(|type_def| {
+        let builtin = &type_def.constraint_name;
         quote! {
-            impl From<#f32_alias> for #f64_alias {
+            impl<F: Float, C: __private::Custom + SubsetOf<F, #builtin>> From<Strict<F, C>>
+                for Strict<F, #builtin>
+            {
                 #[inline]
-                fn from(value: #f32_alias) -> Self {
-                    value.as_f64_type()
+                fn from(value: Strict<F, C>) -> Self {
+                    value.convert()
                 }
             }
-        }
-    });
-
-    quote! { #(#impls)* }
-}
-
-/// Generate: F64 → F32 (`TryFrom`)
-fn generate_f64_to_f32_tryfrom(config: &TypeConfig) -> proc_macro2::TokenStream {
-    let impls = for_all_constraint_float_types(config, |type_name, float_type, _| {
-        if *float_type != "f64" {
-            return quote! {};
-        }
-
-        let f32_alias = format_ident!("{}F32", type_name);
-        let f64_alias = format_ident!("{}F64", type_name);
-
-        quote! {
-            impl TryFrom<#f64_alias> for #f32_alias {
-                type Error = FloatError;
 
+            impl<F: Float, C: __private::Custom> From<Strict<F, #builtin>> for Strict<F, C>
+            where
+                #builtin: SubsetOf<F, C>,
+            {
                 #[inline]
-                fn try_from(value: #f64_alias) -> Result<Self, Self::Error> {
-                    value.try_into_f32_type()
+                fn from(value: Strict<F, #builtin>) -> Self {
+                    value.convert()
                 }
             }
         }
@@ -225,50 +189,92 @@ fn generate_f64_to_f32_tryfrom(config: &TypeConfig) -> proc_macro2::TokenStream
     quote! { #(#impls)* }
 }
 
-/// Generate: f32 → F64 constraint type (`TryFrom`)
-fn generate_f32_to_f64_constraint_tryfrom(config: &TypeConfig) -> proc_macro2::TokenStream {
-    let impls = for_all_constraint_float_types(config, |type_name, float_type, _| {
-        if *float_type != "f64" {
-            return quote! {};
-        }
+/// Generate: F32 → F64 (From, or `TryFrom` when rounding widens the f32 bounds)
+///
+/// `From` is implemented for every `LosslessWidening` constraint; the remaining
+/// built-in types get a concrete `TryFrom`.
+fn generate_f32_to_f64_traits(config: &TypeConfig) -> proc_macro2::TokenStream {
+    let fallible_impls = if config.crate_path.is_some() {
+        Vec::new()
+    } else {
+        for_all_constraint_float_types(config, |type_name, float_type, constraint_def| {
+            if *float_type != "f32" || widens_losslessly(config, constraint_def) {
+                return quote! {};
+            }
 
-        let f64_alias = format_ident!("{}F64", type_name);
+            let f32_alias = format_ident!("{}F32", type_name);
+            let f64_alias = format_ident!("{}F64", type_name);
 
-        quote! {
-            impl TryFrom<f32> for #f64_alias {
-                type Error = FloatError;
+            quote! {
+                impl TryFrom<#f32_alias> for #f64_alias {
+                    type Error = FloatError;
 
-                #[inline]
-                fn try_from(value: f32) -> Result<Self, Self::Error> {
-                    Self::new(value as f64)
+                    #[inline]
+                    fn try_from(value: #f32_alias) -> Result<Self, Self::Error> {
+                        value.try_into_f64_type()
+                    }
                 }
             }
+        })
+    };
+
+    quote! {
+        impl<C: LosslessWidening> From<Strict<f32, C>> for Strict<f64, C> {
+            #[inline]
+            fn from(value: Strict<f32, C>) -> Self {
+                value.as_f64_type()
+            }
         }
-    });
 
-    quote! { #(#impls)* }
+        #(#fallible_impls)*
+    }
 }
 
-/// Generate: f64 → F32 constraint type (`TryFrom`)
-fn generate_f64_to_f32_constraint_tryfrom(config: &TypeConfig) -> proc_macro2::TokenStream {
-    let impls = for_all_constraint_float_types(config, |type_name, float_type, _| {
-        if *float_type != "f32" {
-            return quote! {};
+/// Generate: F64 → F32 (`TryFrom`)
+fn generate_f64_to_f32_tryfrom() -> proc_macro2::TokenStream {
+    quote! {
+        impl<C: Constraint> TryFrom<Strict<f64, C>> for Strict<f32, C> {
+            type Error = FloatError;
+
+            #[inline]
+            fn try_from(value: Strict<f64, C>) -> Result<Self, Self::Error> {
+                value.try_into_f32_type()
+            }
         }
+    }
+}
 
-        let f32_alias = format_ident!("{}F32", type_name);
+/// Generate `SubsetOf` for every pair of constraints where the first one is a subset
+///
+/// This includes each constraint with itself, so `convert()` also works as an identity.
+pub fn generate_subset_impls(config: &TypeConfig) -> proc_macro2::TokenStream {
+    let mut all_impls = vec![];
 
-        quote! {
-            impl TryFrom<f64> for #f32_alias {
-                type Error = FloatError;
+    for float_type in &["f32", "f64"] {
+        let float_ident = Ident::new(float_type, proc_macro2::Span::call_site());
+        let types = filter_constraint_types_by_float(config, &float_ident);
 
-                #[inline]
-                fn try_from(value: f64) -> Result<Self, Self::Error> {
-                    Self::new(value as f32)
+        for src_type in &types {
+            for dst_type in &types {
+                if !src_type.is_local && !dst_type.is_local {
+                    continue; // Both built-in: already implemented by the main crate
+                }
+
+                let src_constraint =
+                    find_float_constraint_def(config, &src_type.constraint_name, &float_ident);
+                let dst_constraint =
+                    find_float_constraint_def(config, &dst_type.constraint_name, &float_ident);
+
+                if src_constraint.is_subset_of(dst_constraint) {
+                    let src = &src_type.constraint_name;
+                    let dst = &dst_type.constraint_name;
+                    all_impls.push(quote! {
+                        impl SubsetOf<#float_ident, #dst> for #src {}
+                    });
                 }
             }
         }
-    });
+    }
 
-    quote! { #(#impls)* }
+    quote! { #(#all_impls)* }
 }
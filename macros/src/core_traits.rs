@@ -0,0 +1,844 @@
+//! Core trait generation module
+//!
+//! Generates the sealed `Float` and `Constraint` traits, the `Bounds` they carry,
+//! and the traits through which arithmetic output types are resolved.
+
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+
+use crate::config::{get_euclid_ops, get_standard_arithmetic_ops};
+
+/// Generates the hidden module backing sealed traits and `define_constraints!`
+pub fn generate_private_module() -> TokenStream {
+    quote! {
+        #[doc(hidden)]
+        pub mod __private {
+            //! Items used by generated code; not part of the public API.
+
+            /// Supertrait preventing implementations outside generated code
+            pub trait Sealed {}
+
+            /// Marks constraints defined with `define_constraints!`
+            ///
+            /// Lets the main crate implement `From` between them and the built-in types,
+            /// which the orphan rule forbids in the crate defining them.
+            pub trait Custom: crate::Constraint {}
+
+            pub use strict_num_extended_macros::define_custom_constraints;
+
+            #[cfg(feature = "serde")]
+            pub use serde;
+        }
+    }
+}
+
+/// Generates the `Bounds` struct with a `const` membership test for each width
+fn generate_bounds() -> TokenStream {
+    let contains_impls = ["f32", "f64"].iter().map(|float_type| {
+        let float_ident = Ident::new(float_type, Span::call_site());
+        quote! {
+            impl Bounds<#float_ident> {
+                /// Checks whether a value is finite and satisfies these bounds
+                #[must_use]
+                pub const fn contains(&self, value: #float_ident) -> bool {
+                    if !value.is_finite() {
+                        return false;
+                    }
+                    let above_lower = match self.lower {
+                        Some(lower) if self.lower_inclusive => value >= lower,
+                        Some(lower) => value > lower,
+                        None => true,
+                    };
+                    let below_upper = match self.upper {
+                        Some(upper) if self.upper_inclusive => value <= upper,
+                        Some(upper) => value < upper,
+                        None => true,
+                    };
+                    above_lower && below_upper && !(self.excludes_zero && value == 0.0)
+                }
+
+                /// Finds the bound a finite value violates, if any
+                ///
+                /// The lower bound is checked first, then the upper bound, then zero
+                /// exclusion.
+                #[must_use]
+                pub const fn violated_bound(&self, value: #float_ident) -> Option<ViolatedBound> {
+                    match self.lower {
+                        Some(lower) if (self.lower_inclusive && value < lower)
+                            || (!self.lower_inclusive && value <= lower) => {
+                            return Some(ViolatedBound::Lower {
+                                bound: lower as f64,
+                                inclusive: self.lower_inclusive,
+                            });
+                        }
+                        _ => {}
+                    }
+                    match self.upper {
+                        Some(upper) if (self.upper_inclusive && value > upper)
+                            || (!self.upper_inclusive && value >= upper) => {
+                            return Some(ViolatedBound::Upper {
+                                bound: upper as f64,
+                                inclusive: self.upper_inclusive,
+                            });
+                        }
+                        _ => {}
+                    }
+                    if self.excludes_zero && value == 0.0 {
+                        return Some(ViolatedBound::Zero);
+                    }
+                    None
+                }
+
+                /// Clamps a value to the nearest one satisfying these bounds
+                ///
+                /// Infinities saturate to the bounds or to `MAX`/`MIN`, NaN is treated as
+                /// zero, and an excluded zero (including underflow onto an exclusive zero
+                /// bound) becomes `MIN_POSITIVE` with the sign of the value.
+                #[must_use]
+                pub const fn saturate(&self, value: #float_ident) -> #float_ident {
+                    let mut value = if value.is_nan() { 0.0 } else { value };
+                    if self.excludes_zero && value == 0.0 {
+                        value = if value.is_sign_negative() {
+                            -#float_ident::MIN_POSITIVE
+                        } else {
+                            #float_ident::MIN_POSITIVE
+                        };
+                    }
+                    value = match self.lower {
+                        Some(lower) if self.lower_inclusive && value < lower => lower,
+                        Some(lower) if !self.lower_inclusive && value <= lower => {
+                            Self::step_towards_positive(lower)
+                        }
+                        None if value < #float_ident::MIN => #float_ident::MIN,
+                        _ => value,
+                    };
+                    match self.upper {
+                        Some(upper) if self.upper_inclusive && value > upper => upper,
+                        Some(upper) if !self.upper_inclusive && value >= upper => {
+                            -Self::step_towards_positive(-upper)
+                        }
+                        None if value > #float_ident::MAX => #float_ident::MAX,
+                        _ => value,
+                    }
+                }
+
+                /// Gets the closest value above an exclusive bound (`MIN_POSITIVE` above zero)
+                const fn step_towards_positive(bound: #float_ident) -> #float_ident {
+                    if bound == 0.0 {
+                        #float_ident::MIN_POSITIVE
+                    } else if bound > 0.0 {
+                        #float_ident::from_bits(bound.to_bits() + 1)
+                    } else {
+                        #float_ident::from_bits(bound.to_bits() - 1)
+                    }
+                }
+            }
+        }
+    });
+
+    quote! {
+        /// Range of values admitted by a constraint, rounded to one float width
+        ///
+        /// An unbounded side is `None`; its inclusive flag is always `true`.
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        pub struct Bounds<F> {
+            /// Lower bound, if any
+            pub lower: Option<F>,
+            /// Upper bound, if any
+            pub upper: Option<F>,
+            /// Whether the lower bound itself is admitted
+            pub lower_inclusive: bool,
+            /// Whether the upper bound itself is admitted
+            pub upper_inclusive: bool,
+            /// Whether zero (and negative zero) is rejected
+            pub excludes_zero: bool,
+        }
+
+        #(#contains_impls)*
+    }
+}
+
+/// Generates the sealed `Float` trait and its implementations for `f32` and `f64`
+#[expect(clippy::too_many_lines)]
+fn generate_float_trait() -> TokenStream {
+    let float_impls = ["f32", "f64"].iter().map(|float_type| {
+        let float_ident = Ident::new(float_type, Span::call_site());
+        let bounds_const = Ident::new(
+            &format!("{}_BOUNDS", float_type.to_uppercase()),
+            Span::call_site(),
+        );
+        let type_name_const = Ident::new(
+            &format!("{}_TYPE_NAME", float_type.to_uppercase()),
+            Span::call_site(),
+        );
+        // Software fused multiply-add, roots and trigonometry used without `std`
+        let (libm_fma, libm_sqrt, libm_cbrt, libm_hypot) = if *float_type == "f32" {
+            (
+                quote! { libm::fmaf },
+                quote! { libm::sqrtf },
+                quote! { libm::cbrtf },
+                quote! { libm::hypotf },
+            )
+        } else {
+            (
+                quote! { libm::fma },
+                quote! { libm::sqrt },
+                quote! { libm::cbrt },
+                quote! { libm::hypot },
+            )
+        };
+        let (libm_sin, libm_cos, libm_tan) = if *float_type == "f32" {
+            (
+                quote! { libm::sinf },
+                quote! { libm::cosf },
+                quote! { libm::tanf },
+            )
+        } else {
+            (
+                quote! { libm::sin },
+                quote! { libm::cos },
+                quote! { libm::tan },
+            )
+        };
+        // Integer type holding every integral value below 2^(mantissa bits)
+        let (int_ident, exact_int_limit) = if *float_type == "f32" {
+            (Ident::new("i32", Span::call_site()), quote! { 8_388_608.0 })
+        } else {
+            (
+                Ident::new("i64", Span::call_site()),
+                quote! { 4_503_599_627_370_496.0 },
+            )
+        };
+        quote! {
+            impl __private::Sealed for #float_ident {}
+
+            impl Float for #float_ident {
+                const PI: Self = core::#float_ident::consts::PI;
+                const TAU: Self = core::#float_ident::consts::TAU;
+
+                #[inline]
+                fn satisfies<C: Constraint>(self) -> bool {
+                    C::#bounds_const.contains(self)
+                }
+
+                #[inline]
+                fn saturate<C: Constraint>(self) -> Self {
+                    C::#bounds_const.saturate(self)
+                }
+
+                #[inline]
+                fn type_name<C: Constraint>() -> &'static str {
+                    C::#type_name_const
+                }
+
+                #[inline]
+                fn violated_bound<C: Constraint>(self) -> Option<ViolatedBound> {
+                    C::#bounds_const.violated_bound(self)
+                }
+
+                #[inline]
+                fn from_f64(value: f64) -> Self {
+                    value as #float_ident
+                }
+
+                #[inline]
+                fn is_nan(self) -> bool {
+                    #float_ident::is_nan(self)
+                }
+
+                #[inline]
+                fn is_finite(self) -> bool {
+                    #float_ident::is_finite(self)
+                }
+
+                #[inline]
+                fn abs(self) -> Self {
+                    #float_ident::abs(self)
+                }
+
+                #[inline]
+                fn signum(self) -> Self {
+                    #float_ident::signum(self)
+                }
+
+                #[inline]
+                fn rem_euclid(self, rhs: Self) -> Self {
+                    let r = self % rhs;
+                    if r < 0.0 { r + rhs.abs() } else { r }
+                }
+
+                #[inline]
+                fn div_euclid(self, rhs: Self) -> Self {
+                    let q = self / rhs;
+                    // Values this large are integers (or infinite) already
+                    let q = if q.abs() < #exact_int_limit {
+                        let truncated = q as #int_ident as #float_ident;
+                        if truncated == 0.0 && q.is_sign_negative() { -0.0 } else { truncated }
+                    } else {
+                        q
+                    };
+                    if self % rhs < 0.0 {
+                        if rhs > 0.0 { q - 1.0 } else { q + 1.0 }
+                    } else {
+                        q
+                    }
+                }
+
+                #[cfg(feature = "std")]
+                #[inline]
+                fn mul_add(self, a: Self, b: Self) -> Self {
+                    #float_ident::mul_add(self, a, b)
+                }
+
+                #[cfg(not(feature = "std"))]
+                #[inline]
+                fn mul_add(self, a: Self, b: Self) -> Self {
+                    #libm_fma(self, a, b)
+                }
+
+                #[cfg(feature = "std")]
+                #[inline]
+                fn sqrt(self) -> Self {
+                    #float_ident::sqrt(self)
+                }
+
+                #[cfg(not(feature = "std"))]
+                #[inline]
+                fn sqrt(self) -> Self {
+                    #libm_sqrt(self)
+                }
+
+                #[cfg(feature = "std")]
+                #[inline]
+                fn cbrt(self) -> Self {
+                    #float_ident::cbrt(self)
+                }
+
+                #[cfg(not(feature = "std"))]
+                #[inline]
+                fn cbrt(self) -> Self {
+                    #libm_cbrt(self)
+                }
+
+                #[cfg(feature = "std")]
+                #[inline]
+                fn hypot(self, other: Self) -> Self {
+                    #float_ident::hypot(self, other)
+                }
+
+                #[cfg(not(feature = "std"))]
+                #[inline]
+                fn hypot(self, other: Self) -> Self {
+                    #libm_hypot(self, other)
+                }
+
+                #[cfg(feature = "std")]
+                #[inline]
+                fn sin(self) -> Self {
+                    #float_ident::sin(self)
+                }
+
+                #[cfg(not(feature = "std"))]
+                #[inline]
+                fn sin(self) -> Self {
+                    #libm_sin(self)
+                }
+
+                #[cfg(feature = "std")]
+                #[inline]
+                fn cos(self) -> Self {
+                    #float_ident::cos(self)
+                }
+
+                #[cfg(not(feature = "std"))]
+                #[inline]
+                fn cos(self) -> Self {
+                    #libm_cos(self)
+                }
+
+                #[cfg(feature = "std")]
+                #[inline]
+                fn tan(self) -> Self {
+                    #float_ident::tan(self)
+                }
+
+                #[cfg(not(feature = "std"))]
+                #[inline]
+                fn tan(self) -> Self {
+                    #libm_tan(self)
+                }
+            }
+        }
+    });
+
+    quote! {
+        /// Primitive float types that can back a [`Strict`] value: `f32` and `f64`
+        ///
+        /// This trait is sealed and cannot be implemented outside this crate.
+        pub trait Float:
+            __private::Sealed
+            + Copy
+            + PartialEq
+            + PartialOrd
+            + core::fmt::Debug
+            + core::fmt::Display
+            + core::str::FromStr<Err = core::num::ParseFloatError>
+            + Add<Output = Self>
+            + Sub<Output = Self>
+            + Mul<Output = Self>
+            + Div<Output = Self>
+            + Rem<Output = Self>
+            + Neg<Output = Self>
+            + IntoF64
+            + 'static
+        {
+            /// Archimedes' constant (π) at this width
+            const PI: Self;
+
+            /// The full circle constant (τ = 2π) at this width
+            const TAU: Self;
+
+            /// Checks whether the value satisfies constraint `C` at this width
+            fn satisfies<C: Constraint>(self) -> bool;
+
+            /// Clamps the value to the bounds of constraint `C` at this width
+            fn saturate<C: Constraint>(self) -> Self;
+
+            /// Name of the type constrained by `C` at this width, e.g. `"PositiveF64"`
+            fn type_name<C: Constraint>() -> &'static str;
+
+            /// Finds the bound of constraint `C` that the finite value violates, if any
+            fn violated_bound<C: Constraint>(self) -> Option<ViolatedBound>;
+
+            /// Converts from `f64`, rounding to the nearest value of this width
+            fn from_f64(value: f64) -> Self;
+
+            /// Returns `true` if the value is NaN
+            fn is_nan(self) -> bool;
+
+            /// Returns `true` if the value is neither infinite nor NaN
+            fn is_finite(self) -> bool;
+
+            /// Computes the absolute value
+            fn abs(self) -> Self;
+
+            /// Computes the sign of the value
+            fn signum(self) -> Self;
+
+            /// Computes the least non-negative remainder of `self / rhs`, as `f64::rem_euclid`
+            fn rem_euclid(self, rhs: Self) -> Self;
+
+            /// Computes the quotient of Euclidean division, as `f64::div_euclid`
+            fn div_euclid(self, rhs: Self) -> Self;
+
+            /// Computes `(self * a) + b` with a single rounding, as `f64::mul_add`
+            fn mul_add(self, a: Self, b: Self) -> Self;
+
+            /// Computes the square root, NaN for negative values
+            fn sqrt(self) -> Self;
+
+            /// Computes the cube root
+            fn cbrt(self) -> Self;
+
+            /// Computes `sqrt(self² + other²)` without intermediate overflow
+            fn hypot(self, other: Self) -> Self;
+
+            /// Computes the sine (in radians)
+            fn sin(self) -> Self;
+
+            /// Computes the cosine (in radians)
+            fn cos(self) -> Self;
+
+            /// Computes the tangent (in radians)
+            fn tan(self) -> Self;
+        }
+
+        #(#float_impls)*
+    }
+}
+
+/// Generates the sealed `Constraint` trait
+fn generate_constraint_trait() -> TokenStream {
+    quote! {
+        /// Marker types describing the values a [`Strict`] type admits
+        ///
+        /// Implemented by zero-sized markers such as [`Positive`], including those
+        /// declared with [`define_constraints!`](crate::define_constraints). The trait is
+        /// sealed: implementations are only produced by this crate's macros.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use strict_num_extended::*;
+        ///
+        /// fn describe<F: Float, C: Constraint>(x: Strict<F, C>) -> String {
+        ///     format!("{x} ({})", C::NAME)
+        /// }
+        ///
+        /// assert_eq!(describe(PositiveF64::new(2.5)?), "2.5 (Positive)");
+        /// assert_eq!(describe(NormalizedF32::new(0.5)?), "0.5 (Normalized)");
+        /// assert!(!Positive::F64_BOUNDS.contains(0.0));
+        /// # Ok::<(), FloatError>(())
+        /// ```
+        pub trait Constraint: __private::Sealed + Copy + core::fmt::Debug + 'static {
+            /// Name of the constraint, e.g. `"Positive"`
+            const NAME: &'static str;
+
+            /// Human-readable description of the admitted values, e.g. `"non-negative"`
+            const DESCRIPTION: &'static str;
+
+            /// Name of the `f32` type, e.g. `"PositiveF32"`
+            const F32_TYPE_NAME: &'static str;
+
+            /// Name of the `f64` type, e.g. `"PositiveF64"`
+            const F64_TYPE_NAME: &'static str;
+
+            /// Bounds as seen by `f32` values
+            const F32_BOUNDS: Bounds<f32>;
+
+            /// Bounds as seen by `f64` values
+            const F64_BOUNDS: Bounds<f64>;
+
+            /// Constraint satisfied by the absolute value
+            type Abs: Constraint;
+
+            /// Constraint satisfied by the sign function
+            type Signum: Constraint;
+        }
+
+        /// Constraint satisfied by the negation of values of `Self`
+        ///
+        /// Implemented when a constraint with exactly the mirrored bounds is defined in
+        /// the same invocation or among the built-in types. Bounds are compared exactly:
+        /// `>= -PI` mirrors `<= PI`, but not `<= 3.14159`.
+        ///
+        /// ```compile_fail
+        /// use strict_num_extended::*;
+        ///
+        /// // No type admits [-100, 0], so `Percent` values cannot be negated
+        /// define_constraints!([(Percent, [">= 0.0", "<= 100.0"])]);
+        ///
+        /// let _ = -PercentF64::new(5.0).unwrap();
+        /// ```
+        #[diagnostic::on_unimplemented(
+            message = "values constrained by `{Self}` cannot be negated",
+            label = "no constraint mirrors `{Self}` around zero",
+            note = "define a type whose bounds are exactly the negated bounds of `{Self}` to enable `Neg`"
+        )]
+        pub trait NegOutput: Constraint {
+            /// Constraint of the negated value
+            type Output: Constraint;
+        }
+
+        /// Marks that every `Strict<F, Self>` value is also a valid `Strict<F, Super>`
+        ///
+        /// Enables the infallible [`Strict::convert`].
+        pub trait SubsetOf<F: Float, Super: Constraint>: Constraint {}
+
+        /// Marks constraints whose `f32` values all satisfy the `f64` bounds
+        ///
+        /// Not implemented when a bound rounds outward in `f32`, such as `<= PI`.
+        pub trait LosslessWidening: Constraint {}
+    }
+}
+
+/// Generates the `OpOutput` trait and the per-operator output traits
+#[expect(clippy::too_many_lines)]
+fn generate_output_traits() -> TokenStream {
+    let operator_docs =
+        get_standard_arithmetic_ops()
+            .into_iter()
+            .map(|(_, trait_name, _, op_symbol)| {
+                (
+                    trait_name,
+                    format!("Output type of `Strict<F, Self> {op_symbol} Strict<F, Rhs>`"),
+                )
+            });
+    let euclid_docs = get_euclid_ops()
+        .into_iter()
+        .map(|(_, trait_name, method_name, _)| {
+            (
+                trait_name,
+                format!("Output type of `Strict<F, Self>::{method_name}(Strict<F, Rhs>)`"),
+            )
+        });
+    let op_traits = operator_docs.chain(euclid_docs).map(|(trait_name, doc)| {
+        let output_trait = Ident::new(&format!("{trait_name}Output"), Span::call_site());
+        quote! {
+            #[doc = #doc]
+            pub trait #output_trait<F: Float, Rhs: Constraint>: Constraint {
+                /// `Strict<F, _>` when the operation cannot fail, otherwise a `Result`
+                type Output: OpOutput<F>;
+            }
+        }
+    });
+
+    quote! {
+        /// Output of an arithmetic operation between [`Strict`] values
+        ///
+        /// Implemented by `Strict<F, C>` for operations proven safe and by
+        /// `Result<Strict<F, C>, FloatError>` for those that need validation.
+        pub trait OpOutput<F: Float>: __private::Sealed + Sized {
+            /// Constraint of the value produced on success
+            type Output: Constraint;
+
+            /// Output of `lhs op Option<rhs>`: `Option` when infallible, otherwise `Result`
+            type Optional;
+
+            /// Output of an operation that follows this one, fallible if either is
+            type Then<Next: OpOutput<F>>: OpOutput<F>;
+
+            /// Wraps `value`, the raw result of `operation`
+            ///
+            /// Fallible outputs validate the operands and the value, and classify a
+            /// failure as [`FloatError::InvalidOperand`], `DivisionByZero`, `NaN`,
+            /// `Overflow`, `Underflow`, `BelowRange`, `AboveRange` or `ExcludedZero`.
+            ///
+            /// # Safety
+            ///
+            /// `value` must be the result of the operation this output was inferred for.
+            unsafe fn from_operation(operation: Operation, value: F) -> Self;
+
+            /// Wraps `value`, the raw result of `operation`, recording the context of a
+            /// failure
+            ///
+            /// # Safety
+            ///
+            /// `value` must be the result of the operation this output was inferred for.
+            ///
+            /// # Errors
+            ///
+            /// Fallible outputs return the [`DetailedError`] of an operation that
+            /// [`from_operation`](Self::from_operation) would reject.
+            unsafe fn from_operation_detailed(
+                operation: Operation,
+                value: F,
+            ) -> Result<Strict<F, Self::Output>, DetailedError>;
+
+            /// Wraps `value`, the raw result of a unary operation
+            ///
+            /// Fallible outputs validate the value like [`Strict::new`].
+            ///
+            /// # Safety
+            ///
+            /// `value` must be the result of the operation this output was inferred for.
+            unsafe fn from_value(value: F) -> Self;
+
+            /// Wraps `value`, the raw result of a unary operation, recording the context
+            /// of a failure
+            ///
+            /// # Safety
+            ///
+            /// `value` must be the result of the operation this output was inferred for.
+            ///
+            /// # Errors
+            ///
+            /// Fallible outputs return the [`DetailedError`] of a value that
+            /// [`Strict::new_detailed`] would reject.
+            unsafe fn from_value_detailed(value: F) -> Result<Strict<F, Self::Output>, DetailedError>;
+
+            /// Converts into a `Result`
+            ///
+            /// # Errors
+            ///
+            /// Returns the error of a failed operation.
+            fn into_result(self) -> Result<Strict<F, Self::Output>, FloatError>;
+
+            /// Converts into the output of an operation whose operand was `Some`
+            fn into_optional(self) -> Self::Optional;
+
+            /// Output of an operation whose operand was `None`
+            fn none() -> Self::Optional;
+        }
+
+        impl<F: Float, C: Constraint> __private::Sealed for Strict<F, C> {}
+
+        impl<F: Float, C: Constraint> OpOutput<F> for Strict<F, C> {
+            type Output = C;
+            type Optional = Option<Self>;
+            type Then<Next: OpOutput<F>> = Next;
+
+            #[inline]
+            unsafe fn from_operation(_operation: Operation, value: F) -> Self {
+                // SAFETY: guaranteed by the caller
+                unsafe { Self::new_unchecked(value) }
+            }
+
+            #[inline]
+            unsafe fn from_operation_detailed(
+                _operation: Operation,
+                value: F,
+            ) -> Result<Self, DetailedError> {
+                // SAFETY: guaranteed by the caller
+                Ok(unsafe { Self::new_unchecked(value) })
+            }
+
+            #[inline]
+            unsafe fn from_value(value: F) -> Self {
+                // SAFETY: guaranteed by the caller
+                unsafe { Self::new_unchecked(value) }
+            }
+
+            #[inline]
+            unsafe fn from_value_detailed(value: F) -> Result<Self, DetailedError> {
+                // SAFETY: guaranteed by the caller
+                Ok(unsafe { Self::new_unchecked(value) })
+            }
+
+            #[inline]
+            fn into_result(self) -> Result<Self, FloatError> {
+                Ok(self)
+            }
+
+            #[inline]
+            fn into_optional(self) -> Option<Self> {
+                Some(self)
+            }
+
+            #[inline]
+            fn none() -> Option<Self> {
+                None
+            }
+        }
+
+        impl<F: Float, C: Constraint> __private::Sealed for Result<Strict<F, C>, FloatError> {}
+
+        impl<F: Float, C: Constraint> OpOutput<F> for Result<Strict<F, C>, FloatError> {
+            type Output = C;
+            type Optional = Self;
+            type Then<Next: OpOutput<F>> = Result<Strict<F, Next::Output>, FloatError>;
+
+            #[inline]
+            unsafe fn from_operation(operation: Operation, value: F) -> Self {
+                // SAFETY: guaranteed by the caller
+                unsafe { Self::from_operation_detailed(operation, value) }.map_err(FloatError::from)
+            }
+
+            #[inline]
+            unsafe fn from_operation_detailed(
+                operation: Operation,
+                value: F,
+            ) -> Result<Strict<F, C>, DetailedError> {
+                // Primitive operands may be non-finite and still give a valid value
+                if operation.operands_are_finite() && value.satisfies::<C>() {
+                    // SAFETY: the value has just been validated
+                    Ok(unsafe { Strict::new_unchecked(value) })
+                } else {
+                    Err(DetailedError::from_operation::<F, C>(operation, value))
+                }
+            }
+
+            #[inline]
+            unsafe fn from_value(value: F) -> Self {
+                Strict::new(value)
+            }
+
+            #[inline]
+            unsafe fn from_value_detailed(value: F) -> Result<Strict<F, C>, DetailedError> {
+                Strict::new_detailed(value)
+            }
+
+            #[inline]
+            fn into_result(self) -> Self {
+                self
+            }
+
+            #[inline]
+            fn into_optional(self) -> Self {
+                self
+            }
+
+            #[inline]
+            fn none() -> Self {
+                Err(FloatError::NoneOperand)
+            }
+        }
+
+        #(#op_traits)*
+
+        /// Output type of adding `Strict<F, Rhs>` to the unrounded product of a
+        /// `Strict<F, Self>::mul_add`
+        ///
+        /// Unlike [`AddOutput`], bounded sums are safe whatever the signs of the operands.
+        pub trait FusedAddOutput<F: Float, Rhs: Constraint>: Constraint {
+            /// `Strict<F, _>` when the addition cannot fail, otherwise a `Result`
+            type Output: OpOutput<F>;
+        }
+
+        /// Output type of `Strict<F, Self>::mul_add(Strict<F, A>, Strict<F, B>)`
+        ///
+        /// Composes the [`MulOutput`] of `Self * A` with the [`FusedAddOutput`] of the
+        /// product and `B`: the result is a `Result` when either step may fail.
+        pub trait MulAddOutput<F: Float, A: Constraint, B: Constraint>: Constraint {
+            /// `Strict<F, _>` when the operation cannot fail, otherwise a `Result`
+            type Output: OpOutput<F>;
+        }
+
+        impl<F: Float, L, A: Constraint, B: Constraint> MulAddOutput<F, A, B> for L
+        where
+            L: MulOutput<F, A>,
+            <<L as MulOutput<F, A>>::Output as OpOutput<F>>::Output: FusedAddOutput<F, B>,
+        {
+            type Output = <<L as MulOutput<F, A>>::Output as OpOutput<F>>::Then<
+                <<<L as MulOutput<F, A>>::Output as OpOutput<F>>::Output as FusedAddOutput<F, B>>::Output,
+            >;
+        }
+
+        /// Euclidean remainder, the counterpart of `f64::rem_euclid` for [`Strict`] values
+        ///
+        /// The result is never negative, and its type is inferred like the operators.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use strict_num_extended::*;
+        ///
+        /// const TURN: PositiveF64 = PositiveF64::new_const(360.0);
+        /// let heading: NonNegativeF64 = FinF64::new(-90.0)?.rem_euclid(TURN);
+        /// assert_eq!(heading.get(), 270.0);
+        /// # Ok::<(), FloatError>(())
+        /// ```
+        pub trait RemEuclid<Rhs = Self> {
+            /// Result of the operation
+            type Output;
+
+            /// Computes the least non-negative remainder of `self / rhs`
+            fn rem_euclid(self, rhs: Rhs) -> Self::Output;
+        }
+
+        /// Euclidean division, the counterpart of `f64::div_euclid` for [`Strict`] values
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use strict_num_extended::*;
+        ///
+        /// const TURN: PositiveF64 = PositiveF64::new_const(360.0);
+        /// let turns = FinF64::new(-90.0)?.div_euclid(TURN)?;
+        /// assert_eq!(turns.get(), -1.0);
+        /// # Ok::<(), FloatError>(())
+        /// ```
+        pub trait DivEuclid<Rhs = Self> {
+            /// Result of the operation
+            type Output;
+
+            /// Computes the quotient of Euclidean division of `self` by `rhs`
+            fn div_euclid(self, rhs: Rhs) -> Self::Output;
+        }
+    }
+}
+
+/// Generates all core traits
+pub fn generate_core_traits() -> TokenStream {
+    let bounds = generate_bounds();
+    let float_trait = generate_float_trait();
+    let constraint_trait = generate_constraint_trait();
+    let output_traits = generate_output_traits();
+
+    quote! {
+        #bounds
+        #float_trait
+        #constraint_trait
+        #output_traits
+    }
+}
@@ -0,0 +1,246 @@
+//! Custom constraint type support module
+//!
+//! Generates the `define_constraints!` macro exported by the main crate, and the
+//! scaffolding around types that downstream crates define with it.
+
+use proc_macro2::{Delimiter, Group, Literal, TokenStream, TokenTree};
+use quote::{ToTokens, quote};
+use syn::LitStr;
+
+use crate::arithmetic::INTERVAL_INFERENCE;
+use crate::config::TypeConfig;
+
+/// Generates the `define_constraints!` macro exported by the main crate
+///
+/// The built-in type list is forwarded to the hidden proc macro on every call, so
+/// custom types are inferred and converted against exactly the types the main crate
+/// was generated from.
+pub fn generate_define_constraints_macro(builtin_input: &TokenStream) -> TokenStream {
+    quote! {
+        /// Defines custom constraint types in a downstream crate.
+        ///
+        /// Accepts the same DSL as the built-in types: a list of `(Name, [conditions])`
+        /// pairs, optionally followed by a list of `(Name, Alias)` pairs. Each entry
+        /// generates the `Name` constraint and the `NameF32` and `NameF64` types in the
+        /// calling module, sharing [`FloatError`], [`ParseFloatError`] and [`FiniteFloat`]
+        /// with the built-in types.
+        ///
+        /// Custom types are aliases of [`Strict`] like the built-in ones, so they share
+        /// every method and operator. They convert to and from the built-in types with
+        /// `From` (or [`Strict::convert`]) for subsets and [`Strict::try_convert`]
+        /// otherwise, and support arithmetic with them. Conversions between custom
+        /// types have no `From`, so they use [`Strict::convert`] as well. Result types are inferred from all known types,
+        /// built-in ones taking precedence.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use strict_num_extended::{FinF64, NonNegativeF64, NormalizedF64};
+        ///
+        /// strict_num_extended::define_constraints!(
+        ///     [
+        ///         (Probability, [">= 0.0", "<= 1.0"]),
+        ///         (Kelvin, [">= 0.0"]),
+        ///     ],
+        ///     [(Probability, Prob)]
+        /// );
+        ///
+        /// let p = ProbF64::new(0.25).unwrap();
+        /// let fin: FinF64 = p.into();
+        /// let norm: NormalizedF64 = p.into();
+        /// assert_eq!(fin.get(), norm.get());
+        ///
+        /// let kelvin: KelvinF64 = FinF64::new(300.0).unwrap().try_convert().unwrap();
+        /// let sum: NonNegativeF64 = (kelvin + norm).unwrap();
+        /// assert_eq!(sum.get(), 300.25);
+        /// ```
+        ///
+        /// # Malformed declarations
+        ///
+        /// Malformed declarations are rejected with an error pointing at the offending
+        /// condition or type name. Each example below breaks this valid declaration:
+        ///
+        /// ```
+        /// strict_num_extended::define_constraints!([
+        ///     (Kelvin, [">= 0.0"]),
+        ///     (Probability, [">= 0.0", "<= 1.0"]),
+        /// ]);
+        /// ```
+        ///
+        /// Unknown operators are rejected:
+        ///
+        /// ```compile_fail
+        /// strict_num_extended::define_constraints!([
+        ///     (Kelvin, ["=> 0.0"]),
+        ///     (Probability, [">= 0.0", "<= 1.0"]),
+        /// ]);
+        /// ```
+        ///
+        /// Bounds must be finite numbers or known constants:
+        ///
+        /// ```compile_fail
+        /// strict_num_extended::define_constraints!([
+        ///     (Kelvin, [">= zero"]),
+        ///     (Probability, [">= 0.0", "<= 1.0"]),
+        /// ]);
+        /// ```
+        ///
+        /// Conditions must admit at least one value:
+        ///
+        /// ```compile_fail
+        /// strict_num_extended::define_constraints!([
+        ///     (Kelvin, [">= 0.0"]),
+        ///     (Probability, [">= 1.0", "<= 0.0"]),
+        /// ]);
+        /// ```
+        ///
+        /// Type names must be unique:
+        ///
+        /// ```compile_fail
+        /// strict_num_extended::define_constraints!([
+        ///     (Kelvin, [">= 0.0"]),
+        ///     (Kelvin, [">= 0.0", "<= 1.0"]),
+        /// ]);
+        /// ```
+        ///
+        /// Two types must not share the same constraints:
+        ///
+        /// ```compile_fail
+        /// strict_num_extended::define_constraints!([
+        ///     (Kelvin, [">= 0.0"]),
+        ///     (Probability, [">= 0.0"]),
+        /// ]);
+        /// ```
+        #[macro_export]
+        macro_rules! define_constraints {
+            ($($input:tt)*) => {
+                $crate::__private::define_custom_constraints! {
+                    $crate; #builtin_input; $($input)*
+                }
+            };
+        }
+    }
+}
+
+/// Generates the imports used by implementations of custom types
+///
+/// Custom types are implemented inside an anonymous `const` block, so the main
+/// crate's items are imported by name and can't clash with the caller's.
+pub fn generate_custom_imports(config: &TypeConfig) -> TokenStream {
+    let krate = &config.crate_path;
+    let builtin_constraints = config
+        .constraint_types
+        .iter()
+        .filter(|t| !t.is_local)
+        .map(|t| &t.constraint_name);
+    let bounded_imports = INTERVAL_INFERENCE.then(|| {
+        quote! {
+            #[allow(unused_imports)]
+            use #krate::{BoundedAddOutput, BoundedDivOutput, BoundedMulOutput, BoundedSubOutput};
+        }
+    });
+
+    quote! {
+        #bounded_imports
+        #[allow(unused_imports)]
+        use #krate::{
+            AddOutput, Bounds, CbrtOutput, Constraint, DivEuclidOutput, DivOutput, FloatError, FusedAddOutput, HypotOutput, InfallibleProduct, LerpOutput, LosslessWidening,
+            MaxOutput, MinOutput, MulOutput, NegOutput, RecipOutput, RemEuclidOutput, RemOutput, SqrtOutput, Strict, SubOutput, SubsetOf,
+            #(#builtin_constraints),*
+        };
+    }
+}
+
+/// Marks every code block in generated documentation as `ignore`
+///
+/// The examples are written against the built-in types of the main crate; in a
+/// downstream crate they would be compiled as that crate's doctests and fail.
+pub fn ignore_doc_tests(tokens: TokenStream) -> TokenStream {
+    let mut in_code_block = false;
+    rewrite_doc_attrs(tokens, &mut in_code_block)
+}
+
+/// Walks a token stream and rewrites the string literals of `#[doc = ...]` attributes
+fn rewrite_doc_attrs(tokens: TokenStream, in_code_block: &mut bool) -> TokenStream {
+    let mut output = Vec::new();
+    let mut after_pound = false;
+
+    for token in tokens {
+        let rewritten = if let TokenTree::Group(group) = &token {
+            let is_doc_attr = after_pound
+                && group.delimiter() == Delimiter::Bracket
+                && group
+                    .stream()
+                    .into_iter()
+                    .next()
+                    .is_some_and(|first| first.to_string() == "doc");
+            let stream = if is_doc_attr {
+                rewrite_doc_literals(group.stream(), in_code_block)
+            } else {
+                rewrite_doc_attrs(group.stream(), in_code_block)
+            };
+            let mut new_group = Group::new(group.delimiter(), stream);
+            new_group.set_span(group.span());
+            TokenTree::Group(new_group)
+        } else {
+            token
+        };
+
+        after_pound = matches!(&rewritten, TokenTree::Punct(p) if p.as_char() == '#');
+        output.push(rewritten);
+    }
+
+    output.into_iter().collect()
+}
+
+/// Rewrites the code fences of all string literals inside a doc attribute
+fn rewrite_doc_literals(tokens: TokenStream, in_code_block: &mut bool) -> TokenStream {
+    tokens
+        .into_iter()
+        .map(|token| {
+            if let TokenTree::Group(group) = &token {
+                let mut new_group = Group::new(
+                    group.delimiter(),
+                    rewrite_doc_literals(group.stream(), in_code_block),
+                );
+                new_group.set_span(group.span());
+                TokenTree::Group(new_group)
+            } else if let TokenTree::Literal(literal) = &token {
+                rewrite_literal(literal, in_code_block).unwrap_or(token)
+            } else {
+                token
+            }
+        })
+        .collect()
+}
+
+/// Replaces the info string of every opening code fence with `ignore`
+///
+/// Returns `None` for literals that aren't strings or contain no fences.
+fn rewrite_literal(literal: &Literal, in_code_block: &mut bool) -> Option<TokenTree> {
+    let lit: LitStr = syn::parse2(literal.to_token_stream()).ok()?;
+    let value = lit.value();
+    if !value.contains("```") {
+        return None;
+    }
+
+    let lines: Vec<String> = value
+        .split('\n')
+        .map(|line| {
+            let trimmed = line.trim_start();
+            if !trimmed.starts_with("```") {
+                return line.to_string();
+            }
+            *in_code_block = !*in_code_block;
+            if *in_code_block {
+                let indent = line.strip_suffix(trimmed).unwrap_or_default();
+                format!("{indent}```ignore")
+            } else {
+                line.to_string()
+            }
+        })
+        .collect();
+
+    let new_lit = LitStr::new(&lines.join("\n"), lit.span());
+    syn::parse2(new_lit.to_token_stream()).ok()
+}
@@ -5,8 +5,8 @@
 use proc_macro2::{Ident, TokenStream};
 use quote::quote;
 
-use crate::config::{Bounds, ConstraintDef, Sign};
-use core::f64::consts::PI;
+use crate::config::{Bounds, ConstraintDef};
+use core::f64::consts::{PI, TAU};
 
 /// Generates documentation comments for struct definitions
 ///
@@ -39,287 +39,62 @@ pub fn generate_struct_doc(
 }
 
 /// Generates a mathematical formula expression for constraints
-#[expect(clippy::too_many_lines)]
 pub fn generate_constraint_formula(constraint_def: &ConstraintDef) -> String {
-    match (
-        constraint_def.sign,
-        constraint_def.excludes_zero,
-        &constraint_def.bounds,
-    ) {
-        // Positive types
-        (
-            Sign::Positive,
-            false,
-            Bounds {
-                lower: Some(0.0),
-                upper: None,
-            },
-        ) => "x ≥ 0".to_string(),
-        (
-            Sign::Positive,
-            true,
-            Bounds {
-                lower: Some(0.0),
-                upper: None,
-            },
-        ) => "x > 0".to_string(),
-        (
-            Sign::Positive,
-            _,
-            Bounds {
-                lower: Some(l),
-                upper: Some(u),
-            },
-        ) => {
-            if constraint_def.excludes_zero {
-                format!(
-                    "{} < x ≤ {}",
-                    format_bound_value((*l).max(0.0)),
-                    format_bound_value(*u)
-                )
-            } else {
-                format!(
-                    "{} ≤ x ≤ {}",
-                    format_bound_value((*l).max(0.0)),
-                    format_bound_value(*u)
-                )
-            }
-        }
-
-        // Negative types
-        (
-            Sign::Negative,
-            false,
-            Bounds {
-                lower: None,
-                upper: Some(0.0),
-            },
-        ) => "x ≤ 0".to_string(),
-        (
-            Sign::Negative,
-            true,
-            Bounds {
-                lower: None,
-                upper: Some(0.0),
-            },
-        ) => "x < 0".to_string(),
-        (
-            Sign::Negative,
-            _,
-            Bounds {
-                lower: Some(l),
-                upper: Some(u),
-            },
-        ) => {
-            if constraint_def.excludes_zero {
-                format!(
-                    "{} ≤ x < {}",
-                    format_bound_value(*l),
-                    format_bound_value((*u).min(0.0))
-                )
-            } else {
-                format!(
-                    "{} ≤ x ≤ {}",
-                    format_bound_value(*l),
-                    format_bound_value((*u).min(0.0))
-                )
-            }
-        }
-
-        // NonZero types
-        (
-            Sign::Any,
-            true,
-            Bounds {
-                lower: None,
-                upper: None,
-            },
-        ) => "x ≠ 0".to_string(),
-
-        // Bounded types
-        (
-            Sign::Any,
-            false,
-            Bounds {
-                lower: Some(l),
-                upper: Some(u),
-            },
-        ) => {
-            // Special formatting for PI bounds
-            let lower_str = format_bound_value(*l);
-            let upper_str = format_bound_value(*u);
-            format!("{lower_str} ≤ x ≤ {upper_str}")
-        }
-        (
-            Sign::Any,
-            true,
-            Bounds {
-                lower: Some(l),
-                upper: Some(u),
-            },
-        ) => {
-            if *l == 0.0 {
-                format!("0 < x ≤ {u}")
-            } else if *u == 0.0 {
-                format!("{l} ≤ x < 0")
-            } else {
-                format!("{l} ≤ x ≤ {u}, x ≠ 0")
-            }
+    let bounds = &constraint_def.bounds;
+    let lower_op = if bounds.lower_inclusive { "≤" } else { "<" };
+    let upper_op = if bounds.upper_inclusive { "≤" } else { "<" };
+
+    let formula = match (bounds.lower, bounds.upper) {
+        (Some(l), Some(u)) => format!(
+            "{} {lower_op} x {upper_op} {}",
+            format_bound_value(l),
+            format_bound_value(u)
+        ),
+        (Some(l), None) => {
+            let op = if bounds.lower_inclusive { "≥" } else { ">" };
+            format!("x {op} {}", format_bound_value(l))
         }
-
-        // Default: finite numbers
-        _ => "x ∈ ℝ".to_string(),
+        (None, Some(u)) => format!("x {upper_op} {}", format_bound_value(u)),
+        (None, None) if constraint_def.excludes_zero => return "x ≠ 0".to_string(),
+        (None, None) => return "x ∈ ℝ".to_string(),
+    };
+
+    // Zero exclusion that is not already implied by the interval
+    if constraint_def.excludes_zero && bounds.contains(0.0) {
+        format!("{formula}, x ≠ 0")
+    } else {
+        formula
     }
 }
 
 /// Generates a text description of constraints
-#[expect(clippy::too_many_lines)]
 pub fn generate_constraint_description(constraint_def: &ConstraintDef) -> String {
-    match (
-        constraint_def.sign,
-        constraint_def.excludes_zero,
-        &constraint_def.bounds,
-    ) {
-        // Positive types
-        (
-            Sign::Positive,
-            false,
-            Bounds {
-                lower: Some(0.0),
-                upper: None,
-            },
-        ) => "non-negative".to_string(),
-        (
-            Sign::Positive,
-            true,
-            Bounds {
-                lower: Some(0.0),
-                upper: None,
-            },
-        ) => "positive".to_string(),
-        (
-            Sign::Positive,
-            false,
-            Bounds {
-                lower: Some(l),
-                upper: Some(u),
-            },
-        ) => {
-            format!(
-                "range [{}, {}]",
-                format_bound_value((*l).max(0.0)),
-                format_bound_value(*u)
-            )
-        }
-        (
-            Sign::Positive,
-            true,
-            Bounds {
-                lower: Some(l),
-                upper: Some(u),
-            },
-        ) => {
-            format!(
-                "range ({}, {}]",
-                format_bound_value((*l).max(0.0)),
-                format_bound_value(*u)
-            )
-        }
-
-        // Negative types
-        (
-            Sign::Negative,
-            false,
-            Bounds {
-                lower: None,
-                upper: Some(0.0),
-            },
-        ) => "non-positive".to_string(),
-        (
-            Sign::Negative,
-            true,
-            Bounds {
-                lower: None,
-                upper: Some(0.0),
-            },
-        ) => "negative".to_string(),
-        (
-            Sign::Negative,
-            false,
-            Bounds {
-                lower: Some(l),
-                upper: Some(u),
-            },
-        ) => {
-            format!(
-                "range [{}, {}]",
-                format_bound_value(*l),
-                format_bound_value((*u).min(0.0))
-            )
-        }
-        (
-            Sign::Negative,
-            true,
-            Bounds {
-                lower: Some(l),
-                upper: Some(u),
-            },
-        ) => {
-            format!(
-                "range [{}, {})",
-                format_bound_value(*l),
-                format_bound_value((*u).min(0.0))
-            )
-        }
-
-        // NonZero types
-        (
-            Sign::Any,
-            true,
-            Bounds {
-                lower: None,
-                upper: None,
-            },
-        ) => "non-zero".to_string(),
+    let bounds = &constraint_def.bounds;
 
-        // Bounded types
-        (
-            Sign::Any,
-            false,
-            Bounds {
-                lower: Some(l),
-                upper: Some(u),
-            },
-        ) => {
-            format!(
-                "range [{}, {}]",
-                format_bound_value(*l),
-                format_bound_value(*u)
-            )
-        }
-        (
-            Sign::Any,
-            true,
-            Bounds {
-                lower: Some(l),
-                upper: Some(u),
-            },
-        ) => {
-            if *l == 0.0 {
-                format!("range (0, {}]", format_bound_value(*u))
-            } else if *u == 0.0 {
-                format!("range [{}, 0)", format_bound_value(*l))
-            } else {
-                format!(
-                    "range [{}, {}], excluding zero",
-                    format_bound_value(*l),
-                    format_bound_value(*u)
-                )
-            }
+    let description = match (bounds.lower, bounds.upper) {
+        // Sign-only types
+        (Some(0.0), None) if bounds.lower_inclusive => return "non-negative".to_string(),
+        (Some(0.0), None) => return "positive".to_string(),
+        (None, Some(0.0)) if bounds.upper_inclusive => return "non-positive".to_string(),
+        (None, Some(0.0)) => return "negative".to_string(),
+        (None, None) if constraint_def.excludes_zero => return "non-zero".to_string(),
+        (None, None) => return "finite".to_string(),
+
+        // Interval types
+        (lower, upper) => {
+            let open = if bounds.lower_inclusive { "[" } else { "(" };
+            let close = if bounds.upper_inclusive { "]" } else { ")" };
+            let lower = lower.map_or_else(|| "-∞".to_string(), format_bound_value);
+            let upper = upper.map_or_else(|| "+∞".to_string(), format_bound_value);
+            format!("range {open}{lower}, {upper}{close}")
         }
+    };
 
-        // Default: finite numbers
-        _ => "finite".to_string(),
+    // Zero exclusion that is not already implied by the interval
+    if constraint_def.excludes_zero && bounds.contains(0.0) {
+        format!("{description}, excluding zero")
+    } else {
+        description
     }
 }
 
@@ -427,6 +202,7 @@ fn generate_type_description(
             Bounds {
                 lower: Some(0.0),
                 upper: None,
+                ..
             },
         ) => format!(
             "Positive numbers are useful for:\n\
@@ -463,6 +239,7 @@ fn generate_type_description(
             Bounds {
                 lower: Some(0.0),
                 upper: None,
+                ..
             },
         ) => format!(
             "Non-negative numbers are commonly used for:\n\
@@ -500,6 +277,7 @@ fn generate_type_description(
             Bounds {
                 lower: None,
                 upper: Some(0.0),
+                ..
             },
         ) => format!(
             "Negative numbers are useful for:\n\
@@ -536,6 +314,7 @@ fn generate_type_description(
             Bounds {
                 lower: None,
                 upper: Some(0.0),
+                ..
             },
         ) => format!(
             "Non-positive numbers are commonly used for:\n\
@@ -573,6 +352,7 @@ fn generate_type_description(
             Bounds {
                 lower: None,
                 upper: None,
+                ..
             },
         ) => format!(
             "Non-zero numbers are useful for:\n\
@@ -631,6 +411,7 @@ fn generate_valid_example_for_type(_type_name: &Ident, constraint_def: &Constrai
             Bounds {
                 lower: Some(0.0),
                 upper: None,
+                ..
             },
             Sign::Positive,
         ) => "42.0".to_string(),
@@ -640,6 +421,7 @@ fn generate_valid_example_for_type(_type_name: &Ident, constraint_def: &Constrai
             Bounds {
                 lower: None,
                 upper: Some(0.0),
+                ..
             },
             Sign::Negative,
         ) => "-42.0".to_string(),
@@ -658,6 +440,7 @@ fn generate_valid_example_for_type(_type_name: &Ident, constraint_def: &Constrai
             Bounds {
                 lower: Some(l),
                 upper: Some(u),
+                ..
             },
             _,
         ) => {
@@ -679,143 +462,14 @@ fn generate_valid_example_for_type(_type_name: &Ident, constraint_def: &Constrai
     }
 }
 
-/// Generates documentation for the `new()` method
-pub fn generate_new_method_doc(
-    struct_name: &Ident,
-    float_type: &Ident,
-    constraint_def: &ConstraintDef,
-) -> TokenStream {
-    let type_name_str = struct_name.to_string();
-    let constraint_desc = generate_constraint_description(constraint_def);
-
-    let valid_example = generate_valid_example(float_type, constraint_def);
-    let (invalid_example, invalid_reason) = generate_invalid_example(float_type, constraint_def);
-
-    quote! {
-        concat!("Creates a new ", #type_name_str, " value.\n\n",
-               "The value must satisfy the constraint: ", #constraint_desc, ".\n\n",
-               "# Examples\n\n",
-               "Valid value:\n\n",
-               "```rust\n",
-               "#![expect(clippy::approx_constant)]\n",
-               "use strict_num_extended::{", #type_name_str, ", FloatError};\n\n",
-               "let value = ", #type_name_str, "::new(", #valid_example, ")?;\n",
-               "assert_eq!(value.get(), ", #valid_example, ");\n",
-               "# Ok::<(), FloatError>(())\n",
-               "```\n\n",
-               "Invalid value (", #invalid_reason, "):\n\n",
-               "```rust\n",
-               "use strict_num_extended::", #type_name_str, ";\n\n",
-               "let invalid = ", #type_name_str, "::new(", #invalid_example, ");\n",
-               "assert!(invalid.is_err());\n",
-               "```\n\n",
-               "# Errors\n\n",
-               "Returns `Err(FloatError)` if the value does not satisfy the constraint.")
-    }
-}
-
-/// Generates a valid example value
-pub fn generate_valid_example(_float_type: &Ident, constraint_def: &ConstraintDef) -> String {
-    match (constraint_def.sign, &constraint_def.bounds) {
-        (
-            Sign::Positive,
-            Bounds {
-                lower: Some(0.0),
-                upper: None,
-            },
-        ) => "42.0".to_string(),
-        (
-            Sign::Positive | Sign::Negative,
-            Bounds {
-                lower: Some(l),
-                upper: Some(u),
-            },
-        ) => {
-            format!("{}", f64::midpoint(*l, *u))
-        }
-        (
-            Sign::Negative,
-            Bounds {
-                upper: Some(0.0),
-                lower: None,
-            },
-        ) => "-42.0".to_string(),
-        (
-            Sign::Any,
-            Bounds {
-                lower: Some(0.0),
-                upper: Some(1.0),
-            },
-        ) => "0.5".to_string(),
-        (
-            Sign::Any,
-            Bounds {
-                lower: Some(l),
-                upper: Some(u),
-            },
-        ) => {
-            let mid = f64::midpoint(*l, *u);
-            if mid == 0.0 {
-                format!("{}", *l + 0.1)
-            } else {
-                format!("{mid}")
-            }
-        }
-        _ => "1.0".to_string(),
-    }
-}
-
-/// Generates an invalid example value (for demonstrating errors)
-pub fn generate_invalid_example(
-    float_type: &Ident,
-    constraint_def: &ConstraintDef,
-) -> (String, &'static str) {
-    match (
-        constraint_def.sign,
-        constraint_def.excludes_zero,
-        &constraint_def.bounds,
-    ) {
-        (
-            Sign::Positive,
-            _,
-            Bounds {
-                lower: Some(0.0), ..
-            },
-        ) => ("-1.0".to_string(), "negative value"),
-        (
-            Sign::Negative,
-            _,
-            Bounds {
-                upper: Some(0.0), ..
-            },
-        ) => ("1.0".to_string(), "positive value"),
-        (
-            Sign::Any,
-            true,
-            Bounds {
-                lower: None,
-                upper: None,
-            },
-        ) => ("0.0".to_string(), "zero value"),
-        (Sign::Any, false, Bounds { lower: Some(l), .. }) if *l > 0.0 => {
-            (format!("{}", *l - 1.0), "out of lower bound")
-        }
-        (Sign::Any, false, Bounds { upper: Some(u), .. }) if *u < 0.0 => {
-            (format!("{}", *u + 1.0), "out of upper bound")
-        }
-        _ => (
-            format!("{}::NAN", float_type.to_string().to_lowercase()),
-            "NaN",
-        ),
-    }
-}
-
 /// Formats a bound value for display in documentation
-/// Special handling for PI to avoid `clippy::approx_constant` warnings
+/// Special handling for PI and TAU to avoid `clippy::approx_constant` warnings
 fn format_bound_value(value: f64) -> String {
     const PI_TOLERANCE: f64 = 0.00001;
 
-    if (value - PI).abs() < PI_TOLERANCE {
+    if (value - TAU).abs() < PI_TOLERANCE {
+        "TAU".to_string()
+    } else if (value - PI).abs() < PI_TOLERANCE {
         "PI".to_string()
     } else if (value + PI).abs() < PI_TOLERANCE {
         "-PI".to_string()
@@ -0,0 +1,555 @@
+//! Error type generation module
+//!
+//! Generates `FloatError`, the kind of a failure, and `DetailedError`, which adds the
+//! context of the failure: the rejected value, the target type, the violated bound and
+//! the arithmetic operation that produced the value. All of it is `Copy` and needs no
+//! allocation.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Generates the `FloatError` enum
+fn generate_float_error() -> TokenStream {
+    quote! {
+        /// Errors that can occur when creating or operating on finite floats
+        ///
+        /// Each variant is the kind of a failure. The value, type and operation behind
+        /// it are recorded by [`DetailedError`], which [`Checked`] computations and
+        /// [`Strict::new_detailed`] return.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        pub enum FloatError {
+            /// Value is NaN (Not a Number)
+            NaN,
+            /// Value is positive infinity
+            PosInf,
+            /// Value is negative infinity
+            NegInf,
+            /// Value is below the lower bound of the type
+            BelowRange,
+            /// Value is above the upper bound of the type
+            AboveRange,
+            /// Value is zero, which the type excludes
+            ExcludedZero,
+            /// Right-hand side operand is None in Option arithmetic
+            NoneOperand,
+            /// Division, remainder or Euclidean operation with a zero divisor
+            DivisionByZero,
+            /// Arithmetic result too large in magnitude to be finite
+            Overflow,
+            /// Arithmetic result rounded to zero, which the output type excludes
+            Underflow,
+            /// Primitive operand that is NaN or infinite
+            InvalidOperand,
+            /// Integer operand not exactly representable in the float type
+            PrecisionLoss,
+        }
+
+        impl FloatError {
+            /// Checks whether a finite value was rejected for violating a bound of its
+            /// type: `BelowRange`, `AboveRange` or `ExcludedZero`
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use strict_num_extended::*;
+            ///
+            /// assert_eq!(NormalizedF64::new(1.5), Err(FloatError::AboveRange));
+            /// assert!(FloatError::AboveRange.is_out_of_range());
+            /// assert!(!FloatError::Overflow.is_out_of_range());
+            /// ```
+            #[must_use]
+            pub const fn is_out_of_range(self) -> bool {
+                matches!(
+                    self,
+                    FloatError::BelowRange | FloatError::AboveRange | FloatError::ExcludedZero
+                )
+            }
+
+            /// Classifies why `value`, the result of `operation`, does not satisfy `C`
+            pub(crate) fn of_operation<F: Float, C: Constraint>(operation: Operation, value: F) -> Self {
+                let (operator, lhs, rhs) = (operation.operator, operation.lhs(), operation.rhs());
+                if !operation.operands_are_finite() {
+                    FloatError::InvalidOperand
+                } else if operator.divides() && rhs == 0.0 {
+                    FloatError::DivisionByZero
+                } else if value.is_nan() {
+                    FloatError::NaN
+                } else if !value.is_finite() {
+                    FloatError::Overflow
+                } else if value == F::from_f64(0.0)
+                    && lhs != 0.0
+                    && rhs != 0.0
+                    && operator.may_underflow()
+                {
+                    FloatError::Underflow
+                } else {
+                    Self::of_finite_value::<F, C>(value)
+                }
+            }
+
+            /// Classifies why `value` does not satisfy `C`, which it was validated against
+            pub(crate) fn of_value<F: Float, C: Constraint>(value: F) -> Self {
+                if value.is_nan() {
+                    FloatError::NaN
+                } else if value.is_finite() {
+                    Self::of_finite_value::<F, C>(value)
+                } else if value > F::from_f64(0.0) {
+                    FloatError::PosInf
+                } else {
+                    FloatError::NegInf
+                }
+            }
+
+            /// Classifies a finite value rejected by `C` by the bound it violates
+            fn of_finite_value<F: Float, C: Constraint>(value: F) -> Self {
+                match value.violated_bound::<C>() {
+                    Some(ViolatedBound::Lower { .. }) => FloatError::BelowRange,
+                    Some(ViolatedBound::Upper { .. }) => FloatError::AboveRange,
+                    Some(ViolatedBound::Zero) => FloatError::ExcludedZero,
+                    // Unreachable: a finite value rejected by `C` violates one of its bounds
+                    None => FloatError::NaN,
+                }
+            }
+        }
+
+        impl core::fmt::Display for FloatError {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                match self {
+                    FloatError::NaN => write!(f, "value is NaN (Not a Number)"),
+                    FloatError::PosInf => write!(f, "value is positive infinity"),
+                    FloatError::NegInf => write!(f, "value is negative infinity"),
+                    FloatError::BelowRange => write!(f, "value is below the valid range for this type"),
+                    FloatError::AboveRange => write!(f, "value is above the valid range for this type"),
+                    FloatError::ExcludedZero => write!(f, "value is zero, which this type excludes"),
+                    FloatError::NoneOperand => write!(f, "right-hand side operand is None in Option arithmetic"),
+                    FloatError::DivisionByZero => write!(f, "division by zero"),
+                    FloatError::Overflow => write!(f, "arithmetic result overflowed to infinity"),
+                    FloatError::Underflow => write!(f, "arithmetic result underflowed to zero"),
+                    FloatError::InvalidOperand => write!(f, "operand is NaN or infinite"),
+                    FloatError::PrecisionLoss => write!(f, "integer operand is not exactly representable"),
+                }
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl std::error::Error for FloatError {}
+    }
+}
+
+/// Generates `ViolatedBound`
+fn generate_violated_bound() -> TokenStream {
+    quote! {
+        /// Condition of a constraint that a value failed, displayed as written in the
+        /// constraint, e.g. `> 0`
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        pub enum ViolatedBound {
+            /// Value is below the lower bound
+            Lower {
+                /// The lower bound
+                bound: f64,
+                /// Whether the bound itself is admitted (`>=` rather than `>`)
+                inclusive: bool,
+            },
+            /// Value is above the upper bound
+            Upper {
+                /// The upper bound
+                bound: f64,
+                /// Whether the bound itself is admitted (`<=` rather than `<`)
+                inclusive: bool,
+            },
+            /// Value is zero, which the constraint excludes
+            Zero,
+        }
+
+        impl core::fmt::Display for ViolatedBound {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                match *self {
+                    ViolatedBound::Lower { bound, inclusive: true } => write!(f, ">= {bound}"),
+                    ViolatedBound::Lower { bound, inclusive: false } => write!(f, "> {bound}"),
+                    ViolatedBound::Upper { bound, inclusive: true } => write!(f, "<= {bound}"),
+                    ViolatedBound::Upper { bound, inclusive: false } => write!(f, "< {bound}"),
+                    ViolatedBound::Zero => write!(f, "!= 0"),
+                }
+            }
+        }
+    }
+}
+
+/// Generates `Operator`
+fn generate_operator() -> TokenStream {
+    quote! {
+        /// Arithmetic operator recorded in a [`DetailedError`]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum Operator {
+            /// `+`
+            Add,
+            /// `-`
+            Sub,
+            /// `*`
+            Mul,
+            /// `/`
+            Div,
+            /// `%`
+            Rem,
+            /// `rem_euclid`
+            RemEuclid,
+            /// `div_euclid`
+            DivEuclid,
+            /// `mul_add`, a fused multiply-add
+            MulAdd,
+            /// `hypot`, the length of the hypotenuse
+            Hypot,
+        }
+
+        impl Operator {
+            /// Checks whether the right-hand operand is a divisor
+            const fn divides(self) -> bool {
+                matches!(
+                    self,
+                    Operator::Div | Operator::Rem | Operator::RemEuclid | Operator::DivEuclid
+                )
+            }
+
+            /// Checks whether a nonzero result may round to zero
+            const fn may_underflow(self) -> bool {
+                matches!(self, Operator::Mul | Operator::Div)
+            }
+        }
+    }
+}
+
+/// Generates `Operation` and the raw values it records
+fn generate_operation() -> TokenStream {
+    quote! {
+        /// Raw value recorded in an error, printed at the width it was computed in
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        struct RawValue {
+            value: f64,
+            single_precision: bool,
+        }
+
+        impl RawValue {
+            #[inline]
+            fn new<F: Float>(value: F) -> Self {
+                Self {
+                    value: value.into_f64(),
+                    single_precision: core::mem::size_of::<F>() == 4,
+                }
+            }
+        }
+
+        impl core::fmt::Display for RawValue {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                if self.single_precision {
+                    write!(f, "{:?}", self.value as f32)
+                } else {
+                    write!(f, "{:?}", self.value)
+                }
+            }
+        }
+
+        /// Arithmetic operation whose result was rejected, with its raw operands
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        pub struct Operation {
+            operator: Operator,
+            lhs: RawValue,
+            rhs: RawValue,
+            addend: Option<RawValue>,
+        }
+
+        impl Operation {
+            /// Records `lhs operator rhs`
+            #[inline]
+            #[must_use]
+            pub fn new<F: Float>(operator: Operator, lhs: F, rhs: F) -> Self {
+                Self {
+                    operator,
+                    lhs: RawValue::new(lhs),
+                    rhs: RawValue::new(rhs),
+                    addend: None,
+                }
+            }
+
+            /// Records `lhs.mul_add(rhs, addend)`
+            #[inline]
+            #[must_use]
+            pub fn mul_add<F: Float>(lhs: F, rhs: F, addend: F) -> Self {
+                Self {
+                    addend: Some(RawValue::new(addend)),
+                    ..Self::new(Operator::MulAdd, lhs, rhs)
+                }
+            }
+
+            /// The operator
+            #[must_use]
+            pub const fn operator(&self) -> Operator {
+                self.operator
+            }
+
+            /// Left-hand operand, widened to `f64`
+            #[must_use]
+            pub const fn lhs(&self) -> f64 {
+                self.lhs.value
+            }
+
+            /// Right-hand operand, widened to `f64`
+            #[must_use]
+            pub const fn rhs(&self) -> f64 {
+                self.rhs.value
+            }
+
+            /// Addend of a fused multiply-add, widened to `f64`
+            #[must_use]
+            pub const fn addend(&self) -> Option<f64> {
+                match self.addend {
+                    Some(raw) => Some(raw.value),
+                    None => None,
+                }
+            }
+
+            /// Checks whether every operand is finite
+            fn operands_are_finite(&self) -> bool {
+                self.lhs.value.is_finite()
+                    && self.rhs.value.is_finite()
+                    && self.addend.is_none_or(|addend| addend.value.is_finite())
+            }
+        }
+
+        impl core::fmt::Display for Operation {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let Self { operator, lhs, rhs, addend } = *self;
+                match operator {
+                    Operator::Add => write!(f, "{lhs} + {rhs}"),
+                    Operator::Sub => write!(f, "{lhs} - {rhs}"),
+                    Operator::Mul => write!(f, "{lhs} * {rhs}"),
+                    Operator::Div => write!(f, "{lhs} / {rhs}"),
+                    Operator::Rem => write!(f, "{lhs} % {rhs}"),
+                    Operator::RemEuclid => write!(f, "{lhs}.rem_euclid({rhs})"),
+                    Operator::DivEuclid => write!(f, "{lhs}.div_euclid({rhs})"),
+                    Operator::Hypot => write!(f, "{lhs}.hypot({rhs})"),
+                    Operator::MulAdd => match addend {
+                        Some(addend) => write!(f, "{lhs}.mul_add({rhs}, {addend})"),
+                        None => write!(f, "{lhs}.mul_add({rhs}, _)"),
+                    },
+                }
+            }
+        }
+    }
+}
+
+/// Generates the `DetailedError` struct
+#[expect(clippy::too_many_lines)]
+fn generate_detailed_error() -> TokenStream {
+    quote! {
+        /// A [`FloatError`] together with the context in which it was raised
+        ///
+        /// Records as much as is known where the error occurred: the rejected value, the
+        /// target type, the violated bound and the operation that produced the value.
+        /// It is returned by [`Strict::new_detailed`] and kept by [`Checked`]
+        /// computations, and converts into its [`kind`](Self::kind) with `?`.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use strict_num_extended::*;
+        ///
+        /// let err = PositiveF64::new_detailed(-2.0).unwrap_err();
+        /// assert_eq!(err.kind(), FloatError::BelowRange);
+        /// assert_eq!(err.value(), Some(-2.0));
+        /// assert_eq!(err.type_name(), Some("PositiveF64"));
+        /// assert_eq!(err.to_string(), "PositiveF64: -2.0 violates > 0");
+        ///
+        /// let tiny = PositiveF64::new(1e-200)?;
+        /// let err = (Checked::new(tiny) * tiny).detailed_error().unwrap();
+        /// assert_eq!(err.kind(), FloatError::Underflow);
+        /// assert_eq!(err.operation().unwrap().operator(), Operator::Mul);
+        /// assert_eq!(
+        ///     err.to_string(),
+        ///     "PositiveF64: 1e-200 * 1e-200 = 0.0 underflows, violating > 0"
+        /// );
+        /// # Ok::<(), FloatError>(())
+        /// ```
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        pub struct DetailedError {
+            kind: FloatError,
+            value: Option<RawValue>,
+            type_name: Option<&'static str>,
+            bound: Option<ViolatedBound>,
+            operation: Option<Operation>,
+        }
+
+        impl DetailedError {
+            /// Creates an error of the given kind without context
+            const fn new(kind: FloatError) -> Self {
+                Self {
+                    kind,
+                    value: None,
+                    type_name: None,
+                    bound: None,
+                    operation: None,
+                }
+            }
+
+            /// Records why `value` does not satisfy `C`
+            pub(crate) fn from_value<F: Float, C: Constraint>(value: F) -> Self {
+                let kind = FloatError::of_value::<F, C>(value);
+                let bound = kind.is_out_of_range().then(|| value.violated_bound::<C>()).flatten();
+                Self::new(kind)
+                    .with_value(value, F::type_name::<C>())
+                    .with_bound(bound)
+            }
+
+            /// Records why `value`, the result of `operation`, does not satisfy `C`
+            pub(crate) fn from_operation<F: Float, C: Constraint>(
+                operation: Operation,
+                value: F,
+            ) -> Self {
+                let kind = FloatError::of_operation::<F, C>(operation, value);
+                let bound = (kind.is_out_of_range() || kind == FloatError::Underflow)
+                    .then(|| value.violated_bound::<C>())
+                    .flatten();
+                Self::new(kind)
+                    .with_value(value, F::type_name::<C>())
+                    .with_bound(bound)
+                    .with_operation(operation)
+            }
+
+            /// Records the value and the type it was validated against
+            fn with_value<F: Float>(mut self, value: F, type_name: &'static str) -> Self {
+                self.value = Some(RawValue::new(value));
+                self.type_name = Some(type_name);
+                self
+            }
+
+            /// Records the violated bound
+            const fn with_bound(mut self, bound: Option<ViolatedBound>) -> Self {
+                self.bound = bound;
+                self
+            }
+
+            /// Records the operation that produced the rejected value
+            const fn with_operation(mut self, operation: Operation) -> Self {
+                self.operation = Some(operation);
+                self
+            }
+
+            /// Kind of failure
+            #[must_use]
+            pub const fn kind(&self) -> FloatError {
+                self.kind
+            }
+
+            /// Rejected raw value, widened to `f64`
+            #[must_use]
+            pub const fn value(&self) -> Option<f64> {
+                match self.value {
+                    Some(raw) => Some(raw.value),
+                    None => None,
+                }
+            }
+
+            /// Name of the type the value was validated against, e.g. `"PositiveF64"`
+            #[must_use]
+            pub const fn type_name(&self) -> Option<&'static str> {
+                self.type_name
+            }
+
+            /// Bound violated by an out-of-range value
+            #[must_use]
+            pub const fn bound(&self) -> Option<ViolatedBound> {
+                self.bound
+            }
+
+            /// Arithmetic operation that produced the rejected value
+            #[must_use]
+            pub const fn operation(&self) -> Option<Operation> {
+                self.operation
+            }
+        }
+
+        impl From<FloatError> for DetailedError {
+            #[inline]
+            fn from(kind: FloatError) -> Self {
+                Self::new(kind)
+            }
+        }
+
+        impl From<DetailedError> for FloatError {
+            #[inline]
+            fn from(error: DetailedError) -> Self {
+                error.kind
+            }
+        }
+
+        impl PartialEq<FloatError> for DetailedError {
+            #[inline]
+            fn eq(&self, other: &FloatError) -> bool {
+                self.kind == *other
+            }
+        }
+
+        impl core::fmt::Display for DetailedError {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                let (Some(type_name), Some(value)) = (self.type_name, self.value) else {
+                    return write!(f, "{}", self.kind);
+                };
+                write!(f, "{type_name}: ")?;
+                match (self.kind, self.operation) {
+                    (FloatError::DivisionByZero, Some(operation)) => {
+                        return write!(f, "{operation} divides by zero");
+                    }
+                    (FloatError::InvalidOperand, Some(operation)) => {
+                        return write!(f, "{operation} has a non-finite operand");
+                    }
+                    (_, Some(operation)) => write!(f, "{operation} = ")?,
+                    (_, None) => {}
+                }
+                match (self.kind, self.bound) {
+                    (
+                        FloatError::BelowRange | FloatError::AboveRange | FloatError::ExcludedZero,
+                        Some(bound),
+                    ) => write!(f, "{value} violates {bound}"),
+                    (FloatError::BelowRange | FloatError::AboveRange | FloatError::ExcludedZero, None) => {
+                        write!(f, "{value} is outside the valid range")
+                    }
+                    (FloatError::Underflow, Some(bound)) => {
+                        write!(f, "{value} underflows, violating {bound}")
+                    }
+                    (FloatError::Underflow, None) => write!(f, "{value} underflows"),
+                    (FloatError::Overflow, _) => write!(f, "{value} overflows"),
+                    (FloatError::NaN | FloatError::PosInf | FloatError::NegInf, _) => {
+                        write!(f, "{value} is not a finite number")
+                    }
+                    (
+                        FloatError::NoneOperand
+                        | FloatError::DivisionByZero
+                        | FloatError::InvalidOperand
+                        | FloatError::PrecisionLoss,
+                        _,
+                    ) => write!(f, "{}", self.kind),
+                }
+            }
+        }
+
+        #[cfg(feature = "std")]
+        impl std::error::Error for DetailedError {}
+    }
+}
+
+/// Generates `FloatError`, `DetailedError` and the types describing its context
+pub fn generate_error_types() -> TokenStream {
+    let float_error = generate_float_error();
+    let violated_bound = generate_violated_bound();
+    let operator = generate_operator();
+    let operation = generate_operation();
+    let detailed_error = generate_detailed_error();
+
+    quote! {
+        #float_error
+        #violated_bound
+        #operator
+        #operation
+        #detailed_error
+    }
+}
@@ -0,0 +1,296 @@
+//! Extremum operations module
+//!
+//! Generates `max()`, `min()`, `clamp()` and `clamp_to()`. The output of `max` and
+//! `min` is the tightest declared type containing every possible result, inferred from
+//! the bounds of both operands, and `clamp` composes the two.
+
+use proc_macro2::{Ident, TokenStream as TokenStream2};
+use quote::quote;
+
+use crate::config::{Bounds, ConstraintDef, TypeConfig, tightest_superset};
+use crate::generator::find_float_constraint_def;
+
+/// Extremum operations, whose output traits are generated per pair of constraints
+#[derive(Clone, Copy)]
+enum Extremum {
+    Max,
+    Min,
+}
+
+/// Combines one side of the bounds of two operands.
+///
+/// The side comes from the larger bound if `pick_larger`, and from the smaller one
+/// otherwise. An `inner` side is the one the result cannot go beyond even if only one
+/// operand is bounded on it, such as the lower side of `max`.
+#[expect(clippy::float_cmp)] // bounds are compared exactly, not within a tolerance
+pub fn combine_side(
+    lhs: (Option<f64>, bool),
+    rhs: (Option<f64>, bool),
+    pick_larger: bool,
+    inner: bool,
+) -> (Option<f64>, bool) {
+    match (lhs, rhs) {
+        ((None, _), (None, _)) => (None, true),
+        ((None, _), side @ (Some(_), _)) | (side @ (Some(_), _), (None, _)) => {
+            if inner {
+                side
+            } else {
+                (None, true)
+            }
+        }
+        ((Some(a), a_inclusive), (Some(b), b_inclusive)) => {
+            if a == b {
+                // An inner side is reached only if both operands can reach it
+                let inclusive = if inner {
+                    a_inclusive && b_inclusive
+                } else {
+                    a_inclusive || b_inclusive
+                };
+                (Some(a), inclusive)
+            } else if (a > b) == pick_larger {
+                (Some(a), a_inclusive)
+            } else {
+                (Some(b), b_inclusive)
+            }
+        }
+    }
+}
+
+/// Computes the exact range of results of an extremum of two constraints
+fn extremum_range(op: Extremum, lhs: &Bounds, rhs: &Bounds) -> Bounds {
+    let lower = |b: &Bounds| (b.lower, b.lower_inclusive);
+    let upper = |b: &Bounds| (b.upper, b.upper_inclusive);
+    let (pick_larger, lower_is_inner) = match op {
+        Extremum::Max => (true, true),
+        Extremum::Min => (false, false),
+    };
+
+    let (lower, lower_inclusive) =
+        combine_side(lower(lhs), lower(rhs), pick_larger, lower_is_inner);
+    let (upper, upper_inclusive) =
+        combine_side(upper(lhs), upper(rhs), pick_larger, !lower_is_inner);
+    Bounds {
+        lower,
+        upper,
+        lower_inclusive,
+        upper_inclusive,
+    }
+}
+
+/// Infers the output of an extremum: the tightest declared type containing its range,
+/// preferring the operand types on ties
+///
+/// The result is one of the operands, so it excludes zero if both of them do.
+fn infer_extremum_output<'a>(
+    op: Extremum,
+    lhs: &ConstraintDef,
+    rhs: &ConstraintDef,
+    constraints: &'a [ConstraintDef],
+) -> Option<&'a ConstraintDef> {
+    let range = extremum_range(op, &lhs.bounds, &rhs.bounds);
+    let excludes_zero = lhs.excludes_zero && rhs.excludes_zero;
+    tightest_superset(&range, excludes_zero, constraints, &[&lhs.name, &rhs.name])
+}
+
+/// Generates the `MaxOutput` and `MinOutput` traits and the extremum methods
+pub fn generate_extremum_impls() -> TokenStream2 {
+    quote! {
+        /// Output constraint of [`Strict::max`] with a `Strict<F, Rhs>` operand
+        ///
+        /// The tightest declared constraint containing every possible maximum, e.g.
+        /// `Positive` for the maximum of a `Positive` and a `Fin` value.
+        pub trait MaxOutput<F: Float, Rhs: Constraint>: Constraint {
+            /// Constraint of the maximum
+            type Output: Constraint;
+        }
+
+        /// Output constraint of [`Strict::min`] with a `Strict<F, Rhs>` operand
+        ///
+        /// The tightest declared constraint containing every possible minimum, e.g.
+        /// `Negative` for the minimum of a `Negative` and a `Fin` value.
+        pub trait MinOutput<F: Float, Rhs: Constraint>: Constraint {
+            /// Constraint of the minimum
+            type Output: Constraint;
+        }
+
+        impl<F: Float, C: Constraint> Strict<F, C> {
+            /// Returns the larger of two values
+            ///
+            /// The output type is inferred from the bounds of both operands, so the
+            /// maximum of a `Positive` and any other value is still `Positive`. This
+            /// takes precedence over `Ord::max`, and gives the same type for two values
+            /// of the same type.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use strict_num_extended::*;
+            ///
+            /// let floor = PositiveF64::new(0.5)?;
+            /// let value = FinF64::new(-3.0)?;
+            ///
+            /// let at_least_floor: PositiveF64 = floor.max(value);
+            /// assert_eq!(at_least_floor.get(), 0.5);
+            /// # Ok::<(), FloatError>(())
+            /// ```
+            #[inline]
+            #[must_use]
+            pub fn max<R: Constraint>(self, rhs: Strict<F, R>) -> Strict<F, <C as MaxOutput<F, R>>::Output>
+            where
+                C: MaxOutput<F, R>,
+            {
+                let result = if self.value >= rhs.value { self.value } else { rhs.value };
+                // SAFETY: the result is one of the operands, and `MaxOutput` admits
+                // every value of both operands that can be the maximum
+                unsafe { Strict::new_unchecked(result) }
+            }
+
+            /// Returns the smaller of two values
+            ///
+            /// The output type is inferred from the bounds of both operands, so the
+            /// minimum of a `Negative` and any other value is still `Negative`. This
+            /// takes precedence over `Ord::min`, and gives the same type for two values
+            /// of the same type.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use strict_num_extended::*;
+            ///
+            /// let ceiling = NegativeF64::new(-1.0)?;
+            /// let value = FinF64::new(2.0)?;
+            ///
+            /// let at_most_ceiling: NegativeF64 = ceiling.min(value);
+            /// assert_eq!(at_most_ceiling.get(), -1.0);
+            /// # Ok::<(), FloatError>(())
+            /// ```
+            #[inline]
+            #[must_use]
+            pub fn min<R: Constraint>(self, rhs: Strict<F, R>) -> Strict<F, <C as MinOutput<F, R>>::Output>
+            where
+                C: MinOutput<F, R>,
+            {
+                let result = if self.value <= rhs.value { self.value } else { rhs.value };
+                // SAFETY: the result is one of the operands, and `MinOutput` admits
+                // every value of both operands that can be the minimum
+                unsafe { Strict::new_unchecked(result) }
+            }
+
+            /// Restricts the value to `[lo, hi]`
+            ///
+            /// Computed as `self.max(lo).min(hi)`, so the output type composes the
+            /// inference of both. Unlike `f64::clamp`, it never panics: if `lo > hi`,
+            /// the result is `hi`.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use strict_num_extended::*;
+            ///
+            /// let lo = NormalizedF64::new(0.25)?;
+            /// let hi = NormalizedF64::new(0.75)?;
+            ///
+            /// let clamped: NormalizedF64 = FinF64::new(3.0)?.clamp(lo, hi);
+            /// assert_eq!(clamped.get(), 0.75);
+            /// # Ok::<(), FloatError>(())
+            /// ```
+            #[inline]
+            #[must_use]
+            pub fn clamp<Lo: Constraint, Hi: Constraint>(
+                self,
+                lo: Strict<F, Lo>,
+                hi: Strict<F, Hi>,
+            ) -> Strict<F, <<C as MaxOutput<F, Lo>>::Output as MinOutput<F, Hi>>::Output>
+            where
+                C: MaxOutput<F, Lo>,
+                <C as MaxOutput<F, Lo>>::Output: MinOutput<F, Hi>,
+            {
+                self.max(lo).min(hi)
+            }
+
+            /// Clamps the value to the bounds of constraint `D`
+            ///
+            /// Values below or above the range become the nearest admitted value. Beyond
+            /// an exclusive bound, that is the next value inside it, or `MIN_POSITIVE` for
+            /// the `0` of `Positive`, which is also what an excluded zero becomes.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use strict_num_extended::*;
+            ///
+            /// let gain = FinF64::new(1.5)?;
+            /// assert_eq!(gain.clamp_to::<Normalized>().get(), 1.0);
+            ///
+            /// let weight: PositiveF64 = FinF64::new(-2.0)?.clamp_to();
+            /// assert_eq!(weight.get(), f64::MIN_POSITIVE);
+            /// # Ok::<(), FloatError>(())
+            /// ```
+            #[inline]
+            #[must_use]
+            pub fn clamp_to<D: Constraint>(self) -> Strict<F, D> {
+                // SAFETY: `saturate` always returns a value within the bounds of `D` at
+                // this width
+                unsafe { Strict::new_unchecked(self.value.saturate::<D>()) }
+            }
+        }
+    }
+}
+
+/// Generates the `MaxOutput` and `MinOutput` implementations of every pair of
+/// constraint types
+pub fn generate_extremum_output_impls(config: &TypeConfig) -> TokenStream2 {
+    let ops = [
+        (Extremum::Max, quote! { MaxOutput }),
+        (Extremum::Min, quote! { MinOutput }),
+    ];
+    let mut impls = Vec::new();
+
+    for lhs_type in &config.constraint_types {
+        for rhs_type in &config.constraint_types {
+            if !lhs_type.is_local && !rhs_type.is_local {
+                continue;
+            }
+            for float_type in &lhs_type.float_types {
+                let lhs = find_float_constraint_def(config, &lhs_type.constraint_name, float_type);
+                let rhs = find_float_constraint_def(config, &rhs_type.constraint_name, float_type);
+                for (op, output_trait) in &ops {
+                    impls.push(generate_extremum_output_impl(
+                        config,
+                        *op,
+                        output_trait,
+                        float_type,
+                        lhs,
+                        rhs,
+                    ));
+                }
+            }
+        }
+    }
+
+    quote! {
+        #(#impls)*
+    }
+}
+
+/// Generates the output of one extremum, if a declared type contains its range
+fn generate_extremum_output_impl(
+    config: &TypeConfig,
+    op: Extremum,
+    output_trait: &TokenStream2,
+    float_type: &Ident,
+    lhs: &ConstraintDef,
+    rhs: &ConstraintDef,
+) -> TokenStream2 {
+    let Some(output) = infer_extremum_output(op, lhs, rhs, config.constraints_for(float_type))
+    else {
+        return quote! {};
+    };
+    let (lhs_name, rhs_name, output_name) = (&lhs.name, &rhs.name, &output.name);
+
+    quote! {
+        impl #output_trait<#float_type, #rhs_name> for #lhs_name {
+            type Output = #output_name;
+        }
+    }
+}
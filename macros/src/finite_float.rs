@@ -1,132 +1,111 @@
-//! `FiniteFloat` struct and basic methods module
+//! `Strict` struct and basic methods module
 
+use proc_macro2::{Ident, Span};
 use quote::quote;
 
 use crate::config::TypeConfig;
 use crate::doc_generator;
-use crate::generator::{build_validation_expr, for_all_constraint_float_types, make_type_alias};
+use crate::generator::{for_all_constraint_float_types, make_type_alias};
+use crate::unary_ops::infer_sin_cos_output_type;
 
-/// Generates concrete struct definitions for each constraint × float type combination
-pub fn generate_concrete_structs(config: &TypeConfig) -> proc_macro2::TokenStream {
-    let structs =
+/// Generates the generic `Strict<F, C>` struct shared by all constraint types
+pub fn generate_strict_struct() -> proc_macro2::TokenStream {
+    quote! {
+        /// A finite floating-point number of width `F` satisfying constraint `C`
+        ///
+        /// Concrete types such as [`PositiveF64`] are aliases of this struct, so code can be
+        /// generic over the constraint, the float width, or both.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use strict_num_extended::*;
+        ///
+        /// fn scale<C: Constraint>(x: Strict<f64, C>, factor: f64) -> Result<FinF64, FloatError> {
+        ///     FinF64::new(x.get() * factor)
+        /// }
+        ///
+        /// let doubled = scale(PositiveF64::new(1.5)?, 2.0)?;
+        /// assert_eq!(doubled.get(), 3.0);
+        ///
+        /// let negated = scale(NegativeF64::new(-1.5)?, -1.0)?;
+        /// assert_eq!(negated.get(), 1.5);
+        /// # Ok::<(), FloatError>(())
+        /// ```
+        #[repr(transparent)]
+        pub struct Strict<F, C> {
+            value: F,
+            _constraint: PhantomData<C>,
+        }
+
+        // Derives would require `C: Clone`, which markers only provide as a supertrait
+        #[expect(clippy::expl_impl_clone_on_copy)]
+        impl<F: Float, C: Constraint> Clone for Strict<F, C> {
+            #[inline]
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+
+        impl<F: Float, C: Constraint> Copy for Strict<F, C> {}
+    }
+}
+
+/// Generates a type alias for each constraint × float type combination
+pub fn generate_concrete_aliases(config: &TypeConfig) -> proc_macro2::TokenStream {
+    let strict = config
+        .crate_path
+        .as_ref()
+        .map_or_else(|| quote! { Strict }, |krate| quote! { #krate::Strict });
+    let aliases =
         for_all_constraint_float_types(config, |type_name, float_type, constraint_def| {
-            let struct_name = make_type_alias(type_name, float_type); // e.g., FinF32
-            let constraint_name = type_name; // e.g., Fin
+            let alias_name = make_type_alias(type_name, float_type); // e.g., FinF32
             let struct_doc =
                 doc_generator::generate_struct_doc(type_name, float_type, constraint_def);
 
             quote! {
                 #[doc = #struct_doc]
-                #[repr(transparent)]
-                #[derive(Clone, Copy)]
-                #[expect(clippy::approx_constant)]
-                pub struct #struct_name {
-                    value: #float_type,
-                    _constraint: core::marker::PhantomData<#constraint_name>,
-                }
+                pub type #alias_name = #strict<#float_type, #type_name>;
             }
         });
 
     quote! {
-        // Concrete struct definitions
-        #(#structs)*
+        // Concrete type aliases
+        #(#aliases)*
     }
 }
 
-/// Generates implementations for each concrete struct
-pub fn generate_concrete_impls(config: &TypeConfig) -> proc_macro2::TokenStream {
-    let impls = for_all_constraint_float_types(config, |type_name, float_type, constraint_def| {
-        let struct_name = make_type_alias(type_name, float_type);
-        let validate_expr = build_validation_expr(constraint_def, float_type);
-        let new_method_doc =
-            doc_generator::generate_new_method_doc(&struct_name, float_type, constraint_def);
+/// Generates the methods shared by every float width
+#[expect(clippy::too_many_lines)]
+pub fn generate_strict_impls(config: &TypeConfig) -> proc_macro2::TokenStream {
+    let width_impls = ["f32", "f64"].iter().map(|float_type| {
+        let float_ident = Ident::new(float_type, Span::call_site());
+        let bounds_const = Ident::new(
+            &format!("{}_BOUNDS", float_type.to_uppercase()),
+            Span::call_site(),
+        );
+        let example_type = make_type_alias(&Ident::new("Fin", Span::call_site()), &float_ident);
+        let example_type_str = example_type.to_string();
+        let const_example = format!(
+            "```\nuse strict_num_extended::{example_type_str};\n\nconst ONE: {example_type_str} = {example_type_str}::new_const(1.0);\nassert_eq!(ONE.get(), 1.0);\n```"
+        );
 
         quote! {
-            impl #struct_name {
-                #[doc = #new_method_doc]
-                #[must_use]
-                pub fn new(value: #float_type) -> Result<Self, FloatError> {
-                    let val_f64: f64 = value.into();
-
-                    // Check for NaN
-                    if val_f64.is_nan() {
-                        return Err(FloatError::NaN);
-                    }
-
-                    // Check for positive infinity
-                    if val_f64.is_infinite() && val_f64 > 0.0 {
-                        return Err(FloatError::PosInf);
-                    }
-
-                    // Check for negative infinity
-                    if val_f64.is_infinite() && val_f64 < 0.0 {
-                        return Err(FloatError::NegInf);
-                    }
-
-                    // Validate bounds and zero exclusion
-                    if #validate_expr {
-                        Ok(Self {
-                            value,
-                            _constraint: core::marker::PhantomData,
-                        })
-                    } else {
-                        Err(FloatError::OutOfRange)
-                    }
-                }
-
-                /// Unsafely creates a finite floating-point number (no validation)
-                ///
-                /// # Safety
-                ///
-                /// Caller must ensure the value satisfies the constraint.
-                /// Violating the constraint leads to undefined behavior.
-                #[inline]
-                pub const unsafe fn new_unchecked(value: #float_type) -> Self {
-                    Self {
-                        value,
-                        _constraint: core::marker::PhantomData,
-                    }
-                }
-
-                /// Gets the inner value
-                ///
-                /// # Example
-                ///
-                /// ```
-                /// use strict_num_extended::FinF32;
-                ///
-                /// let finite = FinF32::new(2.5);
-                /// assert_eq!(finite.unwrap().get(), 2.5);
-                /// ```
-                #[must_use]
-                pub const fn get(&self) -> #float_type {
-                    self.value
-                }
-
-                /// Gets the inner value (alias for `get()`)
+            impl<C: Constraint> Strict<#float_ident, C> {
+                /// Creates a value at compile time
                 ///
                 /// # Example
                 ///
-                /// ```
-                /// use strict_num_extended::FinF32;
-                ///
-                /// let finite = FinF32::new(2.5);
-                /// assert_eq!(finite.unwrap().value(), 2.5);
-                /// ```
-                #[must_use]
-                pub const fn value(&self) -> #float_type {
-                    self.value
-                }
-
-                /// Creates a value at compile time
+                #[doc = #const_example]
                 ///
                 /// # Panics
                 ///
                 /// Will [`panic`] at compile time or runtime if the value does not satisfy the constraint.
                 #[inline]
                 #[must_use]
-                pub const fn new_const(value: #float_type) -> Self {
-                    if #validate_expr {
+                pub const fn new_const(value: #float_ident) -> Self {
+                    if C::#bounds_const.contains(value) {
+                        // SAFETY: the value was just checked against the bounds of `C`
                         unsafe { Self::new_unchecked(value) }
                     } else {
                         panic!("Value does not satisfy the constraint");
@@ -136,66 +115,362 @@ pub fn generate_concrete_impls(config: &TypeConfig) -> proc_macro2::TokenStream
         }
     });
 
+    let sin_cos_output = infer_sin_cos_output_type(config);
+
     quote! {
-        #(#impls)*
-    }
-}
+        impl<F: Float, C: Constraint> Strict<F, C> {
+            /// Creates a new value, checking that it is finite and satisfies `C`
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use strict_num_extended::{FloatError, PositiveF64};
+            ///
+            /// assert_eq!(PositiveF64::new(2.5)?.get(), 2.5);
+            /// assert_eq!(PositiveF64::new(0.0), Err(FloatError::BelowRange));
+            /// assert_eq!(PositiveF64::new(f64::NAN), Err(FloatError::NaN));
+            /// # Ok::<(), FloatError>(())
+            /// ```
+            ///
+            /// # Errors
+            ///
+            /// Returns `Err(FloatError)` if the value does not satisfy the constraint.
+            #[must_use = "Return value may contain an error and should not be ignored"]
+            pub fn new(value: F) -> Result<Self, FloatError> {
+                Self::new_detailed(value).map_err(FloatError::from)
+            }
 
-/// Generates serde support for concrete types
-pub fn generate_concrete_serde_impls(config: &TypeConfig) -> proc_macro2::TokenStream {
-    let serialize_impls = for_all_constraint_float_types(config, |type_name, float_type, _| {
-        let struct_name = make_type_alias(type_name, float_type);
+            /// Creates a new value like [`new`](Self::new), recording the rejected value,
+            /// the type and the violated bound on failure
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use strict_num_extended::*;
+            ///
+            /// let err = NormalizedF64::new_detailed(1.5).unwrap_err();
+            /// assert_eq!(err.kind(), FloatError::AboveRange);
+            /// assert_eq!(err.to_string(), "NormalizedF64: 1.5 violates <= 1");
+            /// ```
+            ///
+            /// # Errors
+            ///
+            /// Returns a [`DetailedError`] if the value does not satisfy the constraint.
+            #[must_use = "Return value may contain an error and should not be ignored"]
+            pub fn new_detailed(value: F) -> Result<Self, DetailedError> {
+                // NaN and infinities never satisfy a constraint
+                if value.is_finite() && value.satisfies::<C>() {
+                    Ok(Self {
+                        value,
+                        _constraint: PhantomData,
+                    })
+                } else {
+                    Err(DetailedError::from_value::<F, C>(value))
+                }
+            }
 
-        quote! {
-            #[cfg(feature = "serde")]
-            impl serde::Serialize for #struct_name
+            /// Unsafely creates a finite floating-point number (no validation)
+            ///
+            /// # Safety
+            ///
+            /// Caller must ensure the value satisfies the constraint.
+            /// Violating the constraint leads to undefined behavior.
+            #[inline]
+            pub const unsafe fn new_unchecked(value: F) -> Self {
+                Self {
+                    value,
+                    _constraint: PhantomData,
+                }
+            }
+
+            /// Gets the inner value
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// use strict_num_extended::FinF32;
+            ///
+            /// let finite = FinF32::new(2.5);
+            /// assert_eq!(finite.unwrap().get(), 2.5);
+            /// ```
+            #[must_use]
+            pub const fn get(&self) -> F {
+                self.value
+            }
+
+            /// Gets the inner value (alias for `get()`)
+            ///
+            /// # Example
+            ///
+            /// ```
+            /// use strict_num_extended::FinF32;
+            ///
+            /// let finite = FinF32::new(2.5);
+            /// assert_eq!(finite.unwrap().value(), 2.5);
+            /// ```
+            #[must_use]
+            pub const fn value(&self) -> F {
+                self.value
+            }
+
+            /// Converts to a constraint that admits every value of `C`
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use strict_num_extended::*;
+            ///
+            /// let positive = PositiveF64::new(2.0)?;
+            /// let non_negative: NonNegativeF64 = positive.convert();
+            /// assert_eq!(non_negative.get(), 2.0);
+            /// # Ok::<(), FloatError>(())
+            /// ```
+            #[inline]
+            #[must_use]
+            pub fn convert<D: Constraint>(self) -> Strict<F, D>
             where
-                #float_type: serde::Serialize,
+                C: SubsetOf<F, D>,
             {
-                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-                where
-                    S: serde::Serializer,
-                {
-                    self.value.serialize(serializer)
+                // SAFETY: every value of `C` satisfies `D` at this width
+                unsafe { Strict::new_unchecked(self.value) }
+            }
+
+            /// Attempts to convert to another constraint
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use strict_num_extended::*;
+            ///
+            /// let fin = FinF64::new(-2.0)?;
+            /// assert!(fin.try_convert::<Positive>().is_err());
+            /// assert_eq!(fin.try_convert::<Negative>()?.get(), -2.0);
+            /// # Ok::<(), FloatError>(())
+            /// ```
+            ///
+            /// # Errors
+            ///
+            /// Returns `Err(FloatError::BelowRange)`, `AboveRange` or `ExcludedZero` if the
+            /// value does not satisfy `D`.
+            #[inline]
+            pub fn try_convert<D: Constraint>(self) -> Result<Strict<F, D>, FloatError> {
+                Strict::new(self.value)
+            }
+
+            /// Computes the absolute value.
+            ///
+            /// The return type is automatically inferred based on the source constraint:
+            /// - `NonNegative`/`NonPositive` → `NonNegative`
+            /// - `NonZero` → `Positive`
+            /// - `Normalized` → `Normalized` (reflexive)
+            /// - `Symmetric` → `Normalized`
+            /// - `Fin` → `NonNegative`
+            ///
+            /// # Examples
+            /// ```
+            /// use strict_num_extended::*;
+            ///
+            /// let neg = NegativeF64::new(-5.0).unwrap();
+            /// let abs_val: PositiveF64 = neg.abs();
+            /// assert_eq!(abs_val.get(), 5.0);
+            /// ```
+            #[inline]
+            #[must_use]
+            pub fn abs(self) -> Strict<F, C::Abs> {
+                // SAFETY: `C::Abs` was inferred at compile time to admit the absolute
+                // value of every value of `C`.
+                unsafe { Strict::new_unchecked(self.value.abs()) }
+            }
+
+            /// Computes the sign function.
+            ///
+            /// Returns the sign of the number:
+            /// - `1.0` if the number is positive
+            /// - `0.0` if the number is zero
+            /// - `-1.0` if the number is negative
+            ///
+            /// The return type is automatically inferred based on the source constraint:
+            /// - `Positive` types → `Normalized` (signum in {0, 1})
+            /// - `Negative` types → `NegativeNormalized` (signum in {-1, 0})
+            /// - `NonZero` types → `Symmetric` (signum in {-1, 1})
+            /// - `Fin`/`Symmetric` → `Symmetric` (signum in {-1, 0, 1})
+            ///
+            /// # Examples
+            /// ```
+            /// use strict_num_extended::*;
+            ///
+            /// let pos = PositiveF64::new(5.0).unwrap();
+            /// let sign: NormalizedF64 = pos.signum();
+            /// assert_eq!(sign.get(), 1.0);
+            ///
+            /// let neg = NegativeF64::new(-5.0).unwrap();
+            /// let sign: NegativeNormalizedF64 = neg.signum();
+            /// assert_eq!(sign.get(), -1.0);
+            /// ```
+            #[inline]
+            #[must_use]
+            pub fn signum(self) -> Strict<F, C::Signum> {
+                // SAFETY: `C::Signum` was inferred at compile time to admit the sign of
+                // every value of `C`.
+                unsafe { Strict::new_unchecked(self.value.signum()) }
+            }
+
+            /// Computes the sine of the value.
+            ///
+            /// # Mathematical Properties
+            ///
+            /// For any finite real input x:
+            /// - **Range**: sin(x) ∈ [-1, 1]
+            /// - **Output Type**: Always returns `Symmetric` ([-1, 1])
+            /// - **Defined**: sin(x) is defined for all finite inputs
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use strict_num_extended::*;
+            ///
+            /// let angle: FinF64 = FinF64::new(std::f64::consts::PI / 2.0).unwrap();
+            /// let sin_val: SymmetricF64 = angle.sin();
+            /// assert_eq!(sin_val.get(), 1.0);
+            ///
+            /// let zero: NonNegativeF64 = NonNegativeF64::new_const(0.0);
+            /// let sin_zero: SymmetricF64 = zero.sin();
+            /// assert_eq!(sin_zero.get(), 0.0);
+            /// ```
+            #[cfg(feature = "std")]
+            #[inline]
+            #[must_use]
+            pub fn sin(self) -> Strict<F, #sin_cos_output> {
+                // SAFETY: sin(x) for any finite x always produces a value in [-1, 1],
+                // which satisfies the Symmetric constraint.
+                unsafe { Strict::new_unchecked(self.value.sin()) }
+            }
+
+            /// Computes the cosine of the value.
+            ///
+            /// # Mathematical Properties
+            ///
+            /// For any finite real input x:
+            /// - **Range**: cos(x) ∈ [-1, 1]
+            /// - **Output Type**: Always returns `Symmetric` ([-1, 1])
+            /// - **Defined**: cos(x) is defined for all finite inputs
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use strict_num_extended::*;
+            ///
+            /// let angle: FinF64 = FinF64::new(0.0).unwrap();
+            /// let cos_val: SymmetricF64 = angle.cos();
+            /// assert_eq!(cos_val.get(), 1.0);
+            ///
+            /// let pi: FinF64 = FinF64::new(std::f64::consts::PI).unwrap();
+            /// let cos_pi: SymmetricF64 = pi.cos();
+            /// assert!((cos_pi.get() - (-1.0)).abs() < f64::EPSILON);
+            /// ```
+            #[cfg(feature = "std")]
+            #[inline]
+            #[must_use]
+            pub fn cos(self) -> Strict<F, #sin_cos_output> {
+                // SAFETY: cos(x) for any finite x always produces a value in [-1, 1],
+                // which satisfies the Symmetric constraint.
+                unsafe { Strict::new_unchecked(self.value.cos()) }
+            }
+
+            /// Computes the tangent of the value.
+            ///
+            /// # Mathematical Properties
+            ///
+            /// For any finite real input x (excluding π/2 + kπ):
+            /// - **Range**: tan(x) ∈ (-∞, +∞)
+            /// - **Output Type**: Always returns `Fin` (unbounded)
+            /// - **Singular Points**: Undefined at π/2 + kπ (may return infinity)
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use strict_num_extended::*;
+            ///
+            /// let angle: FinF64 = FinF64::new(0.0).unwrap();
+            /// let tan_val: Result<FinF64, FloatError> = angle.tan();
+            /// assert_eq!(tan_val.unwrap().get(), 0.0);
+            ///
+            /// let pi_over_4: FinF64 = FinF64::new(std::f64::consts::PI / 4.0).unwrap();
+            /// let tan_45: Result<FinF64, FloatError> = pi_over_4.tan();
+            /// assert!((tan_45.unwrap().get() - 1.0).abs() < f64::EPSILON);
+            /// ```
+            ///
+            /// # Errors
+            ///
+            /// Returns `Err(FloatError::Overflow)` if the result is not finite.
+            #[cfg(feature = "std")]
+            #[inline]
+            pub fn tan(self) -> Result<Strict<F, Fin>, FloatError> {
+                let result = self.value.tan();
+                // tan() may produce ±∞ at singular points (π/2 + kπ)
+                if !result.is_finite() {
+                    return Err(FloatError::Overflow);
                 }
+                // SAFETY: When tan() produces a finite value, it satisfies the Fin constraint.
+                unsafe { Ok(Strict::new_unchecked(result)) }
             }
         }
-    });
 
-    let deserialize_impls = for_all_constraint_float_types(config, |type_name, float_type, _| {
-        let struct_name = make_type_alias(type_name, float_type);
+        #(#width_impls)*
+    }
+}
 
-        quote! {
-            #[cfg(feature = "serde")]
-            impl<'de> serde::Deserialize<'de> for #struct_name
+/// Generates serde support for `Strict`
+pub fn generate_serde_impls() -> proc_macro2::TokenStream {
+    quote! {
+        #[cfg(feature = "serde")]
+        impl<F, C> serde::Serialize for Strict<F, C>
+        where
+            F: Float + serde::Serialize,
+            C: Constraint,
+        {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
             where
-                #float_type: serde::Deserialize<'de>,
+                S: serde::Serializer,
             {
-                fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-                where
-                    D: serde::Deserializer<'de>,
-                {
-                    // First deserialize the raw value
-                    let value = #float_type::deserialize(deserializer)?;
-
-                    // Then validate using the new() method
-                    Self::new(value).map_err(|e| {
-                        use serde::de::Error;
-                        match e {
-                            FloatError::NaN => D::Error::custom("value is NaN"),
-                            FloatError::PosInf => D::Error::custom("value is positive infinity"),
-                            FloatError::NegInf => D::Error::custom("value is negative infinity"),
-                            FloatError::OutOfRange => D::Error::custom("value is out of range"),
-                            FloatError::NoneOperand => D::Error::custom("none operand"),
-                        }
-                    })
-                }
+                self.value.serialize(serializer)
             }
         }
-    });
 
-    quote! {
-        #(#serialize_impls)*
-        #(#deserialize_impls)*
+        #[cfg(feature = "serde")]
+        impl<'de, F, C> serde::Deserialize<'de> for Strict<F, C>
+        where
+            F: Float + serde::Deserialize<'de>,
+            C: Constraint,
+        {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                // First deserialize the raw value
+                let value = F::deserialize(deserializer)?;
+
+                // Then validate, keeping the context for the message
+                Self::new_detailed(value).map_err(|e| {
+                    use serde::de::Error;
+                    match e.kind() {
+                        FloatError::NaN => D::Error::custom("value is NaN"),
+                        FloatError::PosInf => D::Error::custom("value is positive infinity"),
+                        FloatError::NegInf => D::Error::custom("value is negative infinity"),
+                        FloatError::BelowRange
+                        | FloatError::AboveRange
+                        | FloatError::ExcludedZero => {
+                            D::Error::custom(format_args!("value is out of range ({e})"))
+                        }
+                        FloatError::NoneOperand
+                        | FloatError::DivisionByZero
+                        | FloatError::Overflow
+                        | FloatError::Underflow
+                        | FloatError::InvalidOperand
+                        | FloatError::PrecisionLoss => D::Error::custom(e),
+                    }
+                })
+            }
+        }
     }
 }
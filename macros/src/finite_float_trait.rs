@@ -5,9 +5,6 @@
 use proc_macro2::TokenStream;
 use quote::quote;
 
-use crate::config::TypeConfig;
-use crate::generator::{for_all_constraint_float_types, make_type_alias};
-
 /// Generates `IntoF64` trait and its implementations for f32 and f64
 fn generate_into_f64_trait() -> TokenStream {
     quote! {
@@ -37,7 +34,7 @@ fn generate_into_f64_trait() -> TokenStream {
 ///
 /// This trait provides a unified interface for all finite floating-point types,
 /// allowing polymorphic usage through `Box<dyn FiniteFloat>`.
-pub fn generate_finite_float_trait(_config: &TypeConfig) -> TokenStream {
+pub fn generate_finite_float_trait() -> TokenStream {
     let into_f64_trait = generate_into_f64_trait();
 
     quote! {
@@ -97,33 +94,57 @@ pub fn generate_finite_float_trait(_config: &TypeConfig) -> TokenStream {
             /// assert_eq!(val.as_f64(), 2.5);
             /// ```
             fn as_f64(&self) -> f64;
+
+            /// Returns the name of the constraint, e.g. `"Positive"`
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use strict_num_extended::{FiniteFloat, NormalizedF32, PositiveF64};
+            ///
+            /// let values: [&dyn FiniteFloat; 2] = [
+            ///     &PositiveF64::new(2.0).unwrap(),
+            ///     &NormalizedF32::new(0.5).unwrap(),
+            /// ];
+            /// assert_eq!(values[0].constraint_name(), "Positive");
+            /// assert_eq!(values[1].constraint_name(), "Normalized");
+            /// ```
+            fn constraint_name(&self) -> &'static str;
+
+            /// Returns a human-readable description of the admitted values
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use strict_num_extended::{FiniteFloat, NonNegativeF64, NormalizedF32};
+            ///
+            /// assert_eq!(NonNegativeF64::new(2.0).unwrap().describe(), "non-negative");
+            /// assert_eq!(NormalizedF32::new(0.5).unwrap().describe(), "range [0, 1]");
+            /// ```
+            fn describe(&self) -> &'static str;
         }
     }
 }
 
-/// Generates `FiniteFloat` trait implementations for all constraint types
-///
-/// This function iterates through all generated types (`FinF32`, `FinF64`, `PositiveF32`, etc.)
-/// and generates the corresponding `FiniteFloat` trait implementations.
-pub fn generate_finite_float_impls(config: &TypeConfig) -> TokenStream {
-    let impls = for_all_constraint_float_types(config, |type_name, float_type, _| {
-        let struct_name = make_type_alias(type_name, float_type);
+/// Generates the `FiniteFloat` trait implementation shared by all constraint types
+pub fn generate_finite_float_impls() -> TokenStream {
+    quote! {
+        impl<F: Float, C: Constraint> FiniteFloat for Strict<F, C> {
+            fn new<T: IntoF64>(value: T) -> Result<Self, FloatError> {
+                Strict::new(F::from_f64(value.into_f64()))
+            }
 
-        quote! {
-            impl FiniteFloat for #struct_name {
-                fn new<T: IntoF64>(value: T) -> Result<Self, FloatError> {
-                    let f64_val = value.into_f64();
-                    #struct_name::new(f64_val as #float_type)
-                }
+            fn as_f64(&self) -> f64 {
+                self.value.into_f64()
+            }
 
-                fn as_f64(&self) -> f64 {
-                    self.get() as f64
-                }
+            fn constraint_name(&self) -> &'static str {
+                C::NAME
             }
-        }
-    });
 
-    quote! {
-        #(#impls)*
+            fn describe(&self) -> &'static str {
+                C::DESCRIPTION
+            }
+        }
     }
 }
@@ -1,9 +1,7 @@
 //! # `FromStr` trait implementation generation module
 //!
-//! Automatically generates `FromStr` implementations for all constraint types
+//! Automatically generates the `FromStr` implementation for all constraint types
 
-use crate::config::TypeConfig;
-use crate::generator::for_all_constraint_float_types;
 use quote::quote;
 
 /// Generate `ParseFloatError` type and its trait implementations
@@ -63,32 +61,26 @@ pub fn generate_parse_error_from_impls() -> proc_macro2::TokenStream {
     }
 }
 
-/// Generate all `FromStr` implementations
-pub fn generate_fromstr_traits(config: &TypeConfig) -> proc_macro2::TokenStream {
-    let impls = for_all_constraint_float_types(config, |type_name, float_type, _| {
-        let struct_name = crate::generator::make_type_alias(type_name, float_type);
-
-        quote! {
-            impl core::str::FromStr for #struct_name {
-                type Err = ParseFloatError;
+/// Generate the `FromStr` implementation shared by all constraint types
+pub fn generate_fromstr_traits() -> proc_macro2::TokenStream {
+    quote! {
+        impl<F: Float, C: Constraint> core::str::FromStr for Strict<F, C> {
+            type Err = ParseFloatError;
 
-                fn from_str(s: &str) -> Result<Self, Self::Err> {
-                    // 1. Trim whitespace and check for empty string
-                    let trimmed = s.trim();
-                    if trimmed.is_empty() {
-                        return Err(ParseFloatError::Empty);
-                    }
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                // 1. Trim whitespace and check for empty string
+                let trimmed = s.trim();
+                if trimmed.is_empty() {
+                    return Err(ParseFloatError::Empty);
+                }
 
-                    // 2. Parse using standard library (automatically supports scientific notation)
-                    // Using ? operator which uses From trait for error conversion
-                    let value: #float_type = trimmed.parse()?;
+                // 2. Parse using standard library (automatically supports scientific notation)
+                // Using ? operator which uses From trait for error conversion
+                let value: F = trimmed.parse()?;
 
-                    // 3. Validate constraints (reuses existing new() logic)
-                    Self::new(value).map_err(ParseFloatError::ValidationFailed)
-                }
+                // 3. Validate constraints (reuses existing new() logic)
+                Self::new(value).map_err(ParseFloatError::ValidationFailed)
             }
         }
-    });
-
-    quote! { #(#impls)* }
+    }
 }
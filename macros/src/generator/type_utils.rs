@@ -52,6 +52,34 @@ pub fn find_constraint_def<'a>(
         .expect("Constraint not found")
 }
 
+/// Finds constraint definition by constraint name, with bounds rounded to `float_type`
+///
+/// # Arguments
+///
+/// * `config` - Type configuration
+/// * `constraint_name` - Constraint name (e.g., `Positive`, `Negative`)
+/// * `float_type` - Floating-point type (e.g., `f32`, `f64`)
+///
+/// # Returns
+///
+/// Reference to the found constraint definition
+///
+/// # Panics
+///
+/// Panics if the corresponding constraint definition is not found
+pub fn find_float_constraint_def<'a>(
+    config: &'a TypeConfig,
+    constraint_name: &Ident,
+    float_type: &Ident,
+) -> &'a ConstraintDef {
+    #[expect(clippy::expect_used)]
+    config
+        .constraints_for(float_type)
+        .iter()
+        .find(|c| &c.name == constraint_name)
+        .expect("Constraint not found")
+}
+
 /// Filters constraint types that include the specified floating-point type
 ///
 /// # Arguments
@@ -83,12 +111,14 @@ pub fn filter_constraint_types_by_float<'a>(
 ///
 /// This function encapsulates the common pattern of iterating through all constraint types
 /// and their associated float types, providing the constraint definition and type names
-/// to a generator function.
+/// to a generator function. Built-in types that custom types are defined against are
+/// skipped, since their code already lives in the main crate.
 ///
 /// # Arguments
 ///
 /// * `config` - Type configuration containing constraint definitions
-/// * `generator` - Function that generates code for each (`type_name`, `float_type`, `constraint_def`) combination
+/// * `generator` - Function that generates code for each (`type_name`, `float_type`, `constraint_def`) combination,
+///   where `constraint_def` has its bounds rounded to `float_type`
 ///
 /// # Returns
 ///
@@ -102,16 +132,12 @@ where
 {
     let mut results = Vec::new();
 
-    for type_def in &config.constraint_types {
+    for type_def in config.local_types() {
         let type_name = &type_def.type_name;
-        #[expect(clippy::expect_used)]
-        let constraint_def = config
-            .constraints
-            .iter()
-            .find(|c| c.name == type_def.constraint_name)
-            .expect("Constraint not found");
 
         for float_type in &type_def.float_types {
+            let constraint_def =
+                find_float_constraint_def(config, &type_def.constraint_name, float_type);
             results.push(generator(type_name, float_type, constraint_def));
         }
     }
@@ -0,0 +1,343 @@
+//! Interpolation and easing module
+//!
+//! Generates `lerp()` and `inverse_lerp()`, parameterized by the `[0, 1]` type, and the
+//! `easing` module of curves mapping that type onto itself. The output of `lerp` is the
+//! tightest declared type containing both endpoints' ranges. The `[0, 1]` type is looked
+//! up by its bounds, so invocations that do not define it get no interpolation.
+
+use proc_macro2::{Ident, TokenStream as TokenStream2};
+use quote::quote;
+
+use crate::config::{Bounds, ConstraintDef, Sign, TypeConfig, tightest_superset};
+use crate::extremum_ops::combine_side;
+use crate::generator::find_float_constraint_def;
+
+/// Finds the constraint admitting exactly `[0, 1]`
+fn find_normalized_type(config: &TypeConfig) -> Option<Ident> {
+    config.find_type_by_constraints(Sign::Positive, &Bounds::closed(Some(0.0), Some(1.0)), false)
+}
+
+/// Computes the smallest interval containing the ranges of both endpoints
+fn hull_range(lhs: &Bounds, rhs: &Bounds) -> Bounds {
+    let (lower, lower_inclusive) = combine_side(
+        (lhs.lower, lhs.lower_inclusive),
+        (rhs.lower, rhs.lower_inclusive),
+        false,
+        false,
+    );
+    let (upper, upper_inclusive) = combine_side(
+        (lhs.upper, lhs.upper_inclusive),
+        (rhs.upper, rhs.upper_inclusive),
+        true,
+        false,
+    );
+    Bounds {
+        lower,
+        upper,
+        lower_inclusive,
+        upper_inclusive,
+    }
+}
+
+/// Infers the output of `lerp`: the tightest declared type containing the hull of both
+/// endpoints, preferring the endpoint types on ties
+///
+/// Interpolating between values of opposite signs may reach zero, so zero is only
+/// excluded when the hull itself excludes it.
+fn infer_lerp_output<'a>(
+    lhs: &ConstraintDef,
+    rhs: &ConstraintDef,
+    constraints: &'a [ConstraintDef],
+) -> Option<&'a ConstraintDef> {
+    let range = hull_range(&lhs.bounds, &rhs.bounds);
+    tightest_superset(&range, false, constraints, &[&lhs.name, &rhs.name])
+}
+
+/// Generates the easing curves, as free functions of the `easing` module
+fn generate_easing_module(normalized: &Ident) -> TokenStream2 {
+    quote! {
+        /// Easing curves over [`Normalized`] values
+        ///
+        /// Every curve maps `[0, 1]` onto itself, fixing `0` and `1`, so animation
+        /// parameters never need to be validated again. Results are clamped against
+        /// rounding.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use strict_num_extended::*;
+        ///
+        /// let t = NormalizedF64::new(0.25)?;
+        /// let eased: NormalizedF64 = easing::smoothstep(t);
+        /// assert_eq!(eased.get(), 0.156_25);
+        ///
+        /// let position: FinF64 = FinF64::new(-10.0)?.lerp(FinF64::new(10.0)?, easing::ease_in_quad(t));
+        /// assert_eq!(position.get(), -8.75);
+        /// # Ok::<(), FloatError>(())
+        /// ```
+        pub mod easing {
+            use super::{Float, #normalized, Strict};
+
+            /// Wraps a curve value, clamping rounding errors back into `[0, 1]`
+            #[inline]
+            fn eased<F: Float>(value: F) -> Strict<F, #normalized> {
+                // SAFETY: `saturate` always returns a value within the bounds of the
+                // constraint at this width
+                unsafe { Strict::new_unchecked(value.saturate::<#normalized>()) }
+            }
+
+            /// Hermite smoothstep, `3t² - 2t³`
+            #[inline]
+            #[must_use]
+            pub fn smoothstep<F: Float>(t: Strict<F, #normalized>) -> Strict<F, #normalized> {
+                let (t, n) = (t.get(), F::from_f64);
+                eased(t * t * (n(3.0) - n(2.0) * t))
+            }
+
+            /// Perlin's smootherstep, `6t⁵ - 15t⁴ + 10t³`
+            #[inline]
+            #[must_use]
+            pub fn smootherstep<F: Float>(t: Strict<F, #normalized>) -> Strict<F, #normalized> {
+                let (t, n) = (t.get(), F::from_f64);
+                eased(t * t * t * (t * (t * n(6.0) - n(15.0)) + n(10.0)))
+            }
+
+            /// Quadratic ease-in, `t²`
+            #[inline]
+            #[must_use]
+            pub fn ease_in_quad<F: Float>(t: Strict<F, #normalized>) -> Strict<F, #normalized> {
+                let t = t.get();
+                eased(t * t)
+            }
+
+            /// Quadratic ease-out, `1 - (1 - t)²`
+            #[inline]
+            #[must_use]
+            pub fn ease_out_quad<F: Float>(t: Strict<F, #normalized>) -> Strict<F, #normalized> {
+                let (t, n) = (t.get(), F::from_f64);
+                eased(t * (n(2.0) - t))
+            }
+
+            /// Quadratic ease-in for the first half, ease-out for the second
+            #[inline]
+            #[must_use]
+            pub fn ease_in_out_quad<F: Float>(t: Strict<F, #normalized>) -> Strict<F, #normalized> {
+                let (t, n) = (t.get(), F::from_f64);
+                if t < n(0.5) {
+                    eased(n(2.0) * t * t)
+                } else {
+                    let u = n(2.0) - n(2.0) * t;
+                    eased(n(1.0) - u * u / n(2.0))
+                }
+            }
+
+            /// Cubic ease-in, `t³`
+            #[inline]
+            #[must_use]
+            pub fn ease_in_cubic<F: Float>(t: Strict<F, #normalized>) -> Strict<F, #normalized> {
+                let t = t.get();
+                eased(t * t * t)
+            }
+
+            /// Cubic ease-out, `1 - (1 - t)³`
+            #[inline]
+            #[must_use]
+            pub fn ease_out_cubic<F: Float>(t: Strict<F, #normalized>) -> Strict<F, #normalized> {
+                let (t, n) = (t.get(), F::from_f64);
+                let u = n(1.0) - t;
+                eased(n(1.0) - u * u * u)
+            }
+
+            /// Cubic ease-in for the first half, ease-out for the second
+            #[inline]
+            #[must_use]
+            pub fn ease_in_out_cubic<F: Float>(t: Strict<F, #normalized>) -> Strict<F, #normalized> {
+                let (t, n) = (t.get(), F::from_f64);
+                if t < n(0.5) {
+                    eased(n(4.0) * t * t * t)
+                } else {
+                    let u = n(2.0) - n(2.0) * t;
+                    eased(n(1.0) - u * u * u / n(2.0))
+                }
+            }
+
+            /// Sinusoidal ease-in, `1 - cos(tπ/2)`
+            #[inline]
+            #[must_use]
+            pub fn ease_in_sine<F: Float>(t: Strict<F, #normalized>) -> Strict<F, #normalized> {
+                let n = F::from_f64;
+                // Computed as `1 - sin((1 - t)π/2)`, since `cos(π/2)` is not exactly zero
+                eased(n(1.0) - ((n(1.0) - t.get()) * F::PI / n(2.0)).sin())
+            }
+
+            /// Sinusoidal ease-out, `sin(tπ/2)`
+            #[inline]
+            #[must_use]
+            pub fn ease_out_sine<F: Float>(t: Strict<F, #normalized>) -> Strict<F, #normalized> {
+                eased((t.get() * F::PI / F::from_f64(2.0)).sin())
+            }
+
+            /// Sinusoidal ease-in-out, `(1 - cos(tπ)) / 2`
+            #[inline]
+            #[must_use]
+            pub fn ease_in_out_sine<F: Float>(t: Strict<F, #normalized>) -> Strict<F, #normalized> {
+                let n = F::from_f64;
+                eased((n(1.0) - (t.get() * F::PI).cos()) / n(2.0))
+            }
+        }
+    }
+}
+
+/// Generates the `LerpOutput` trait, `lerp`, `inverse_lerp` and the `easing` module,
+/// if the config defines a `[0, 1]` type
+pub fn generate_interpolation_impls(config: &TypeConfig) -> TokenStream2 {
+    let Some(normalized) = find_normalized_type(config) else {
+        return quote! {};
+    };
+    let easing = generate_easing_module(&normalized);
+
+    quote! {
+        /// Output constraint of [`Strict::lerp`] towards a `Strict<F, Rhs>` endpoint
+        ///
+        /// The tightest declared constraint containing both endpoints' ranges, e.g.
+        /// `Symmetric` between a `Normalized` and a `NegativeNormalized` value.
+        pub trait LerpOutput<F: Float, Rhs: Constraint>: Constraint {
+            /// Constraint of the interpolated value
+            type Output: Constraint;
+        }
+
+        impl<F: Float, C: Constraint> Strict<F, C> {
+            /// Linearly interpolates from `self` to `end`
+            ///
+            /// Returns `self` at `t = 0` and `end` at `t = 1`. The result never leaves
+            /// the interval between both endpoints, so the output type is inferred from
+            /// their bounds and the interpolation cannot fail: two values of the same
+            /// type interpolate to that type.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use strict_num_extended::*;
+            ///
+            /// let t = NormalizedF64::new(0.25)?;
+            ///
+            /// let opacity: NormalizedF64 = NormalizedF64::new(0.0)?.lerp(NormalizedF64::new(1.0)?, t);
+            /// assert_eq!(opacity.get(), 0.25);
+            ///
+            /// let offset: SymmetricF64 = NegativeNormalizedF64::new(-1.0)?.lerp(NormalizedF64::new(1.0)?, t);
+            /// assert_eq!(offset.get(), -0.5);
+            /// # Ok::<(), FloatError>(())
+            /// ```
+            #[inline]
+            #[must_use]
+            pub fn lerp<R: Constraint>(
+                self,
+                end: Strict<F, R>,
+                t: Strict<F, #normalized>,
+            ) -> Strict<F, <C as LerpOutput<F, R>>::Output>
+            where
+                C: LerpOutput<F, R>,
+            {
+                let (a, b, t) = (self.value, end.value, t.value);
+                // Exact at both ends; rounding past an endpoint is clamped below
+                let value = (F::from_f64(1.0) - t) * a + t * b;
+                let (lo, hi) = if a <= b { (a, b) } else { (b, a) };
+                let result = if value < lo {
+                    lo
+                } else if value > hi {
+                    hi
+                } else {
+                    value
+                };
+                // SAFETY: the result lies between both endpoints, and `LerpOutput`
+                // admits every value between any two values of `C` and `R`
+                unsafe { Strict::new_unchecked(result) }
+            }
+
+            /// Finds the parameter `t` for which `self.lerp(end, t)` is `value`
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use strict_num_extended::*;
+            ///
+            /// let start = FinF64::new(10.0)?;
+            /// let end = FinF64::new(20.0)?;
+            ///
+            /// let t: NormalizedF64 = start.inverse_lerp(end, PositiveF64::new(12.5)?)?;
+            /// assert_eq!(t.get(), 0.25);
+            /// assert!(start.inverse_lerp(end, FinF64::new(30.0)?).is_err());
+            /// # Ok::<(), FloatError>(())
+            /// ```
+            ///
+            /// # Errors
+            ///
+            /// Returns `Err(FloatError::DivisionByZero)` if both endpoints are equal, and
+            /// `Err(FloatError::BelowRange)` or `AboveRange` if `value` is below or above
+            /// both of them.
+            #[inline]
+            pub fn inverse_lerp<R: Constraint, V: Constraint>(
+                self,
+                end: Strict<F, R>,
+                value: Strict<F, V>,
+            ) -> Result<Strict<F, #normalized>, FloatError> {
+                let (a, b, v) = (self.value, end.value, value.value);
+                if a == b {
+                    return Err(FloatError::DivisionByZero);
+                }
+                // Checked first, since `v - a` may overflow for a value far outside
+                let (lo, hi) = if a <= b { (a, b) } else { (b, a) };
+                if v < lo {
+                    return Err(FloatError::BelowRange);
+                }
+                if v > hi {
+                    return Err(FloatError::AboveRange);
+                }
+                let span = b - a;
+                if span.is_finite() {
+                    return Strict::new((v - a) / span);
+                }
+                // The span overflowed: halving the operands keeps the differences finite
+                let half = F::from_f64(0.5);
+                Strict::new((v * half - a * half) / (b * half - a * half))
+            }
+        }
+
+        #easing
+    }
+}
+
+/// Generates the `LerpOutput` implementations of every pair of constraint types
+pub fn generate_lerp_output_impls(config: &TypeConfig) -> TokenStream2 {
+    if find_normalized_type(config).is_none() {
+        return quote! {};
+    }
+    let mut impls = Vec::new();
+
+    for lhs_type in &config.constraint_types {
+        for rhs_type in &config.constraint_types {
+            if !lhs_type.is_local && !rhs_type.is_local {
+                continue;
+            }
+            for float_type in &lhs_type.float_types {
+                let lhs = find_float_constraint_def(config, &lhs_type.constraint_name, float_type);
+                let rhs = find_float_constraint_def(config, &rhs_type.constraint_name, float_type);
+                let Some(output) = infer_lerp_output(lhs, rhs, config.constraints_for(float_type))
+                else {
+                    continue;
+                };
+                let (lhs_name, rhs_name, output_name) = (&lhs.name, &rhs.name, &output.name);
+
+                impls.push(quote! {
+                    impl LerpOutput<#float_type, #rhs_name> for #lhs_name {
+                        type Output = #output_name;
+                    }
+                });
+            }
+        }
+    }
+
+    quote! {
+        #(#impls)*
+    }
+}
@@ -0,0 +1,195 @@
+//! Iterator operations module
+//!
+//! Generates `Sum` and `Product` for types closed under addition and multiplication,
+//! the infallible `Product` of types whose multiplication is safe and closed, and the
+//! compensated `sum_compensated()`.
+
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+
+use crate::config::{ArithmeticOp, TypeConfig};
+use crate::generator::for_all_constraint_float_types;
+
+/// Generates the `Sum` and `Product` implementations and the `SumCompensated` trait
+pub fn generate_iterator_impls() -> TokenStream2 {
+    quote! {
+        /// Marks constraints whose products never fail
+        ///
+        /// Implemented when multiplying two values of the constraint is safe and gives
+        /// the same constraint, and the empty product `1` is admitted, as for
+        /// [`Normalized`] and [`Symmetric`]. Enables `Product` directly into `Strict`.
+        pub trait InfallibleProduct<F: Float>: MulOutput<F, Self, Output = Strict<F, Self>> {}
+
+        impl<F: Float, C: Constraint> core::iter::Sum<Strict<F, C>> for Result<Strict<F, C>, FloatError>
+        where
+            C: AddOutput<F, C>,
+            <C as AddOutput<F, C>>::Output: OpOutput<F, Output = C>,
+        {
+            /// Adds the values, stopping at the first error
+            ///
+            /// The sum of no values is `0`, which is an error for types excluding zero.
+            fn sum<I: Iterator<Item = Strict<F, C>>>(mut iter: I) -> Self {
+                match iter.next() {
+                    Some(first) => iter.try_fold(first, |acc, value| (acc + value).into_result()),
+                    None => Strict::new(F::from_f64(0.0)),
+                }
+            }
+        }
+
+        impl<'a, F: Float, C: Constraint> core::iter::Sum<&'a Strict<F, C>>
+            for Result<Strict<F, C>, FloatError>
+        where
+            C: AddOutput<F, C>,
+            <C as AddOutput<F, C>>::Output: OpOutput<F, Output = C>,
+        {
+            fn sum<I: Iterator<Item = &'a Strict<F, C>>>(iter: I) -> Self {
+                iter.copied().sum()
+            }
+        }
+
+        impl<F: Float, C: Constraint> core::iter::Product<Strict<F, C>>
+            for Result<Strict<F, C>, FloatError>
+        where
+            C: MulOutput<F, C>,
+            <C as MulOutput<F, C>>::Output: OpOutput<F, Output = C>,
+        {
+            /// Multiplies the values, stopping at the first error
+            ///
+            /// The product of no values is `1`, which is an error for types excluding it.
+            fn product<I: Iterator<Item = Strict<F, C>>>(mut iter: I) -> Self {
+                match iter.next() {
+                    Some(first) => iter.try_fold(first, |acc, value| (acc * value).into_result()),
+                    None => Strict::new(F::from_f64(1.0)),
+                }
+            }
+        }
+
+        impl<'a, F: Float, C: Constraint> core::iter::Product<&'a Strict<F, C>>
+            for Result<Strict<F, C>, FloatError>
+        where
+            C: MulOutput<F, C>,
+            <C as MulOutput<F, C>>::Output: OpOutput<F, Output = C>,
+        {
+            fn product<I: Iterator<Item = &'a Strict<F, C>>>(iter: I) -> Self {
+                iter.copied().product()
+            }
+        }
+
+        impl<F: Float, C: InfallibleProduct<F>> core::iter::Product for Strict<F, C> {
+            fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+                // SAFETY: `InfallibleProduct` is only implemented for constraints admitting 1
+                let one = unsafe { Self::new_unchecked(F::from_f64(1.0)) };
+                iter.fold(one, |acc, value| acc * value)
+            }
+        }
+
+        impl<'a, F: Float, C: InfallibleProduct<F>> core::iter::Product<&'a Self> for Strict<F, C> {
+            fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                iter.copied().product()
+            }
+        }
+
+        /// Compensated summation of [`Strict`] values
+        ///
+        /// Available on iterators of the types for which `Sum` is implemented.
+        pub trait SumCompensated<F: Float, C: Constraint>: Iterator<Item = Strict<F, C>> {
+            /// Adds the values with Neumaier's compensated summation
+            ///
+            /// The rounding error of every addition is accumulated separately and added
+            /// back at the end, so long sums of values of different magnitudes stay
+            /// accurate. Like `sum()`, the sum of no values is `0`.
+            ///
+            /// # Errors
+            ///
+            /// Returns [`FloatError::Overflow`] as soon as a partial sum overflows,
+            /// and `BelowRange` or `AboveRange` if the sum is not admitted by `C`.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use strict_num_extended::*;
+            ///
+            /// let readings = [1.0, 1e-16, 1e-16, 1e-16, 1e-16].map(|r| PositiveF64::new(r).unwrap());
+            ///
+            /// let naive: Result<PositiveF64, FloatError> = readings.iter().sum();
+            /// assert_eq!(naive?.get(), 1.0);
+            ///
+            /// let compensated = readings.into_iter().sum_compensated()?;
+            /// assert_eq!(compensated.get(), 1.0000000000000004);
+            /// # Ok::<(), FloatError>(())
+            /// ```
+            fn sum_compensated(self) -> Result<Strict<F, C>, FloatError>;
+        }
+
+        impl<F: Float, C: Constraint, I: Iterator<Item = Strict<F, C>>> SumCompensated<F, C> for I
+        where
+            C: AddOutput<F, C>,
+            <C as AddOutput<F, C>>::Output: OpOutput<F, Output = C>,
+        {
+            fn sum_compensated(mut self) -> Result<Strict<F, C>, FloatError> {
+                let Some(first) = self.next() else {
+                    return Strict::new(F::from_f64(0.0));
+                };
+                let mut sum = first.value;
+                let mut compensation = F::from_f64(0.0);
+                for value in self.map(|value| value.value) {
+                    let total = sum + value;
+                    if !total.is_finite() {
+                        return Err(FloatError::of_operation::<F, C>(
+                            Operation::new(Operator::Add, sum, value),
+                            total,
+                        ));
+                    }
+                    // The low-order bits lost by the addition, from the smaller operand
+                    compensation = if sum.abs() >= value.abs() {
+                        compensation + ((sum - total) + value)
+                    } else {
+                        compensation + ((value - total) + sum)
+                    };
+                    sum = total;
+                }
+
+                let result = sum + compensation;
+                if result.satisfies::<C>() {
+                    // SAFETY: the value has just been validated
+                    Ok(unsafe { Strict::new_unchecked(result) })
+                } else {
+                    Err(FloatError::of_operation::<F, C>(
+                        Operation::new(Operator::Add, sum, compensation),
+                        result,
+                    ))
+                }
+            }
+        }
+    }
+}
+
+/// Generates `InfallibleProduct` for every constraint whose multiplication is safe and
+/// closed, and that admits `1`
+pub fn generate_infallible_product_impls(config: &TypeConfig) -> TokenStream2 {
+    let impls = for_all_constraint_float_types(config, |_, float_type, constraint_def| {
+        let name = &constraint_def.name;
+        let key = (
+            ArithmeticOp::Mul,
+            name.to_string(),
+            name.to_string(),
+            float_type.to_string(),
+        );
+        let closed = config
+            .arithmetic_results
+            .get(&key)
+            .is_some_and(|result| result.is_safe && result.output_type == *name);
+
+        if closed && constraint_def.bounds.contains(1.0) {
+            quote! {
+                impl InfallibleProduct<#float_type> for #name {}
+            }
+        } else {
+            quote! {}
+        }
+    });
+
+    quote! {
+        #(#impls)*
+    }
+}
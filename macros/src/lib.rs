@@ -7,52 +7,73 @@ use proc_macro::TokenStream;
 use quote::quote;
 use syn::parse_macro_input;
 
+mod angle_ops;
 mod arithmetic;
+mod checked_arithmetic;
 mod comparison;
 mod config;
 mod constants;
+mod constraint_impls;
+mod constraint_info;
 mod conversion;
+mod core_traits;
+mod custom_types;
 mod doc_generator;
+mod error_types;
+mod extremum_ops;
 mod finite_float;
 mod finite_float_trait;
 mod float_conversion;
 mod fromstr_impl;
 mod generator;
+mod interpolation;
+mod iterator_ops;
 mod option_arithmetic;
 mod result_arithmetic;
+mod root_ops;
 mod type_aliases;
 mod unary_ops;
 
-use arithmetic::{generate_arithmetic_impls, generate_neg_impls};
-use comparison::{generate_comparison_traits, generate_concrete_comparison_traits};
-use config::TypeConfig;
+use angle_ops::generate_angle_impls;
+use arithmetic::{
+    generate_arithmetic_impls, generate_arithmetic_output_impls, generate_assign_impls,
+    generate_bounded_impls, generate_bounded_output_impls, generate_integer_arithmetic_impls,
+    generate_mul_add_impls, generate_neg_impls, generate_neg_output_impls, generate_recip_impls,
+    generate_recip_output_impls, generate_saturating_impls,
+};
+use checked_arithmetic::generate_checked_impls;
+use comparison::generate_comparison_traits;
+use config::{CustomTypeConfig, TypeConfig};
 use constants::generate_constants;
-use conversion::generate_conversion_traits;
+use constraint_impls::{generate_constraint_impls, generate_constraint_markers};
+use constraint_info::generate_constraint_info;
+use conversion::{generate_conversion_traits, generate_subset_impls};
+use core_traits::{generate_core_traits, generate_private_module};
+use custom_types::{generate_custom_imports, generate_define_constraints_macro, ignore_doc_tests};
+use error_types::generate_error_types;
+use extremum_ops::{generate_extremum_impls, generate_extremum_output_impls};
 use finite_float::{
-    generate_concrete_impls, generate_concrete_serde_impls, generate_concrete_structs,
+    generate_concrete_aliases, generate_serde_impls, generate_strict_impls, generate_strict_struct,
 };
 use finite_float_trait::{generate_finite_float_impls, generate_finite_float_trait};
-use float_conversion::{
-    generate_as_f32_primitive_methods, generate_as_f32_type_methods,
-    generate_as_f64_primitive_methods, generate_as_f64_type_methods,
-    generate_try_into_f32_type_methods,
-};
+use float_conversion::generate_float_conversion_methods;
 use fromstr_impl::{
     generate_fromstr_traits, generate_parse_error_from_impls, generate_parse_error_type,
 };
+use interpolation::{generate_interpolation_impls, generate_lerp_output_impls};
+use iterator_ops::{generate_infallible_product_impls, generate_iterator_impls};
 use option_arithmetic::generate_option_arithmetic_impls;
 use result_arithmetic::generate_result_arithmetic_impls;
+use root_ops::{generate_root_impls, generate_root_output_impls};
 use type_aliases::generate_type_aliases;
-use unary_ops::{
-    generate_abs_impls, generate_cos_impls, generate_signum_impls, generate_sin_impls,
-    generate_tan_impls,
-};
 
 /// Generates common definitions (constants)
 fn generate_common_definitions() -> proc_macro2::TokenStream {
     quote! {
         use core::marker::PhantomData;
-        use core::ops::{Add, Sub, Mul, Div, Neg};
+        use core::ops::{
+            Add, AddAssign, Sub, SubAssign, Mul, MulAssign, Div, DivAssign, Rem, RemAssign, Neg,
+        };
 
         // ========== f64 boundary bit representation constants ==========
         const F64_MIN_BITS: i64 = f64::MIN.to_bits() as i64;
@@ -73,128 +94,74 @@ fn generate_common_definitions() -> proc_macro2::TokenStream {
     }
 }
 
-/// Generates the `FloatError` type and its trait implementations
-fn generate_error_type() -> proc_macro2::TokenStream {
-    quote! {
-        /// Errors that can occur when creating or operating on finite floats
-        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-        pub enum FloatError {
-            /// Value is NaN (Not a Number)
-            NaN,
-            /// Value is positive infinity
-            PosInf,
-            /// Value is negative infinity
-            NegInf,
-            /// Value is outside the valid range for this type
-            OutOfRange,
-            /// Right-hand side operand is None in Option arithmetic
-            NoneOperand,
-        }
-
-        impl core::fmt::Display for FloatError {
-            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-                match self {
-                    FloatError::NaN => write!(f, "value is NaN (Not a Number)"),
-                    FloatError::PosInf => write!(f, "value is positive infinity"),
-                    FloatError::NegInf => write!(f, "value is negative infinity"),
-                    FloatError::OutOfRange => write!(f, "value is outside the valid range for this type"),
-                    FloatError::NoneOperand => write!(f, "right-hand side operand is None in Option arithmetic"),
-                }
-            }
-        }
-
-        #[cfg(feature = "std")]
-        impl std::error::Error for FloatError {}
-    }
-}
-
-/// Generates zero-sized constraint marker types dynamically from config
-fn generate_constraint_markers(config: &TypeConfig) -> proc_macro2::TokenStream {
-    let markers = config.constraints.iter().map(|constraint| {
-        let name = &constraint.name;
-        quote! {
-            #[doc(hidden)]
-            #[derive(Debug, Clone, Copy)]
-            pub(crate) struct #name;
-        }
-    });
-
-    quote! {
-        #(#markers)*
-    }
-}
-
 /// Main macro: generates finite floating-point types with automatic `is_finite()` checking.
+///
+/// Each type is declared as `(Name, ["cond", ...])`, where every condition is one of
+/// `>= x`, `<= x`, `> x`, `< x` or `!= 0.0`. Malformed declarations are rejected with
+/// an error pointing at the offending condition or type name; see `define_constraints!`
+/// in the main crate for examples.
+///
+/// A bound `x` may be a numeric literal, a constant from `core::{f32,f64}::consts`
+/// (`PI`, `TAU`, `FRAC_PI_2`, ...), a limit (`MAX`, `f32::MAX`, `EPSILON`, ...) or
+/// simple arithmetic on them (`2 * PI`, `-1e-3`). Bare constants are emitted per
+/// width, so f32 types compare against the f32 constant.
 #[proc_macro]
 pub fn generate_finite_float_types(input: TokenStream) -> TokenStream {
+    let builtin_input = proc_macro2::TokenStream::from(input.clone());
     let config = parse_macro_input!(input as TypeConfig);
 
     // Collect all code to be generated
     let mut all_code = vec![
         generate_common_definitions(),
-        generate_error_type(),
+        generate_error_types(),
         generate_parse_error_type(),
         generate_parse_error_from_impls(),
-        generate_constraint_markers(&config),
-        generate_concrete_structs(&config),
+        generate_private_module(),
+        generate_core_traits(),
+        generate_strict_struct(),
         generate_comparison_traits(),
     ];
 
-    // Generate concrete struct implementations (includes new, get, new_unchecked, new_const)
-    all_code.push(generate_concrete_impls(&config));
-    all_code.push(generate_concrete_serde_impls(&config));
-    all_code.push(generate_concrete_comparison_traits(&config));
-
-    // Generate type-safe arithmetic operations
-    all_code.push(generate_arithmetic_impls(&config));
-
-    // Generate arithmetic operations for Option types
-    all_code.push(generate_option_arithmetic_impls(&config));
-
-    // Generate arithmetic operations for Result types
-    all_code.push(generate_result_arithmetic_impls(&config));
-
-    // Generate negation operations
-    all_code.push(generate_neg_impls(&config));
-
-    // Generate unary operations (abs, signum)
-    all_code.push(generate_abs_impls(&config));
-    all_code.push(generate_signum_impls(&config));
-
-    // Generate trigonometric operations (sin, cos, tan)
-    all_code.push(generate_sin_impls(&config));
-    all_code.push(generate_cos_impls(&config));
-    all_code.push(generate_tan_impls(&config));
-
-    // Generate negation operations for Result types
-    // Note: Cannot implement Neg for Result<T, E> due to orphan rules
-    // Users should use .map() instead: result.map(|x| -x)
-    // all_code.push(generate_result_neg_impls(&config));
-
-    // Generate F32/F64 conversion methods
-    all_code.push(generate_as_f32_primitive_methods(&config));
-    all_code.push(generate_as_f64_primitive_methods(&config));
-    all_code.push(generate_as_f32_type_methods(&config));
-    all_code.push(generate_as_f64_type_methods(&config));
-    all_code.push(generate_try_into_f32_type_methods(&config));
-
-    // Generate From/TryFrom traits
+    // Generate the methods and traits shared by every constraint type
+    all_code.push(generate_strict_impls(&config));
+    all_code.push(generate_serde_impls());
+    all_code.push(generate_arithmetic_impls());
+    all_code.push(generate_integer_arithmetic_impls(&config));
+    all_code.push(generate_assign_impls());
+    all_code.push(generate_saturating_impls());
+    all_code.push(generate_bounded_impls());
+    all_code.push(generate_mul_add_impls());
+    all_code.push(generate_iterator_impls());
+    all_code.push(generate_extremum_impls());
+    all_code.push(generate_interpolation_impls(&config));
+    all_code.push(generate_angle_impls(&config));
+    all_code.push(generate_option_arithmetic_impls());
+    all_code.push(generate_result_arithmetic_impls());
+    all_code.push(generate_checked_impls(&config));
+    all_code.push(generate_neg_impls());
+    all_code.push(generate_recip_impls());
+    all_code.push(generate_root_impls());
+    all_code.push(generate_float_conversion_methods());
     all_code.push(generate_conversion_traits(&config));
+    all_code.push(generate_fromstr_traits());
+    all_code.push(generate_constants());
 
-    // Generate FromStr trait implementations
-    all_code.push(generate_fromstr_traits(&config));
+    // Generate FiniteFloat and ConstraintInfo traits
+    all_code.push(generate_finite_float_trait());
+    all_code.push(generate_finite_float_impls());
+    all_code.push(generate_constraint_info());
 
-    // Generate FiniteFloat trait and implementations
-    all_code.push(generate_finite_float_trait(&config));
-    all_code.push(generate_finite_float_impls(&config));
-
-    // Generate constants
-    all_code.push(generate_constants(&config));
+    // Generate constraint markers, their implementations and the concrete types
+    all_code.push(generate_constraint_markers(&config));
+    all_code.extend(generate_constraint_type_impls(&config));
+    all_code.push(generate_concrete_aliases(&config));
 
     // Generate type aliases
     all_code.push(generate_type_aliases(&config));
 
+    // Generate `define_constraints!` for downstream crates
+    all_code.push(generate_define_constraints_macro(&builtin_input));
+
     // Combine all code
     let expanded = quote! {
         #(#all_code)*
@@ -202,3 +169,60 @@ pub fn generate_finite_float_types(input: TokenStream) -> TokenStream {
 
     TokenStream::from(expanded)
 }
+
+/// Backend of `strict_num_extended::define_constraints!`.
+///
+/// Generates custom constraints against the built-in ones forwarded by the main crate.
+/// Only the constraint markers and their trait implementations are emitted: all
+/// methods and operators come from the main crate's generic `Strict` type.
+#[doc(hidden)]
+#[proc_macro]
+pub fn define_custom_constraints(input: TokenStream) -> TokenStream {
+    let CustomTypeConfig(config) = parse_macro_input!(input as CustomTypeConfig);
+
+    let markers = generate_constraint_markers(&config);
+    let concrete_aliases = generate_concrete_aliases(&config);
+    let aliases = generate_type_aliases(&config);
+    let imports = generate_custom_imports(&config);
+    let impls = generate_constraint_type_impls(&config);
+
+    // Implementations live in an anonymous block so that their imports stay private
+    let expanded = quote! {
+        #markers
+        #concrete_aliases
+        #aliases
+
+        const _: () = {
+            #imports
+            #(#impls)*
+        };
+    };
+
+    TokenStream::from(ignore_doc_tests(expanded))
+}
+
+/// Generates the trait implementations of every constraint defined by `config`
+fn generate_constraint_type_impls(config: &TypeConfig) -> Vec<proc_macro2::TokenStream> {
+    vec![
+        // Bounds and unary output types
+        generate_constraint_impls(config),
+        // Negation output types
+        generate_neg_output_impls(config),
+        // Reciprocal output types
+        generate_recip_output_impls(config),
+        // Root output types
+        generate_root_output_impls(config),
+        // Arithmetic output types
+        generate_arithmetic_output_impls(config),
+        // Output types of the bounded operations
+        generate_bounded_output_impls(config),
+        // Output types of `max` and `min`
+        generate_extremum_output_impls(config),
+        // Output types of `lerp`
+        generate_lerp_output_impls(config),
+        // Subset relations used by `convert()`
+        generate_subset_impls(config),
+        // Constraints whose products never fail
+        generate_infallible_product_impls(config),
+    ]
+}
@@ -17,6 +17,9 @@
 //!
 //! # Alternatives for Non-Implementable Patterns
 //!
+//! Converting the options into `Checked` values with `ok_or(FloatError::NoneOperand)`
+//! supports all of these patterns. Otherwise:
+//!
 //! ## Negation Operation
 //!
 //! Use the `.map()` method:
@@ -71,63 +74,41 @@
 //! // Or use combinators from libraries like itertools
 //! ```
 
-use proc_macro2::TokenStream as TokenStream2;
-use quote::quote;
+use proc_macro2::{Ident, Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
 
-use crate::config::{ArithmeticOp, TypeConfig, get_standard_arithmetic_ops};
-use crate::generator::generate_arithmetic_for_all_types;
+use crate::arithmetic::generate_ref_forwarding_impls;
+use crate::config::{get_euclid_ops, get_standard_arithmetic_ops};
 
 /// Generates `Lhs op Option<Rhs>` pattern arithmetic operation implementations
-fn generate_pattern_lhs_op_option_rhs(
-    config: &TypeConfig,
-    ops: &[(ArithmeticOp, &str, &str, TokenStream2)],
-) -> TokenStream2 {
-    generate_arithmetic_for_all_types(
-        config,
-        ops,
-        |lhs_alias,
-         rhs_alias,
-         output_alias,
-         trait_ident,
-         method_ident,
-         _op_symbol,
-         result,
-         _op,
-         _| {
-            if result.is_safe {
-                // Safe operation: base returns concrete type, so Option operation returns Option<Output>
-                quote! {
-                    impl #trait_ident<Option<#rhs_alias>> for #lhs_alias {
-                        type Output = Option<#output_alias>;
+fn generate_pattern_lhs_op_option_rhs(trait_name: &str, method_name: &str) -> TokenStream2 {
+    let trait_ident = Ident::new(trait_name, Span::call_site());
+    let method_ident = Ident::new(method_name, Span::call_site());
+    let output_trait = format_ident!("{}Output", trait_name);
 
-                        fn #method_ident(self, rhs: Option<#rhs_alias>) -> Self::Output {
-                            match rhs {
-                                Some(b) => {
-                                    let inner_result = self.#method_ident(b);
-                                    Some(inner_result)
-                                }
-                                None => None,
-                            }
-                        }
-                    }
-                }
-            } else {
-                // Fallible operation: base returns Result, so Option operation returns Result<Output, FloatError>
-                quote! {
-                    impl #trait_ident<Option<#rhs_alias>> for #lhs_alias {
-                        type Output = Result<#output_alias, FloatError>;
+    let ref_impls = generate_ref_forwarding_impls(
+        &trait_ident,
+        &method_ident,
+        &quote! { F: Float, L: Constraint, R: Constraint },
+        &quote! { Strict<F, L> },
+        &quote! { Option<Strict<F, R>> },
+    );
+
+    // Safe operations return Option<Output>, fallible ones Result<Output, FloatError>
+    quote! {
+        #ref_impls
 
-                        fn #method_ident(self, rhs: Option<#rhs_alias>) -> Self::Output {
-                            match rhs {
-                                Some(b) => self.#method_ident(b),
-                                None => Err(FloatError::NoneOperand),
-                            }
-                        }
-                    }
+        impl<F: Float, L: #output_trait<F, R>, R: Constraint> #trait_ident<Option<Strict<F, R>>> for Strict<F, L> {
+            type Output = <<L as #output_trait<F, R>>::Output as OpOutput<F>>::Optional;
+
+            fn #method_ident(self, rhs: Option<Strict<F, R>>) -> Self::Output {
+                match rhs {
+                    Some(b) => self.#method_ident(b).into_optional(),
+                    None => <<L as #output_trait<F, R>>::Output as OpOutput<F>>::none(),
                 }
             }
-        },
-    )
+        }
+    }
 }
 
 /// Generates Option type arithmetic operation implementations
@@ -136,6 +117,8 @@ fn generate_pattern_lhs_op_option_rhs(
 ///
 /// - `Lhs op Option<Rhs>` -> `Option<Output>` or `Result<Option<Output>, FloatError>`
 ///
+/// Either operand may also be passed by reference.
+///
 /// # Return Type Rules
 ///
 /// - **Safe operations** (e.g., `PositiveF64 + NegativeF64 -> FinF64`):
@@ -191,7 +174,16 @@ fn generate_pattern_lhs_op_option_rhs(
 /// assert!(result.is_err());
 /// assert_eq!(result.unwrap_err(), FloatError::NoneOperand);
 /// ```
-pub fn generate_option_arithmetic_impls(config: &TypeConfig) -> TokenStream2 {
-    let ops = get_standard_arithmetic_ops();
-    generate_pattern_lhs_op_option_rhs(config, &ops)
+pub fn generate_option_arithmetic_impls() -> TokenStream2 {
+    let impls = get_standard_arithmetic_ops()
+        .iter()
+        .chain(&get_euclid_ops())
+        .map(|(_, trait_name, method_name, _)| {
+            generate_pattern_lhs_op_option_rhs(trait_name, method_name)
+        })
+        .collect::<Vec<_>>();
+
+    quote! {
+        #(#impls)*
+    }
 }
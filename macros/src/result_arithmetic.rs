@@ -11,7 +11,8 @@
 //!
 //! # Alternative for Result Negation
 //!
-//! Use the `.map()` method:
+//! Wrap the value in `Checked`, which implements `Neg` and `Checked op Checked`, or
+//! use the `.map()` method:
 //!
 //! ```text
 //! let a: Result<PositiveF64, FloatError> = Ok(PositiveF64::new_const(5.0));
@@ -25,11 +26,11 @@
 //! assert!(neg_err.is_err());
 //! ```
 
-use proc_macro2::TokenStream as TokenStream2;
-use quote::quote;
+use proc_macro2::{Ident, Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
 
-use crate::config::{ArithmeticOp, TypeConfig, get_standard_arithmetic_ops};
-use crate::generator::generate_arithmetic_for_all_types;
+use crate::arithmetic::generate_ref_forwarding_impls;
+use crate::config::{get_euclid_ops, get_standard_arithmetic_ops};
 
 /// Generates arithmetic operations for Result types.
 ///
@@ -37,121 +38,93 @@ use crate::generator::generate_arithmetic_for_all_types;
 /// 1. `Lhs op Result<Rhs, FloatError>` -> Result<Output, `FloatError`>
 /// 2. `Result<Lhs, FloatError> op Rhs` -> Result<Output, `FloatError`>
 ///
+/// Either operand may also be passed by reference.
+///
 /// Error propagation strategy:
 /// - Safe operations: wrap concrete result in Ok(...)
 /// - Fallible operations: directly propagate Result from base operation
 /// - Division: zero check is handled by base operation
-pub fn generate_result_arithmetic_impls(config: &TypeConfig) -> TokenStream2 {
-    let ops = get_standard_arithmetic_ops();
+pub fn generate_result_arithmetic_impls() -> TokenStream2 {
+    let impls = get_standard_arithmetic_ops()
+        .iter()
+        .chain(&get_euclid_ops())
+        .map(|(_, trait_name, method_name, _)| {
+            let trait_ident = Ident::new(trait_name, Span::call_site());
+            let method_ident = Ident::new(method_name, Span::call_site());
+            let output_trait = format_ident!("{}Output", trait_name);
+
+            // Generate implementations for both patterns
+            let pattern1_impl =
+                generate_pattern_lhs_op_result_rhs(&trait_ident, &method_ident, &output_trait);
+            let pattern2_impl =
+                generate_pattern_result_lhs_op_rhs(&trait_ident, &method_ident, &output_trait);
 
-    // Generate implementations for all three patterns
-    let pattern1_impls = generate_pattern_lhs_op_result_rhs(config, &ops);
-    let pattern2_impls = generate_pattern_result_lhs_op_rhs(config, &ops);
-    // let pattern3_impls = generate_pattern_result_lhs_op_result_rhs(config, &ops);
+            let generics = quote! { F: Float, L: Constraint, R: Constraint };
+            let pattern1_ref_impls = generate_ref_forwarding_impls(
+                &trait_ident,
+                &method_ident,
+                &generics,
+                &quote! { Strict<F, L> },
+                &quote! { Result<Strict<F, R>, FloatError> },
+            );
+            let pattern2_ref_impls = generate_ref_forwarding_impls(
+                &trait_ident,
+                &method_ident,
+                &generics,
+                &quote! { Result<Strict<F, L>, FloatError> },
+                &quote! { Strict<F, R> },
+            );
+
+            quote! {
+                #pattern1_impl
+                #pattern1_ref_impls
+                #pattern2_impl
+                #pattern2_ref_impls
+            }
+        })
+        .collect::<Vec<_>>();
 
     quote! {
-        #pattern1_impls
-        #pattern2_impls
-        // #pattern3_impls
+        #(#impls)*
     }
 }
 
 /// Pattern 1: Lhs op Result<Rhs, `FloatError`>
 fn generate_pattern_lhs_op_result_rhs(
-    config: &TypeConfig,
-    ops: &[(ArithmeticOp, &str, &str, TokenStream2)],
+    trait_ident: &Ident,
+    method_ident: &Ident,
+    output_trait: &Ident,
 ) -> TokenStream2 {
-    generate_arithmetic_for_all_types(
-        config,
-        ops,
-        |lhs_alias,
-         rhs_alias,
-         output_alias,
-         trait_ident,
-         method_ident,
-         _op_symbol,
-         result,
-         _op,
-         _| {
-            if result.is_safe {
-                // Safe operation: base returns concrete type, wrap in Ok
-                quote! {
-                    impl #trait_ident<Result<#rhs_alias, FloatError>> for #lhs_alias {
-                        type Output = Result<#output_alias, FloatError>;
-
-                        fn #method_ident(self, rhs: Result<#rhs_alias, FloatError>) -> Self::Output {
-                            match rhs {
-                                Ok(b) => Ok(self.#method_ident(b)),
-                                Err(e) => Err(e),
-                            }
-                        }
-                    }
-                }
-            } else {
-                // Fallible operation: base returns Result, directly propagate
-                quote! {
-                    impl #trait_ident<Result<#rhs_alias, FloatError>> for #lhs_alias {
-                        type Output = Result<#output_alias, FloatError>;
+    quote! {
+        impl<F: Float, L: #output_trait<F, R>, R: Constraint> #trait_ident<Result<Strict<F, R>, FloatError>> for Strict<F, L> {
+            type Output = Result<
+                Strict<F, <<L as #output_trait<F, R>>::Output as OpOutput<F>>::Output>,
+                FloatError,
+            >;
 
-                        fn #method_ident(self, rhs: Result<#rhs_alias, FloatError>) -> Self::Output {
-                            match rhs {
-                                Ok(b) => self.#method_ident(b),
-                                Err(e) => Err(e),
-                            }
-                        }
-                    }
-                }
+            fn #method_ident(self, rhs: Result<Strict<F, R>, FloatError>) -> Self::Output {
+                rhs.and_then(|b| self.#method_ident(b).into_result())
             }
-        },
-    )
+        }
+    }
 }
 
 /// Pattern 2: Result<Lhs, `FloatError`> op Rhs
 fn generate_pattern_result_lhs_op_rhs(
-    config: &TypeConfig,
-    ops: &[(ArithmeticOp, &str, &str, TokenStream2)],
+    trait_ident: &Ident,
+    method_ident: &Ident,
+    output_trait: &Ident,
 ) -> TokenStream2 {
-    generate_arithmetic_for_all_types(
-        config,
-        ops,
-        |lhs_alias,
-         rhs_alias,
-         output_alias,
-         trait_ident,
-         method_ident,
-         _op_symbol,
-         result,
-         _op,
-         _| {
-            if result.is_safe {
-                // Safe operation: base returns concrete type, wrap in Ok
-                quote! {
-                    impl #trait_ident<#rhs_alias> for Result<#lhs_alias, FloatError> {
-                        type Output = Result<#output_alias, FloatError>;
-
-                        fn #method_ident(self, rhs: #rhs_alias) -> Self::Output {
-                            match self {
-                                Ok(a) => Ok(a.#method_ident(rhs)),
-                                Err(e) => Err(e),
-                            }
-                        }
-                    }
-                }
-            } else {
-                // Fallible operation: base returns Result, directly propagate
-                quote! {
-                    impl #trait_ident<#rhs_alias> for Result<#lhs_alias, FloatError> {
-                        type Output = Result<#output_alias, FloatError>;
+    quote! {
+        impl<F: Float, L: #output_trait<F, R>, R: Constraint> #trait_ident<Strict<F, R>> for Result<Strict<F, L>, FloatError> {
+            type Output = Result<
+                Strict<F, <<L as #output_trait<F, R>>::Output as OpOutput<F>>::Output>,
+                FloatError,
+            >;
 
-                        fn #method_ident(self, rhs: #rhs_alias) -> Self::Output {
-                            match self {
-                                Ok(a) => a.#method_ident(rhs),
-                                Err(e) => Err(e),
-                            }
-                        }
-                    }
-                }
+            fn #method_ident(self, rhs: Strict<F, R>) -> Self::Output {
+                self.and_then(|a| a.#method_ident(rhs).into_result())
             }
-        },
-    )
+        }
+    }
 }
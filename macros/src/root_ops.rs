@@ -0,0 +1,246 @@
+//! Root operations module
+//!
+//! Generates `sqrt()`, `cbrt()` and `hypot()`. The outputs of `sqrt` and `cbrt` are
+//! inferred per constraint, keeping the input type when it contains every root, and
+//! the output of `hypot` is inferred per pair of constraints.
+
+use proc_macro2::{Ident, TokenStream as TokenStream2};
+use quote::quote;
+
+use crate::config::{ArithmeticResult, Bounds, ConstraintDef, FloatLimits, TypeConfig};
+use crate::generator::{find_float_constraint_def, for_all_constraint_float_types};
+use crate::unary_ops::{infer_cbrt_output, infer_non_negative_type, infer_sqrt_output};
+
+/// Largest magnitude of a bounded constraint
+fn max_magnitude(bounds: &Bounds) -> Option<f64> {
+    Some(bounds.lower?.abs().max(bounds.upper?.abs()))
+}
+
+/// Infers the output type and safety of `hypot()` for one float width
+///
+/// The result is never negative, and is zero only if both operands are. It is safe
+/// when both operands are bounded and the hypotenuse of their largest magnitudes is
+/// finite.
+fn infer_hypot_output(
+    lhs: &ConstraintDef,
+    rhs: &ConstraintDef,
+    config: &TypeConfig,
+    float_type: &Ident,
+) -> ArithmeticResult {
+    let limits = FloatLimits::of(float_type);
+    let is_safe = max_magnitude(&lhs.bounds)
+        .zip(max_magnitude(&rhs.bounds))
+        .is_some_and(|(l, r)| limits.is_finite(l.hypot(r)));
+
+    ArithmeticResult {
+        output_type: infer_non_negative_type(lhs.excludes_zero || rhs.excludes_zero, config),
+        is_safe,
+    }
+}
+
+/// Generates the output type of a possibly fallible operation
+fn op_output(float_type: &Ident, result: &ArithmeticResult) -> TokenStream2 {
+    let output_type = &result.output_type;
+    if result.is_safe {
+        quote! { Strict<#float_type, #output_type> }
+    } else {
+        quote! { Result<Strict<#float_type, #output_type>, FloatError> }
+    }
+}
+
+/// Generates the output traits and the root methods
+pub fn generate_root_impls() -> TokenStream2 {
+    quote! {
+        /// Output of [`Strict::sqrt`] at width `F`
+        ///
+        /// Non-negative constraints give a `Strict` value, of the same constraint when
+        /// it contains every root. Constraints admitting negative values give a
+        /// `Result`, since their square root may be NaN.
+        pub trait SqrtOutput<F: Float>: Constraint {
+            /// `Strict<F, _>` when the square root cannot fail, otherwise a `Result`
+            type Output: OpOutput<F>;
+        }
+
+        /// Output constraint of [`Strict::cbrt`] at width `F`
+        ///
+        /// The cube root keeps the sign and the zero exclusion of its input, so this is
+        /// the constraint itself when it contains every root.
+        pub trait CbrtOutput<F: Float>: Constraint {
+            /// Constraint of the cube root
+            type Output: Constraint;
+        }
+
+        /// Output of [`Strict::hypot`] with a `Strict<F, Rhs>` operand
+        ///
+        /// `NonNegative`, or `Positive` if either operand excludes zero. It is a
+        /// `Result` unless both operands are bounded, since the hypotenuse of large
+        /// values overflows.
+        pub trait HypotOutput<F: Float, Rhs: Constraint>: Constraint {
+            /// `Strict<F, _>` when the hypotenuse cannot fail, otherwise a `Result`
+            type Output: OpOutput<F>;
+        }
+
+        impl<F: Float, C: Constraint> Strict<F, C> {
+            /// Computes the square root
+            ///
+            /// Non-negative types return their own type, such as `Normalized` →
+            /// `Normalized`. Types admitting negative values return a `Result` of the
+            /// tightest type containing the roots, such as `Symmetric` →
+            /// `Result<Normalized>`.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use strict_num_extended::*;
+            ///
+            /// let area = PositiveF64::new(16.0)?;
+            /// let side: PositiveF64 = area.sqrt();
+            /// assert_eq!(side.get(), 4.0);
+            ///
+            /// let gain: NormalizedF64 = NormalizedF64::new(0.25)?.sqrt();
+            /// assert_eq!(gain.get(), 0.5);
+            ///
+            /// let root: Result<NonNegativeF64, FloatError> = FinF64::new(-1.0)?.sqrt();
+            /// assert_eq!(root, Err(FloatError::NaN));
+            /// # Ok::<(), FloatError>(())
+            /// ```
+            ///
+            /// # Errors
+            ///
+            /// Fallible outputs return `Err(FloatError::NaN)` for negative values.
+            #[inline]
+            #[must_use = "Return value may contain an error and should not be ignored"]
+            pub fn sqrt(self) -> <C as SqrtOutput<F>>::Output
+            where
+                C: SqrtOutput<F>,
+            {
+                // SAFETY: `SqrtOutput` was inferred at compile time to admit the root of
+                // every non-negative value of `C`, and fallible outputs validate it
+                unsafe { <<C as SqrtOutput<F>>::Output as OpOutput<F>>::from_value(self.value.sqrt()) }
+            }
+
+            /// Computes the cube root
+            ///
+            /// Never fails, and keeps the sign, the zero exclusion and the bounds of the
+            /// value: `NonZero` → `NonZero`, `Negative` → `Negative`, `Symmetric` →
+            /// `Symmetric`.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use strict_num_extended::*;
+            ///
+            /// let volume = NegativeF64::new(-27.0)?;
+            /// let edge: NegativeF64 = volume.cbrt();
+            /// assert_eq!(edge.get(), -3.0);
+            ///
+            /// let unit: SymmetricF64 = SymmetricF64::new(0.125)?.cbrt();
+            /// assert_eq!(unit.get(), 0.5);
+            /// # Ok::<(), FloatError>(())
+            /// ```
+            #[inline]
+            #[must_use]
+            pub fn cbrt(self) -> Strict<F, <C as CbrtOutput<F>>::Output>
+            where
+                C: CbrtOutput<F>,
+            {
+                // SAFETY: `CbrtOutput` was inferred at compile time to admit the cube
+                // root of every value of `C`
+                unsafe { Strict::new_unchecked(self.value.cbrt()) }
+            }
+
+            /// Computes `sqrt(self² + other²)` without intermediate overflow
+            ///
+            /// The result is `Positive` if either operand excludes zero, otherwise
+            /// `NonNegative`. It is a `Result` unless both operands are bounded.
+            ///
+            /// # Examples
+            ///
+            /// ```
+            /// use strict_num_extended::*;
+            ///
+            /// let dx = PositiveF64::new(3.0)?;
+            /// let dy = FinF64::new(-4.0)?;
+            /// let distance: Result<PositiveF64, FloatError> = dx.hypot(dy);
+            /// assert_eq!(distance?.get(), 5.0);
+            ///
+            /// // Bounded operands cannot overflow
+            /// let diagonal: NonNegativeF64 = NormalizedF64::new(0.6)?.hypot(SymmetricF64::new(-0.8)?);
+            /// assert_eq!(diagonal.get(), 1.0);
+            /// # Ok::<(), FloatError>(())
+            /// ```
+            ///
+            /// # Errors
+            ///
+            /// Fallible outputs return `Err(FloatError::Overflow)` if the result is
+            /// infinite.
+            #[inline]
+            #[must_use = "Return value may contain an error and should not be ignored"]
+            pub fn hypot<R: Constraint>(self, other: Strict<F, R>) -> <C as HypotOutput<F, R>>::Output
+            where
+                C: HypotOutput<F, R>,
+            {
+                let result = self.value.hypot(other.value);
+                // SAFETY: The root configuration has proven at compile time whether
+                // every hypotenuse is finite. Safe outputs skip validation, fallible
+                // outputs validate the result.
+                unsafe {
+                    <<C as HypotOutput<F, R>>::Output as OpOutput<F>>::from_operation(
+                        Operation::new(Operator::Hypot, self.value, other.value),
+                        result,
+                    )
+                }
+            }
+        }
+    }
+}
+
+/// Generates the `SqrtOutput`, `CbrtOutput` and `HypotOutput` implementations of all
+/// constraint types
+pub fn generate_root_output_impls(config: &TypeConfig) -> TokenStream2 {
+    let unary_impls = for_all_constraint_float_types(config, |_, float_type, constraint_def| {
+        let constraints = config.constraints_for(float_type);
+        let constraint_name = &constraint_def.name;
+        let sqrt_output = op_output(float_type, &infer_sqrt_output(constraint_def, constraints));
+        let cbrt_output = infer_cbrt_output(constraint_def, constraints);
+
+        quote! {
+            impl SqrtOutput<#float_type> for #constraint_name {
+                type Output = #sqrt_output;
+            }
+
+            impl CbrtOutput<#float_type> for #constraint_name {
+                type Output = #cbrt_output;
+            }
+        }
+    });
+
+    let mut hypot_impls = Vec::new();
+    for lhs_type in &config.constraint_types {
+        for rhs_type in &config.constraint_types {
+            if !lhs_type.is_local && !rhs_type.is_local {
+                continue;
+            }
+            for float_type in &lhs_type.float_types {
+                let lhs = find_float_constraint_def(config, &lhs_type.constraint_name, float_type);
+                let rhs = find_float_constraint_def(config, &rhs_type.constraint_name, float_type);
+                let output = op_output(
+                    float_type,
+                    &infer_hypot_output(lhs, rhs, config, float_type),
+                );
+                let (lhs_name, rhs_name) = (&lhs.name, &rhs.name);
+
+                hypot_impls.push(quote! {
+                    impl HypotOutput<#float_type, #rhs_name> for #lhs_name {
+                        type Output = #output;
+                    }
+                });
+            }
+        }
+    }
+
+    quote! {
+        #(#unary_impls)*
+        #(#hypot_impls)*
+    }
+}
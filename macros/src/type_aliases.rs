@@ -19,15 +19,19 @@ use crate::config::TypeConfig;
 /// type PosF64 = PositiveF64;
 /// ```
 pub fn generate_type_aliases(config: &TypeConfig) -> proc_macro2::TokenStream {
-    let aliases = config.type_aliases.iter().flat_map(|alias_def| {
-        let original_name = &alias_def.original_name;
-        let alias_name = &alias_def.alias_name;
+    let aliases = config
+        .type_aliases
+        .iter()
+        .filter(|alias_def| alias_def.is_local)
+        .flat_map(|alias_def| {
+            let original_name = &alias_def.original_name;
+            let alias_name = &alias_def.alias_name;
 
-        vec![
-            generate_single_alias(original_name, alias_name, "f32"),
-            generate_single_alias(original_name, alias_name, "f64"),
-        ]
-    });
+            vec![
+                generate_single_alias(original_name, alias_name, "f32"),
+                generate_single_alias(original_name, alias_name, "f64"),
+            ]
+        });
 
     quote! {
         // Type aliases
@@ -1,25 +1,26 @@
 //! Unary operations module
 //!
-//! Generates type-safe unary operations for all constraint types, including:
+//! Infers the output constraints of unary operations, including:
 //! - `abs()`: Absolute value operation with automatic output type inference
-//! - `signum()`: Sign function that always returns Symmetric type
+//! - `signum()`: Sign function inferred from the sign of the constraint
+//! - `sin()`/`cos()`: Always the Symmetric type
+//! - `recip()`: Sign-preserving and non-zero, fallible near zero
+//! - `sqrt()`/`cbrt()`: The input type when it contains every root
+//! - `hypot()`: `NonNegative`, or `Positive` if either operand excludes zero
 
-use proc_macro2::{Ident, Span, TokenStream as TokenStream2};
-use quote::quote;
+use proc_macro2::{Ident, Span};
 
-use crate::config::{Bounds, ConstraintDef, Sign, TypeConfig};
-use crate::generator::{find_constraint_def, make_type_alias};
+use crate::config::{
+    ArithmeticResult, Bounds, ConstraintDef, FloatLimits, Sign, TypeConfig, tightest_superset,
+};
 
 /// Infers the output type for `abs()` operation based on constraint properties
-fn infer_abs_output_type(constraint_def: &ConstraintDef, config: &TypeConfig) -> Ident {
+pub fn infer_abs_output_type(constraint_def: &ConstraintDef, config: &TypeConfig) -> Ident {
     let bounds = &constraint_def.bounds;
 
     // Special bounded cases → Normalized [0, 1]
     if bounds.is_symmetric() || bounds.is_normalized() || bounds.is_negative_normalized() {
-        let normalized_bounds = Bounds {
-            lower: Some(0.0),
-            upper: Some(1.0),
-        };
+        let normalized_bounds = Bounds::closed(Some(0.0), Some(1.0));
         if let Some(ty) = config.find_type_by_constraints(Sign::Positive, &normalized_bounds, false)
         {
             return ty;
@@ -27,13 +28,17 @@ fn infer_abs_output_type(constraint_def: &ConstraintDef, config: &TypeConfig) ->
     }
 
     // General case: absolute value is always non-negative
-    let abs_bounds = Bounds {
-        lower: Some(0.0),
-        upper: None,
+    infer_non_negative_type(constraint_def.excludes_zero, config)
+}
+
+/// Finds the `[0, +∞)` type, or the `(0, +∞)` type if `excludes_zero`
+pub fn infer_non_negative_type(excludes_zero: bool, config: &TypeConfig) -> Ident {
+    let bounds = Bounds {
+        lower_inclusive: !excludes_zero,
+        ..Bounds::closed(Some(0.0), None)
     };
-    let excludes_zero = constraint_def.excludes_zero;
 
-    if let Some(ty) = config.find_type_by_constraints(Sign::Positive, &abs_bounds, excludes_zero) {
+    if let Some(ty) = config.find_type_by_constraints(Sign::Positive, &bounds, excludes_zero) {
         return ty;
     }
 
@@ -54,14 +59,11 @@ fn infer_abs_output_type(constraint_def: &ConstraintDef, config: &TypeConfig) ->
 /// - Negative + excludes zero → signum = -1 → `NegativeNormalized`
 /// - Any + excludes zero (`NonZero`) → signum in {-1, 1} → Symmetric
 /// - Any + includes zero (Fin, Symmetric) → signum in {-1, 0, 1} → Symmetric
-fn infer_signum_output_type(constraint_def: &ConstraintDef, config: &TypeConfig) -> Ident {
+pub fn infer_signum_output_type(constraint_def: &ConstraintDef, config: &TypeConfig) -> Ident {
     match (constraint_def.sign, constraint_def.excludes_zero) {
         // Positive types: signum ∈ {0, 1} or {1} → Normalized
         (Sign::Positive, _) => {
-            let norm_bounds = Bounds {
-                lower: Some(0.0),
-                upper: Some(1.0),
-            };
+            let norm_bounds = Bounds::closed(Some(0.0), Some(1.0));
             config
                 .find_type_by_constraints(Sign::Positive, &norm_bounds, false)
                 .unwrap_or_else(|| Ident::new("Normalized", Span::call_site()))
@@ -69,10 +71,7 @@ fn infer_signum_output_type(constraint_def: &ConstraintDef, config: &TypeConfig)
 
         // Negative types: signum ∈ {-1, 0} or {-1} → NegativeNormalized
         (Sign::Negative, _) => {
-            let neg_norm_bounds = Bounds {
-                lower: Some(-1.0),
-                upper: Some(0.0),
-            };
+            let neg_norm_bounds = Bounds::closed(Some(-1.0), Some(0.0));
             config
                 .find_type_by_constraints(Sign::Negative, &neg_norm_bounds, false)
                 .unwrap_or_else(|| Ident::new("NegativeNormalized", Span::call_site()))
@@ -80,10 +79,7 @@ fn infer_signum_output_type(constraint_def: &ConstraintDef, config: &TypeConfig)
 
         // Any sign types → Symmetric [-1, 1]
         (Sign::Any, _) => {
-            let sym_bounds = Bounds {
-                lower: Some(-1.0),
-                upper: Some(1.0),
-            };
+            let sym_bounds = Bounds::closed(Some(-1.0), Some(1.0));
             config
                 .find_type_by_constraints(Sign::Any, &sym_bounds, false)
                 .unwrap_or_else(|| Ident::new("Symmetric", Span::call_site()))
@@ -91,358 +87,157 @@ fn infer_signum_output_type(constraint_def: &ConstraintDef, config: &TypeConfig)
     }
 }
 
-/// Generates `abs()` method implementations for all constraint types
+/// Infers the output type for `sin()` and `cos()` operations
 ///
-/// For each type, generates code similar to:
-/// ```text
-/// impl TypeAlias {
-///     #[inline]
-///     #[must_use]
-///     pub fn abs(self) -> OutputAlias {
-///         let result = self.get().abs();
-///         // SAFETY: ...
-///         unsafe { OutputAlias::new_unchecked(result) }
-///     }
-/// }
-/// ```
-pub fn generate_abs_impls(config: &TypeConfig) -> TokenStream2 {
-    let mut impls = Vec::new();
-
-    for type_def in &config.constraint_types {
-        let type_name = &type_def.type_name;
-        let constraint_def = find_constraint_def(config, type_name);
-
-        // Infer abs() output type
-        let output_type = infer_abs_output_type(constraint_def, config);
-
-        for float_type in &type_def.float_types {
-            let type_alias = make_type_alias(type_name, float_type);
-            let output_alias = make_type_alias(&output_type, float_type);
-
-            impls.push(quote! {
-                impl #type_alias {
-                    /// Computes the absolute value.
-                    ///
-                    /// The return type is automatically inferred based on the source constraint:
-                    /// - `NonNegative`/`NonPositive` → `NonNegative`
-                    /// - `NonZero` → `Positive`
-                    /// - `Normalized` → `Normalized` (reflexive)
-                    /// - `Symmetric` → `Normalized`
-                    /// - `Fin` → `NonNegative`
-                    ///
-                    /// # Examples
-                    /// ```
-                    /// use strict_num_extended::*;
-                    ///
-                    /// let neg = NegativeF64::new(-5.0).unwrap();
-                    /// let abs_val: PositiveF64 = neg.abs();
-                    /// assert_eq!(abs_val.get(), 5.0);
-                    /// ```
-                    #[inline]
-                    #[must_use]
-                    pub fn abs(self) -> #output_alias {
-                        let result = self.get().abs();
-                        // SAFETY: The output type for abs() was determined at compile time
-                        // through constraint analysis. For any input value, its absolute value
-                        // is guaranteed to satisfy the output type's constraints. Runtime
-                        // validation would be redundant, so we use unchecked for performance.
-                        unsafe { #output_alias::new_unchecked(result) }
-                    }
-                }
-            });
-        }
-    }
-
-    quote! {
-        #(#impls)*
-    }
+/// For any finite real input x, sin(x) and cos(x) lie in [-1, 1], so all types map to
+/// Symmetric.
+pub fn infer_sin_cos_output_type(config: &TypeConfig) -> Ident {
+    let sym_bounds = Bounds::closed(Some(-1.0), Some(1.0));
+    config
+        .find_type_by_constraints(Sign::Any, &sym_bounds, false)
+        .unwrap_or_else(|| Ident::new("Symmetric", Span::call_site()))
 }
 
-/// Generates `signum()` method implementations for all constraint types
+/// Infers the output type and safety of `recip()` for one float width
 ///
-/// For each type, generates code similar to:
-/// ```text
-/// impl TypeAlias {
-///     #[inline]
-///     #[must_use]
-///     pub fn signum(self) -> OutputAlias {
-///         let result = self.get().signum();
-///         // SAFETY: ...
-///         unsafe { OutputAlias::new_unchecked(result) }
-///     }
-/// }
-/// ```
-pub fn generate_signum_impls(config: &TypeConfig) -> TokenStream2 {
-    let mut impls = Vec::new();
-
-    for type_def in &config.constraint_types {
-        let type_name = &type_def.type_name;
-        let constraint_def = find_constraint_def(config, type_name);
-
-        // Infer signum() output type based on constraint properties
-        let output_type = infer_signum_output_type(constraint_def, config);
-
-        for float_type in &type_def.float_types {
-            let type_alias = make_type_alias(type_name, float_type);
-            let output_alias = make_type_alias(&output_type, float_type);
+/// Rules (like division with a dividend of 1):
+/// - The result keeps the sign of the input and is never zero
+/// - Magnitudes bounded away from zero map to a bounded range, e.g. `[1, ∞)` → `(0, 1]`
+/// - Safe only if the input excludes every value whose reciprocal is infinite: zero
+///   itself, and the subnormals when zero is an exclusive bound (`Positive`, `NonZero`)
+pub fn infer_recip_output(
+    constraint_def: &ConstraintDef,
+    constraints: &[ConstraintDef],
+    float_type: &Ident,
+) -> ArithmeticResult {
+    let limits = FloatLimits::of(float_type);
+    let bounds = &constraint_def.bounds;
 
-            impls.push(quote! {
-                impl #type_alias {
-                    /// Computes the sign function.
-                    ///
-                    /// Returns the sign of the number:
-                    /// - `1.0` if the number is positive
-                    /// - `0.0` if the number is zero
-                    /// - `-1.0` if the number is negative
-                    ///
-                    /// The return type is automatically inferred based on the source constraint:
-                    /// - `Positive` types → `Normalized` (signum in {0, 1})
-                    /// - `Negative` types → `NegativeNormalized` (signum in {-1, 0})
-                    /// - `NonZero` types → `Symmetric` (signum in {-1, 1})
-                    /// - `Fin`/`Symmetric` → `Symmetric` (signum in {-1, 0, 1})
-                    ///
-                    /// # Examples
-                    /// ```
-                    /// use strict_num_extended::*;
-                    ///
-                    /// let pos = PositiveF64::new(5.0).unwrap();
-                    /// let sign: NormalizedF64 = pos.signum();
-                    /// assert_eq!(sign.get(), 1.0);
-                    ///
-                    /// let neg = NegativeF64::new(-5.0).unwrap();
-                    /// let sign: NegativeNormalizedF64 = neg.signum();
-                    /// assert_eq!(sign.get(), -1.0);
-                    /// ```
-                    #[inline]
-                    #[must_use]
-                    pub fn signum(self) -> #output_alias {
-                        let result = self.get().signum();
-                        // SAFETY: The output type for signum() was determined at compile time
-                        // through constraint analysis. For any input value, its signum is
-                        // guaranteed to satisfy the output type's constraints. Runtime
-                        // validation would be redundant, so we use unchecked for performance.
-                        unsafe { #output_alias::new_unchecked(result) }
-                    }
-                }
-            });
+    // Smallest magnitude of the input, `None` when it reaches or crosses zero
+    let min_magnitude = match (bounds.lower, bounds.upper) {
+        (Some(lower), _) if lower > 0.0 => Some(lower),
+        (_, Some(upper)) if upper < 0.0 => Some(-upper),
+        _ => None,
+    };
+    let is_safe = min_magnitude.is_some_and(|m| limits.is_finite(1.0 / m));
+
+    // Rounding may reach the reciprocal of an exclusive bound, so only the zero
+    // approached by unbounded inputs stays exclusive
+    let reciprocal = |bound: Option<f64>| (bound.map_or(0.0, |b| 1.0 / b), bound.is_some());
+    let finite = |value: f64| limits.is_finite(value).then_some(value);
+    let range = match constraint_def.sign {
+        Sign::Positive => {
+            let (lower, lower_inclusive) = reciprocal(bounds.upper);
+            Bounds {
+                lower_inclusive,
+                ..Bounds::closed(Some(lower), min_magnitude.and_then(|m| finite(1.0 / m)))
+            }
         }
-    }
-
-    quote! {
-        #(#impls)*
-    }
-}
-
-/// Generates `sin()` method implementations for all constraint types
-///
-/// # Mathematical Properties
-///
-/// For any finite real input x:
-/// - sin(x) ∈ [-1, 1]
-/// - sin(x) is always finite
-///
-/// Therefore, all types map to Symmetric [-1, 1]
-pub fn generate_sin_impls(config: &TypeConfig) -> TokenStream2 {
-    let mut impls = Vec::new();
-
-    // sin() always returns Symmetric [-1, 1]
-    let sym_bounds = Bounds {
-        lower: Some(-1.0),
-        upper: Some(1.0),
+        Sign::Negative => {
+            let (upper, upper_inclusive) = reciprocal(bounds.lower);
+            Bounds {
+                upper_inclusive,
+                ..Bounds::closed(min_magnitude.and_then(|m| finite(-1.0 / m)), Some(upper))
+            }
+        }
+        Sign::Any => Bounds::UNBOUNDED,
     };
-    let output_type = config
-        .find_type_by_constraints(Sign::Any, &sym_bounds, false)
-        .unwrap_or_else(|| Ident::new("Symmetric", Span::call_site()));
-
-    for type_def in &config.constraint_types {
-        let type_name = &type_def.type_name;
 
-        for float_type in &type_def.float_types {
-            let type_alias = make_type_alias(type_name, float_type);
-            let output_alias = make_type_alias(&output_type, float_type);
+    let output_type = tightest_superset(&range, true, constraints, &[&constraint_def.name])
+        .map_or_else(
+            || Ident::new("NonZero", Span::call_site()),
+            |c| c.name.clone(),
+        );
 
-            impls.push(quote! {
-                #[cfg(feature = "std")]
-                impl #type_alias {
-                    /// Computes the sine of the value.
-                    ///
-                    /// # Mathematical Properties
-                    ///
-                    /// For any finite real input x:
-                    /// - **Range**: sin(x) ∈ [-1, 1]
-                    /// - **Output Type**: Always returns `Symmetric` ([-1, 1])
-                    /// - **Defined**: sin(x) is defined for all finite inputs
-                    ///
-                    /// # Examples
-                    ///
-                    /// ```
-                    /// use strict_num_extended::*;
-                    ///
-                    /// let angle: FinF64 = FinF64::new(std::f64::consts::PI / 2.0).unwrap();
-                    /// let sin_val: SymmetricF64 = angle.sin();
-                    /// assert_eq!(sin_val.get(), 1.0);
-                    ///
-                    /// let zero: NonNegativeF64 = NonNegativeF64::new_const(0.0);
-                    /// let sin_zero: SymmetricF64 = zero.sin();
-                    /// assert_eq!(sin_zero.get(), 0.0);
-                    /// ```
-                    #[inline]
-                    #[must_use]
-                    pub fn sin(self) -> #output_alias {
-                        let result = self.get().sin();
-                        // SAFETY: sin(x) for any finite x always produces a value in [-1, 1],
-                        // which satisfies the Symmetric constraint. The standard library
-                        // guarantees that sin() returns finite values for finite inputs.
-                        unsafe { #output_alias::new_unchecked(result) }
-                    }
-                }
-            });
-        }
+    ArithmeticResult {
+        output_type,
+        is_safe,
     }
+}
 
-    quote! {
-        #(#impls)*
-    }
+/// Picks `constraint_def` itself if it admits every value of `range`, otherwise the
+/// tightest declared type containing `range`
+fn preserve_or_tightest(
+    range: &Bounds,
+    excludes_zero: bool,
+    constraint_def: &ConstraintDef,
+    constraints: &[ConstraintDef],
+) -> Option<Ident> {
+    tightest_superset(
+        range,
+        excludes_zero,
+        core::slice::from_ref(constraint_def),
+        &[],
+    )
+    .or_else(|| tightest_superset(range, excludes_zero, constraints, &[]))
+    .map(|c| c.name.clone())
 }
 
-/// Generates `cos()` method implementations for all constraint types
-///
-/// # Mathematical Properties
+/// Infers the output type and safety of `sqrt()` for one float width
 ///
-/// For any finite real input x:
-/// - cos(x) ∈ [-1, 1]
-/// - cos(x) is always finite
+/// Rules:
+/// - Non-negative types are safe, and keep their type when it contains every root
+///   (`NonNegative`, `Positive`, `Normalized`)
+/// - Types admitting negative values fail on them, and the output is the tightest type
+///   containing the roots of their non-negative part (`Fin` → `NonNegative`,
+///   `Symmetric` → `Normalized`)
 ///
-/// Therefore, all types map to Symmetric [-1, 1]
-pub fn generate_cos_impls(config: &TypeConfig) -> TokenStream2 {
-    let mut impls = Vec::new();
+/// The root of a positive value is positive, but other exclusive bounds may be reached
+/// by rounding, so they become inclusive.
+pub fn infer_sqrt_output(
+    constraint_def: &ConstraintDef,
+    constraints: &[ConstraintDef],
+) -> ArithmeticResult {
+    let bounds = &constraint_def.bounds;
+    let is_safe = bounds.lower.is_some_and(|l| l >= 0.0);
 
-    // cos() always returns Symmetric [-1, 1]
-    let sym_bounds = Bounds {
-        lower: Some(-1.0),
-        upper: Some(1.0),
+    let zero_reachable = bounds.contains(0.0) && !constraint_def.excludes_zero;
+    let (lower, lower_inclusive) = match bounds.lower {
+        Some(l) if l > 0.0 => (l.sqrt(), true),
+        _ => (0.0, zero_reachable),
+    };
+    let range = Bounds {
+        lower_inclusive,
+        ..Bounds::closed(Some(lower), bounds.upper.map(|u| u.max(0.0).sqrt()))
     };
-    let output_type = config
-        .find_type_by_constraints(Sign::Any, &sym_bounds, false)
-        .unwrap_or_else(|| Ident::new("Symmetric", Span::call_site()));
-
-    for type_def in &config.constraint_types {
-        let type_name = &type_def.type_name;
-
-        for float_type in &type_def.float_types {
-            let type_alias = make_type_alias(type_name, float_type);
-            let output_alias = make_type_alias(&output_type, float_type);
 
-            impls.push(quote! {
-                #[cfg(feature = "std")]
-                impl #type_alias {
-                    /// Computes the cosine of the value.
-                    ///
-                    /// # Mathematical Properties
-                    ///
-                    /// For any finite real input x:
-                    /// - **Range**: cos(x) ∈ [-1, 1]
-                    /// - **Output Type**: Always returns `Symmetric` ([-1, 1])
-                    /// - **Defined**: cos(x) is defined for all finite inputs
-                    ///
-                    /// # Examples
-                    ///
-                    /// ```
-                    /// use strict_num_extended::*;
-                    ///
-                    /// let angle: FinF64 = FinF64::new(0.0).unwrap();
-                    /// let cos_val: SymmetricF64 = angle.cos();
-                    /// assert_eq!(cos_val.get(), 1.0);
-                    ///
-                    /// let pi: FinF64 = FinF64::new(std::f64::consts::PI).unwrap();
-                    /// let cos_pi: SymmetricF64 = pi.cos();
-                    /// assert!((cos_pi.get() - (-1.0)).abs() < f64::EPSILON);
-                    /// ```
-                    #[inline]
-                    #[must_use]
-                    pub fn cos(self) -> #output_alias {
-                        let result = self.get().cos();
-                        // SAFETY: cos(x) for any finite x always produces a value in [-1, 1],
-                        // which satisfies the Symmetric constraint. The standard library
-                        // guarantees that cos() returns finite values for finite inputs.
-                        unsafe { #output_alias::new_unchecked(result) }
-                    }
-                }
-            });
-        }
+    let output_type = if is_safe {
+        preserve_or_tightest(&range, false, constraint_def, constraints)
+    } else {
+        tightest_superset(&range, false, constraints, &[]).map(|c| c.name.clone())
     }
+    .unwrap_or_else(|| Ident::new("NonNegative", Span::call_site()));
 
-    quote! {
-        #(#impls)*
+    ArithmeticResult {
+        output_type,
+        is_safe,
     }
 }
 
-/// Generates `tan()` method implementations for all constraint types
-///
-/// # Mathematical Properties
-///
-/// For any finite real input x (excluding π/2 + kπ):
-/// - tan(x) ∈ (-∞, +∞)
-/// - tan(x) is finite except at singular points
+/// Infers the output type of `cbrt()` for one float width
 ///
-/// Therefore, all types map to Fin (unbounded), returning Result to handle errors
-pub fn generate_tan_impls(config: &TypeConfig) -> TokenStream2 {
-    let mut impls = Vec::new();
-
-    for type_def in &config.constraint_types {
-        let type_name = &type_def.type_name;
-
-        // tan() always returns Fin (may be infinite at singular points)
-        let output_type = Ident::new("Fin", Span::call_site());
-
-        for float_type in &type_def.float_types {
-            let type_alias = make_type_alias(type_name, float_type);
-            let output_alias = make_type_alias(&output_type, float_type);
-
-            impls.push(quote! {
-                #[cfg(feature = "std")]
-                impl #type_alias {
-                    /// Computes the tangent of the value.
-                    ///
-                    /// # Mathematical Properties
-                    ///
-                    /// For any finite real input x (excluding π/2 + kπ):
-                    /// - **Range**: tan(x) ∈ (-∞, +∞)
-                    /// - **Output Type**: Always returns `Fin` (unbounded)
-                    /// - **Singular Points**: Undefined at π/2 + kπ (may return infinity)
-                    ///
-                    /// # Examples
-                    ///
-                    /// ```
-                    /// use strict_num_extended::*;
-                    ///
-                    /// let angle: FinF64 = FinF64::new(0.0).unwrap();
-                    /// let tan_val: Result<FinF64, FloatError> = angle.tan();
-                    /// assert_eq!(tan_val.unwrap().get(), 0.0);
-                    ///
-                    /// let pi_over_4: FinF64 = FinF64::new(std::f64::consts::PI / 4.0).unwrap();
-                    /// let tan_45: Result<FinF64, FloatError> = pi_over_4.tan();
-                    /// assert!((tan_45.unwrap().get() - 1.0).abs() < f64::EPSILON);
-                    /// ```
-                    #[inline]
-                    #[must_use]
-                    pub fn tan(self) -> Result<#output_alias, FloatError> {
-                        let result = self.get().tan();
-                        // tan() may produce ±∞ at singular points (π/2 + kπ),
-                        // which should return NaN error (not PosInf/NegInf)
-                        if !result.is_finite() {
-                            return Err(FloatError::NaN);
-                        }
-                        // SAFETY: When tan() produces a finite value, it satisfies the Fin constraint.
-                        // The is_finite() check above ensures we only reach this point for valid values.
-                        unsafe { Ok(#output_alias::new_unchecked(result)) }
-                    }
-                }
-            });
-        }
-    }
+/// The cube root is finite, keeps the sign and excludes zero whenever its input does,
+/// so the output is the input type when it contains every root (`Fin`, `NonZero`,
+/// `Positive`, `Symmetric`, ...), and the tightest type containing them otherwise.
+pub fn infer_cbrt_output(constraint_def: &ConstraintDef, constraints: &[ConstraintDef]) -> Ident {
+    let bounds = &constraint_def.bounds;
+    // An exclusive zero bound stays exclusive, other exclusive bounds may be reached
+    let side =
+        |bound: Option<f64>, inclusive: bool| bound.map(|b| (b.cbrt(), inclusive || b != 0.0));
+    let (lower, lower_inclusive) = side(bounds.lower, bounds.lower_inclusive).unzip();
+    let (upper, upper_inclusive) = side(bounds.upper, bounds.upper_inclusive).unzip();
+    let range = Bounds {
+        lower,
+        upper,
+        lower_inclusive: lower_inclusive.unwrap_or(true),
+        upper_inclusive: upper_inclusive.unwrap_or(true),
+    };
 
-    quote! {
-        #(#impls)*
-    }
+    preserve_or_tightest(
+        &range,
+        constraint_def.excludes_zero,
+        constraint_def,
+        constraints,
+    )
+    .unwrap_or_else(|| Ident::new("Fin", Span::call_site()))
 }
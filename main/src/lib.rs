@@ -12,6 +12,7 @@
 //! - `NegativeNormalizedF32` and `NegativeNormalizedF64`: Negative normalized floating-point numbers (-1.0 <= value <= 0.0, finite)
 //! - `SymmetricF32` and `SymmetricF64`: Symmetric floating-point numbers (-1.0 <= value <= 1.0, finite)
 //! - `PiBoundedF32` and `PiBoundedF64`: PI-bounded floating-point numbers (-PI <= value <= PI, finite)
+//! - `TauBoundedF32` and `TauBoundedF64`: Canonical angles (0.0 <= value < TAU, finite)
 //!
 //! ## Feature Flags
 //!
@@ -34,6 +35,17 @@
 //! **Note**: This example requires the `std` feature (for `serde_json`).
 //! For `no_std` environments with `serde`, use alternative serialization formats.
 //!
+//! ### `interval-inference` (optional)
+//!
+//! By default, `+`, `-`, `*` and `/` infer their output from the signs of the operands
+//! and are only safe in a few proven cases. With `interval-inference`, the
+//! [`add_bounded`](Strict::add_bounded), [`sub_bounded`](Strict::sub_bounded),
+//! [`mul_bounded`](Strict::mul_bounded) and [`div_bounded`](Strict::div_bounded)
+//! methods are also available for operands whose results lie in a finite interval.
+//! They never fail, and return the tightest operand type or type unbounded on one side
+//! containing that interval, such as `NonNegativeF64` for the sum of two
+//! `NormalizedF64` values. The operators are unchanged.
+//!
 //! ## Type Safety
 //!
 //! This library provides type safety through both compile-time and runtime guarantees:
@@ -132,6 +144,41 @@
 //!
 //! Additionally, `Option` versions are provided for handling potentially failing operations.
 //!
+//! ## Generic Code
+//!
+//! Every type is an alias of [`Strict<F, C>`](Strict), where `F` is `f32` or `f64` and
+//! `C` is a zero-sized [`Constraint`] marker. Functions can be written once for all
+//! constraints and widths:
+//!
+//! ```
+//! use strict_num_extended::*;
+//!
+//! fn halve<F: Float, C: Constraint>(x: Strict<F, C>) -> Result<Strict<F, C>, FloatError> {
+//!     Strict::new(x.get() * F::from_f64(0.5))
+//! }
+//!
+//! assert_eq!(halve(PositiveF64::new(3.0).unwrap()).unwrap().get(), 1.5);
+//! assert_eq!(halve(NegativeF32::new(-1.0).unwrap()).unwrap().get(), -0.5);
+//! ```
+//!
+//! ## Custom Constraints
+//!
+//! Crates can define their own constraint types with [`define_constraints!`], using the
+//! same condition syntax. The generated types share [`FloatError`] and [`FiniteFloat`]
+//! with the built-in ones and convert to and from them with `From` (or `convert()`) for
+//! subsets and `try_convert()` otherwise:
+//!
+//! ```
+//! use strict_num_extended::*;
+//!
+//! define_constraints!([(Kelvin, [">= 0.0"])]);
+//!
+//! let kelvin = KelvinF64::new(273.15).unwrap();
+//! let non_negative: NonNegativeF64 = kelvin.into();
+//! assert_eq!(non_negative.get(), 273.15);
+//! assert!(FinF64::new(-1.0).unwrap().try_convert::<Kelvin>().is_err());
+//! ```
+//!
 //! # Examples
 //!
 //! ## Quick Overview
@@ -285,6 +332,21 @@
 //! assert_eq!(back_to_f32.unwrap().get(), original_f32.get());
 //! ```
 //!
+//! Bounds are rounded separately for each width, so `PiBoundedF32` accepts
+//! `core::f32::consts::PI`, which lies slightly above the f64 `PI`. Types whose f32
+//! bounds round outward like this provide `try_into_f64_type()` instead:
+//!
+//! ```
+//! use strict_num_extended::*;
+//!
+//! let pi = PiBoundedF32::new(core::f32::consts::PI).unwrap();
+//! assert!(pi.try_into_f64_type().is_err());
+//!
+//! let half = PiBoundedF32::new(0.5).unwrap();
+//! let widened: PiBoundedF64 = half.try_into_f64_type().unwrap();
+//! assert_eq!(widened.get(), 0.5);
+//! ```
+//!
 //! These methods support const contexts for creating values:
 //!
 //! ```
@@ -372,9 +434,33 @@
 //! - `Positive ÷ Positive → Positive` (fallible, division by zero detection)
 //! - `Negative ÷ Negative → Positive` (fallible, division by zero detection)
 //! - `Positive ÷ Negative → Negative` (fallible, division by zero detection)
-//! - `Bounded (in [-1,1]) ÷ NonZero → Bounded` (safe, when dividend is within [-1,1])
+//! - `Bounded (in [-1,1]) ÷ Bounded away from zero → Bounded` (safe, when dividend is within [-1,1])
+//! - `Normalized ÷ Positive → NonNegative` (fallible, a subnormal divisor overflows)
 //! - `NonZero ÷ NonZero → NonZero` (fallible, division by zero detection)
 //!
+//! **Remainder Rules**:
+//!
+//! The remainder has the sign of the dividend and is bounded by both operands. It never
+//! overflows, so it is safe whenever the divisor excludes zero:
+//!
+//! - `Positive % Positive → NonNegative` (safe)
+//! - `Fin % NonZero → Fin` (safe)
+//! - `Normalized % Positive → Normalized` (safe, bounded by the dividend)
+//! - `Positive % Normalized → Normalized` (fallible, bounded by the divisor, which may be zero)
+//!
+//! `rem_euclid()` always returns a non-negative type, and `div_euclid()` follows the
+//! division rules. Both come from the [`RemEuclid`] and [`DivEuclid`] traits:
+//!
+//! ```
+//! use strict_num_extended::*;
+//!
+//! const TURN: PositiveF64 = PositiveF64::new_const(360.0);
+//! let heading = FinF64::new(-450.0).unwrap();
+//! let wrapped: NonNegativeF64 = heading.rem_euclid(TURN);
+//! assert_eq!(wrapped.get(), 270.0);
+//! assert_eq!(heading.div_euclid(TURN).unwrap().get(), -2.0);
+//! ```
+//!
 //! #### Examples
 //!
 //! ```
@@ -406,7 +492,7 @@
 //! assert_eq!(result.unwrap().get(), 7.0);
 //!
 //! // Error propagation when Result is Err
-//! let invalid: Result<PositiveF64, FloatError> = Err(FloatError::OutOfRange);
+//! let invalid: Result<PositiveF64, FloatError> = Err(FloatError::BelowRange);
 //! let error_result: Result<FinF64, FloatError> = invalid + B;  // Error propagates
 //! assert!(error_result.is_err());
 //! ```
@@ -417,10 +503,206 @@
 //!
 //! - **Range Constraints**: Result values must satisfy the target type's bounds
 //! - **Overflow Detection**: Operations that may exceed representable ranges return errors
-//! - **NaN/Infinity**: Operations producing NaN or infinity return `FloatError::NaN`
+//! - **Failure Kinds**: A zero divisor returns `FloatError::DivisionByZero`, an infinite
+//!   result `FloatError::Overflow`, a result rounded to an excluded zero
+//!   `FloatError::Underflow`, and a NaN or infinite primitive operand
+//!   `FloatError::InvalidOperand`
 //!
 //! This ensures mathematical correctness while maintaining ergonomic API design through automatic type inference.
 //!
+//! ### Compound Assignment
+//!
+//! `+=`, `-=`, `*=` and `/=` are available when the operation is safe and its result type
+//! is the left-hand type. Every other operation has a `try_*_assign` method, which
+//! validates the result against the left-hand constraint and leaves the value
+//! unchanged on error:
+//!
+//! ```
+//! use strict_num_extended::*;
+//!
+//! let mut volume = NormalizedF64::new(0.8).unwrap();
+//! volume *= NormalizedF64::new(0.5).unwrap();  // Normalized * Normalized is Normalized
+//! assert_eq!(volume.get(), 0.4);
+//!
+//! let mut balance = PositiveF64::new(10.0).unwrap();
+//! assert!(balance.try_sub_assign(PositiveF64::new(4.0).unwrap()).is_ok());
+//! assert!(balance.try_sub_assign(PositiveF64::new(7.0).unwrap()).is_err());
+//! assert_eq!(balance.get(), 6.0);
+//! ```
+//!
+//! ### Operands by Reference
+//!
+//! Every operator also accepts either operand by reference, with the same output type,
+//! so values can be combined straight from slices and in generic code:
+//!
+//! ```
+//! use strict_num_extended::*;
+//!
+//! let weights = [NormalizedF64::new(0.5).unwrap(), NormalizedF64::new(0.8).unwrap()];
+//! let product: NormalizedF64 = &weights[0] * &weights[1];
+//! assert_eq!(product.get(), 0.4);
+//!
+//! let total = weights.iter().fold(NormalizedF64::new(1.0).unwrap(), |acc, w| acc * w);
+//! assert_eq!(total.get(), 0.4);
+//! ```
+//!
+//! ### Mixed-Width Arithmetic
+//!
+//! F32 and F64 types can be combined in either order. The F32 operand is widened
+//! losslessly, as by `as_f64_type()`, so it must be a `LosslessWidening` type, and the
+//! output is the F64 version of the inferred type:
+//!
+//! ```
+//! use strict_num_extended::*;
+//!
+//! let reading = NormalizedF32::new(0.5).unwrap();
+//! let gain = NormalizedF64::new(0.25).unwrap();
+//!
+//! let scaled: NormalizedF64 = reading * gain;
+//! assert_eq!(scaled.get(), 0.125);
+//!
+//! let offset: Result<FinF64, FloatError> = FinF64::new(1.0).unwrap() - &reading;
+//! assert_eq!(offset.unwrap().get(), 0.5);
+//! ```
+//!
+//! ### Integer Operands
+//!
+//! Integer primitives and their `NonZero` counterparts can be used on either side of
+//! an operator. Unsigned integers act as `NonNegative` values, signed ones as `Fin`,
+//! `NonZeroU32` and the like as `Positive`, and `NonZeroI32` and the like as `NonZero`:
+//!
+//! ```
+//! use core::num::NonZeroU32;
+//! use strict_num_extended::*;
+//!
+//! let total = PositiveF64::new(10.0).unwrap();
+//! let mean: Result<PositiveF64, FloatError> = total / NonZeroU32::new(4).unwrap();
+//! assert_eq!(mean.unwrap().get(), 2.5);
+//!
+//! // Integers of up to 32 bits convert exactly to f64, so safe operations stay safe
+//! let level = NonNegativeF64::new(0.25).unwrap();
+//! let difference: FinF64 = level - 3_u32;
+//! assert_eq!(difference.get(), -2.75);
+//! ```
+//!
+//! `i64`, `u64`, `isize` and `usize`, and integers wider than 16 bits for F32 types,
+//! may not convert exactly, so their operations return a `Result` that reports
+//! `FloatError::PrecisionLoss` for an inexact operand:
+//!
+//! ```
+//! use strict_num_extended::*;
+//!
+//! let one = FinF64::new(1.0).unwrap();
+//! assert_eq!((one * (1_u64 << 53)).unwrap().get(), 9_007_199_254_740_992.0);
+//! assert_eq!(one * ((1_u64 << 53) + 1), Err(FloatError::PrecisionLoss));
+//! ```
+//!
+//! ### Saturating Arithmetic
+//!
+//! `saturating_add`, `saturating_sub`, `saturating_mul` and `saturating_div` return the
+//! same type as the operator but never fail: results are clamped to the bounds of that
+//! type, and a result that underflows to zero in a non-zero type becomes the smallest
+//! normal value of the right sign:
+//!
+//! ```
+//! use strict_num_extended::*;
+//!
+//! let max = PositiveF64::new(f64::MAX).unwrap();
+//! assert_eq!(max.saturating_add(max).get(), f64::MAX);
+//!
+//! let tiny = PositiveF64::new(f64::MIN_POSITIVE).unwrap();
+//! assert_eq!(tiny.saturating_mul(tiny).get(), f64::MIN_POSITIVE);
+//!
+//! let one = FinF64::new(1.0).unwrap();
+//! assert_eq!(one.saturating_div(FinF64::new(0.0).unwrap()).get(), f64::MAX);
+//! ```
+//!
+//! ### Fused Multiply-Add
+//!
+//! `a.mul_add(b, c)` computes `a * b + c` with a single rounding, like `f64::mul_add`
+//! (and `libm` without `std`). Its output type composes the inference of `*` with that
+//! of adding `c` to the product, and it is validated at most once: bounded operands
+//! whose every result is finite give the value directly.
+//!
+//! ```
+//! use strict_num_extended::*;
+//!
+//! let weight = NormalizedF64::new(0.25).unwrap();
+//! let sample = NormalizedF64::new(0.5).unwrap();
+//! let bias = NormalizedF64::new(0.5).unwrap();
+//!
+//! let out: NonNegativeF64 = weight.mul_add(sample, bias);
+//! assert_eq!(out.get(), 0.625);
+//!
+//! // Unbounded operands may overflow, so the result is validated
+//! let gain = PositiveF64::new(4.0).unwrap();
+//! let boosted: Result<PositiveF64, FloatError> = gain.mul_add(gain, gain);
+//! assert_eq!(boosted.unwrap().get(), 20.0);
+//! ```
+//!
+//! ### Reciprocal
+//!
+//! `recip()` keeps the sign of the value and excludes zero from its output, so
+//! `NonZero` stays `NonZero` and `Normalized` gives `Positive`. It returns a `Result`
+//! unless the magnitude is bounded away from zero: `1 / 0` fails with
+//! `DivisionByZero`, and subnormal values overflow.
+//!
+//! ```
+//! use strict_num_extended::*;
+//!
+//! let rate: Result<PositiveF64, FloatError> = PositiveF64::new(4.0).unwrap().recip();
+//! assert_eq!(rate.unwrap().get(), 0.25);
+//!
+//! let zero = FinF64::new(0.0).unwrap();
+//! assert_eq!(zero.recip(), Err(FloatError::DivisionByZero));
+//! ```
+//!
+//! ### Roots
+//!
+//! `sqrt()` is infallible for non-negative types and keeps their type, while types
+//! admitting negative values return a `Result`. `cbrt()` never fails and keeps the
+//! sign and bounds of the value. `a.hypot(b)` is `Positive` if either operand excludes
+//! zero, and needs no validation when both operands are bounded:
+//!
+//! ```
+//! use strict_num_extended::*;
+//!
+//! let side: PositiveF64 = PositiveF64::new(16.0).unwrap().sqrt();
+//! assert_eq!(side.get(), 4.0);
+//!
+//! let root: Result<NonNegativeF64, FloatError> = FinF64::new(-1.0).unwrap().sqrt();
+//! assert!(root.is_err());
+//!
+//! let edge: NegativeF64 = NegativeF64::new(-8.0).unwrap().cbrt();
+//! assert_eq!(edge.get(), -2.0);
+//!
+//! let length: Result<PositiveF64, FloatError> = side.hypot(FinF64::new(3.0).unwrap());
+//! assert_eq!(length.unwrap().get(), 5.0);
+//! ```
+//!
+//! ### Sums and Products
+//!
+//! Iterators of a type closed under `+` (such as `Positive`, `NonNegative` or `Fin`) can
+//! be summed into a `Result` of that type, and those closed under `*` multiplied the
+//! same way. The first error stops the computation. Types whose products are safe
+//! and admit `1`, such as `Normalized` and `Symmetric`, also multiply infallibly, and
+//! [`SumCompensated::sum_compensated`] adds with Neumaier's compensated summation:
+//!
+//! ```
+//! use strict_num_extended::*;
+//!
+//! let lengths = [1.5, 2.0, 0.5].map(|l| PositiveF64::new(l).unwrap());
+//! let total: Result<PositiveF64, FloatError> = lengths.iter().sum();
+//! assert_eq!(total.unwrap().get(), 4.0);
+//!
+//! let weights = [0.5, 0.5].map(|w| NormalizedF64::new(w).unwrap());
+//! let combined: NormalizedF64 = weights.iter().product();
+//! assert_eq!(combined.get(), 0.25);
+//!
+//! let max = PositiveF64::new(f64::MAX).unwrap();
+//! assert_eq!([max, max].into_iter().sum_compensated(), Err(FloatError::Overflow));
+//! ```
+//!
 //! ## Comparison Operations
 //!
 //! All types support full ordering operations:
@@ -438,6 +720,55 @@
 //! assert_ne!(A, B);
 //! ```
 //!
+//! ### Min, Max and Clamp
+//!
+//! `max` and `min` accept any other type of the same width, and return the tightest
+//! type containing every possible result. `clamp(lo, hi)` composes them, and
+//! `clamp_to::<D>()` clamps to the bounds of constraint `D`. None of them can fail:
+//!
+//! ```
+//! use strict_num_extended::*;
+//!
+//! let value = FinF64::new(-3.0).unwrap();
+//!
+//! let floored: PositiveF64 = value.max(PositiveF64::new(0.5).unwrap());
+//! assert_eq!(floored.get(), 0.5);
+//!
+//! let capped: NegativeF64 = NegativeF64::new(-1.0).unwrap().min(value);
+//! assert_eq!(capped.get(), -3.0);
+//!
+//! let lo = NormalizedF64::new(0.25).unwrap();
+//! let hi = NormalizedF64::new(0.75).unwrap();
+//! let clamped: NormalizedF64 = value.clamp(lo, hi);
+//! assert_eq!(clamped.get(), 0.25);
+//!
+//! assert_eq!(FinF64::new(1.5).unwrap().clamp_to::<Normalized>().get(), 1.0);
+//! ```
+//!
+//! ## Interpolation and Easing
+//!
+//! `a.lerp(b, t)` takes its parameter as a `Normalized` value and returns the tightest
+//! type containing both endpoints, so interpolating between two values of the same
+//! bounded type cannot fail. `inverse_lerp` recovers the parameter, and the [`easing`]
+//! curves map `Normalized` values onto themselves:
+//!
+//! ```
+//! use strict_num_extended::*;
+//!
+//! let t = NormalizedF64::new(0.5).unwrap();
+//!
+//! let faded: NormalizedF64 = NormalizedF64::new(0.2).unwrap().lerp(NormalizedF64::new(0.6).unwrap(), t);
+//! assert!((faded.get() - 0.4).abs() < 1e-12);
+//!
+//! let start = FinF64::new(0.0).unwrap();
+//! let end = FinF64::new(8.0).unwrap();
+//! let back: NormalizedF64 = start.inverse_lerp(end, FinF64::new(2.0).unwrap()).unwrap();
+//! assert_eq!(back.get(), 0.25);
+//!
+//! let eased: NormalizedF64 = easing::smoothstep(t);
+//! assert_eq!(eased.get(), 0.5);
+//! ```
+//!
 //! ## Result Type Arithmetic
 //!
 //! Arithmetic operations between `Result<T, FloatError>` and concrete types are supported
@@ -484,7 +815,7 @@
 //! - If LHS is `Err`, the error propagates directly
 //! - If RHS is `Err`, the error propagates directly
 //! - If both are `Ok`, the operation proceeds with normal validation
-//! - Division by zero returns `Err(FloatError::NaN)`
+//! - Division by zero returns `Err(FloatError::DivisionByZero)`
 //!
 //! ```
 //! use strict_num_extended::*;
@@ -494,7 +825,7 @@
 //! const ZERO: NonNegativeF64 = NonNegativeF64::new_const(0.0);
 //! let result: Result<NonNegativeF64, FloatError> = a / ZERO;
 //! assert!(result.is_err());
-//! assert_eq!(result.unwrap_err(), FloatError::NaN);
+//! assert_eq!(result.unwrap_err(), FloatError::DivisionByZero);
 //! ```
 //!
 //! ## Option Type Arithmetic
@@ -561,6 +892,27 @@
 //! assert_eq!(result.unwrap().get(), 24.0);
 //! ```
 //!
+//! ## Chained Arithmetic with `Checked`
+//!
+//! The orphan rule rules out `Result op Result` and `-Result`, so long formulas over
+//! fallible values would need a `?` after every step. [`Checked`] carries either a
+//! value or the first error, supports every operator against `Checked`, concrete
+//! values and primitives, as well as negation and unary math, and is resolved once
+//! with `into_result()`:
+//!
+//! ```
+//! use strict_num_extended::*;
+//!
+//! let x = Checked::new(PositiveF64::new(2.0).unwrap());
+//! let y = Checked::new(NegativeF64::new(-3.0).unwrap());
+//!
+//! let result: Result<PositiveF64, FloatError> = (-(x * y) / x).abs().into_result();
+//! assert_eq!(result.unwrap().get(), 3.0);
+//!
+//! let failed = -(x / 0.0) + 1.0;
+//! assert_eq!(failed.error(), Some(FloatError::DivisionByZero));
+//! ```
+//!
 //! ## Result & Option Arithmetic Overview
 //!
 //! The library provides comprehensive support for arithmetic operations with `Result<T, FloatError>`
@@ -573,7 +925,7 @@
 //! - Operations between `Result<T>` and concrete types automatically propagate errors
 //! - If either operand is `Err`, the error is forwarded directly
 //! - When both operands are `Ok`, the operation proceeds with normal validation
-//! - Division by zero returns `FloatError::NaN`
+//! - Division by zero returns `FloatError::DivisionByZero`
 //!
 //! This eliminates verbose error handling boilerplate in calculations that may fail.
 //!
@@ -600,8 +952,46 @@
 //! - `FloatError::NaN` - Value is NaN (Not a Number)
 //! - `FloatError::PosInf` - Value is positive infinity
 //! - `FloatError::NegInf` - Value is negative infinity
-//! - `FloatError::OutOfRange` - Value is outside the valid range for the target type
+//! - `FloatError::BelowRange` - Value is below the lower bound of the target type
+//! - `FloatError::AboveRange` - Value is above the upper bound of the target type
+//! - `FloatError::ExcludedZero` - Value is zero, which the target type excludes
 //! - `FloatError::NoneOperand` - Right-hand side operand is None in Option arithmetic
+//! - `FloatError::DivisionByZero` - Division, remainder or Euclidean operation by zero
+//! - `FloatError::Overflow` - Arithmetic result too large in magnitude to be finite
+//! - `FloatError::Underflow` - Arithmetic result rounded to a zero the output type excludes
+//! - `FloatError::InvalidOperand` - Primitive operand is NaN or infinite
+//! - `FloatError::PrecisionLoss` - Integer operand not exactly representable in the float type
+//!
+//! Values are only reported as `NaN`, `PosInf` or `NegInf` when they are validated
+//! directly, e.g. by [`Strict::new`]. [`FloatError::is_out_of_range`] matches the three
+//! kinds of bound violations.
+//!
+//! ### Error Context
+//!
+//! A [`DetailedError`] pairs a `FloatError` with the rejected value, the target type,
+//! the violated bound and, for arithmetic, the operator and its operands. It is
+//! returned by [`Strict::new_detailed`] and kept by [`Checked`] computations, so the
+//! failing step of a long formula can be reported:
+//!
+//! ```
+//! use strict_num_extended::*;
+//!
+//! let err = PositiveF64::new_detailed(-2.0).unwrap_err();
+//! assert_eq!(err.kind(), FloatError::BelowRange);
+//! assert_eq!(err.value(), Some(-2.0));
+//! assert_eq!(err.bound(), Some(ViolatedBound::Lower { bound: 0.0, inclusive: false }));
+//! assert_eq!(err.to_string(), "PositiveF64: -2.0 violates > 0");
+//!
+//! let a = PositiveF64::new(3.0)?;
+//! let b = NegativeF64::new(-5.0)?;
+//! let result = (Checked::new(a) * b).mul_add(a, b) / FinF64::new(0.0)?;
+//! assert_eq!(result.error(), Some(FloatError::DivisionByZero));
+//! assert_eq!(
+//!     result.detailed_error().unwrap().to_string(),
+//!     "NonZeroF64: -50.0 / 0.0 divides by zero"
+//! );
+//! # Ok::<(), FloatError>(())
+//! ```
 //!
 //! ## Example: Error Handling
 //!
@@ -625,13 +1015,13 @@
 //! // Out of range error
 //! let out_of_range: Result<NormalizedF32, FloatError> = NormalizedF32::new(2.0);
 //! assert!(out_of_range.is_err());
-//! assert_eq!(out_of_range.unwrap_err(), FloatError::OutOfRange);
+//! assert_eq!(out_of_range.unwrap_err(), FloatError::AboveRange);
 //!
 //! // Division by zero is prevented at creation time
 //! let a = PositiveF64::new(10.0).unwrap();
 //! let zero_result = PositiveF64::new(0.0);
 //! assert!(zero_result.is_err());  // Cannot create zero value
-//! assert_eq!(zero_result.unwrap_err(), FloatError::OutOfRange);
+//! assert_eq!(zero_result.unwrap_err(), FloatError::BelowRange);
 //! ```
 //!
 //! ## Practical Example: Safe Division Function
@@ -734,7 +1124,7 @@
 //!
 //! let valid = NonNegativeF32::new(0.0);      // Ok(value)
 //! let valid = NonNegativeF32::new(1.5);      // Ok(value)
-//! let invalid = NonNegativeF32::new(-1.0);   // Err(FloatError::OutOfRange) (negative)
+//! let invalid = NonNegativeF32::new(-1.0);   // Err(FloatError::BelowRange) (negative)
 //! let invalid = NonNegativeF32::new(f32::INFINITY); // Err(FloatError::PosInf) (infinite)
 //! ```
 //!
@@ -747,7 +1137,7 @@
 //!
 //! let valid = NonPositiveF32::new(0.0);      // Ok(value)
 //! let valid = NonPositiveF32::new(-1.5);     // Ok(value)
-//! let invalid = NonPositiveF32::new(1.0);    // Err(FloatError::OutOfRange) (positive)
+//! let invalid = NonPositiveF32::new(1.0);    // Err(FloatError::AboveRange) (positive)
 //! let invalid = NonPositiveF32::new(f32::NEG_INFINITY); // Err(FloatError::NegInf) (infinite)
 //! ```
 //!
@@ -760,8 +1150,8 @@
 //!
 //! let valid = NonZeroF32::new(1.0);       // Ok(value)
 //! let valid = NonZeroF32::new(-1.0);      // Ok(value)
-//! let invalid = NonZeroF32::new(0.0);     // Err(FloatError::OutOfRange) (zero)
-//! let invalid = NonZeroF32::new(-0.0);    // Err(FloatError::OutOfRange) (negative zero)
+//! let invalid = NonZeroF32::new(0.0);     // Err(FloatError::ExcludedZero) (zero)
+//! let invalid = NonZeroF32::new(-0.0);    // Err(FloatError::ExcludedZero) (negative zero)
 //! ```
 //!
 //! ## Positive Constraint
@@ -773,8 +1163,8 @@
 //!
 //! let valid = PositiveF32::new(1.0);       // Ok(value)
 //! let valid = PositiveF32::new(0.001);     // Ok(value)
-//! let invalid = PositiveF32::new(0.0);     // Err(FloatError::OutOfRange) (zero)
-//! let invalid = PositiveF32::new(-1.0);    // Err(FloatError::OutOfRange) (negative)
+//! let invalid = PositiveF32::new(0.0);     // Err(FloatError::BelowRange) (zero)
+//! let invalid = PositiveF32::new(-1.0);    // Err(FloatError::BelowRange) (negative)
 //! let invalid = PositiveF32::new(f32::INFINITY); // Err(FloatError::PosInf) (infinite)
 //! ```
 //!
@@ -787,8 +1177,8 @@
 //!
 //! let valid = NegativeF32::new(-1.0);      // Ok(value)
 //! let valid = NegativeF32::new(-0.001);    // Ok(value)
-//! let invalid = NegativeF32::new(0.0);     // Err(FloatError::OutOfRange) (zero)
-//! let invalid = NegativeF32::new(1.0);     // Err(FloatError::OutOfRange) (positive)
+//! let invalid = NegativeF32::new(0.0);     // Err(FloatError::AboveRange) (zero)
+//! let invalid = NegativeF32::new(1.0);     // Err(FloatError::AboveRange) (positive)
 //! let invalid = NegativeF32::new(f32::NEG_INFINITY); // Err(FloatError::NegInf) (infinite)
 //! ```
 //!
@@ -802,8 +1192,8 @@
 //! let valid = NormalizedF32::new(0.75);   // Ok(value)
 //! let valid = NormalizedF32::new(0.0);    // Ok(value)
 //! let valid = NormalizedF32::new(1.0);    // Ok(value)
-//! let invalid = NormalizedF32::new(1.5);  // Err(FloatError::OutOfRange) (> 1.0)
-//! let invalid = NormalizedF32::new(-0.5); // Err(FloatError::OutOfRange) (< 0.0)
+//! let invalid = NormalizedF32::new(1.5);  // Err(FloatError::AboveRange) (> 1.0)
+//! let invalid = NormalizedF32::new(-0.5); // Err(FloatError::BelowRange) (< 0.0)
 //! ```
 //!
 //! ## `NegativeNormalized` Constraint
@@ -816,8 +1206,8 @@
 //! let valid = NegativeNormalizedF32::new(-0.75);  // Ok(value)
 //! let valid = NegativeNormalizedF32::new(-1.0);   // Ok(value)
 //! let valid = NegativeNormalizedF32::new(0.0);    // Ok(value)
-//! let invalid = NegativeNormalizedF32::new(1.5);  // Err(FloatError::OutOfRange) (> 0.0)
-//! let invalid = NegativeNormalizedF32::new(-1.5); // Err(FloatError::OutOfRange) (< -1.0)
+//! let invalid = NegativeNormalizedF32::new(1.5);  // Err(FloatError::AboveRange) (> 0.0)
+//! let invalid = NegativeNormalizedF32::new(-1.5); // Err(FloatError::BelowRange) (< -1.0)
 //! ```
 //!
 //! ## `Symmetric` Constraint
@@ -831,8 +1221,8 @@
 //! let valid = SymmetricF32::new(-0.5);   // Ok(value)
 //! let valid = SymmetricF32::new(1.0);    // Ok(value)
 //! let valid = SymmetricF32::new(-1.0);   // Ok(value)
-//! let invalid = SymmetricF32::new(1.5);  // Err(FloatError::OutOfRange) (> 1.0)
-//! let invalid = SymmetricF32::new(-1.5); // Err(FloatError::OutOfRange) (< -1.0)
+//! let invalid = SymmetricF32::new(1.5);  // Err(FloatError::AboveRange) (> 1.0)
+//! let invalid = SymmetricF32::new(-1.5); // Err(FloatError::BelowRange) (< -1.0)
 //! ```
 //!
 //! Note: The `Symmetric` type is reflexive under negation (negating a `Symmetric` returns `Symmetric`):
@@ -856,8 +1246,31 @@
 //! let valid = PiBoundedF64::new(-core::f64::consts::FRAC_PI_3);  // Ok(value)
 //! let valid = PiBoundedF64::new(core::f64::consts::PI);          // Ok(value)
 //! let valid = PiBoundedF64::new(-core::f64::consts::PI);         // Ok(value)
-//! let invalid = PiBoundedF64::new(4.0);                          // Err(FloatError::OutOfRange) (> PI)
-//! let invalid = PiBoundedF64::new(-4.0);                         // Err(FloatError::OutOfRange) (< -PI)
+//! let invalid = PiBoundedF64::new(4.0);                          // Err(FloatError::AboveRange) (> PI)
+//! let invalid = PiBoundedF64::new(-4.0);                         // Err(FloatError::BelowRange) (< -PI)
+//! ```
+//!
+//! ### Angle Wrapping
+//!
+//! `PiBounded` sums and differences are unconstrained under normal inference, so
+//! `wrapping_add` and `wrapping_sub` fold them back into [-PI, PI]. Any value can be
+//! wrapped with `wrap_to_pi()`, or into the canonical [0, TAU) range of `TauBounded`
+//! with `wrap_to_tau()`, and `shortest_angle_to` gives the signed rotation between
+//! two angles. Angles already in range are never changed:
+//!
+//! ```
+//! use core::f64::consts::{FRAC_PI_2, PI};
+//! use strict_num_extended::*;
+//!
+//! let heading = PiBoundedF64::new(PI).unwrap();
+//! let turned = heading.wrapping_add(PiBoundedF64::new(FRAC_PI_2).unwrap());
+//! assert_eq!(turned.get(), -FRAC_PI_2);
+//!
+//! let canonical: TauBoundedF64 = turned.wrap_to_tau();
+//! assert_eq!(canonical.get(), 3.0 * FRAC_PI_2);
+//!
+//! let delta: PiBoundedF64 = heading.shortest_angle_to(turned);
+//! assert_eq!(delta.get(), FRAC_PI_2);
 //! ```
 //!
 //! ## Combined Constraints
@@ -870,8 +1283,8 @@
 //! // Positive = NonNegative AND NonZero (equivalent to x > 0)
 //! let valid = PositiveF32::new(1.0);     // Ok(value)
 //! let valid = PositiveF32::new(0.001);   // Ok(value)
-//! let invalid = PositiveF32::new(0.0);   // Err(FloatError::OutOfRange) (zero)
-//! let invalid = PositiveF32::new(-1.0);  // Err(FloatError::OutOfRange) (negative)
+//! let invalid = PositiveF32::new(0.0);   // Err(FloatError::BelowRange) (zero)
+//! let invalid = PositiveF32::new(-1.0);  // Err(FloatError::BelowRange) (negative)
 //! ```
 //!
 //! ## Unary Negation
@@ -905,7 +1318,6 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #![allow(clippy::manual_range_contains)]
-#![expect(clippy::approx_constant)]
 
 #[cfg(feature = "std")]
 extern crate std;
@@ -923,6 +1335,7 @@ strict_num_extended_macros::generate_finite_float_types!(
         (Negative, ["< 0.0"]),
         (Symmetric, [">= -1.0", "<= 1.0"]),
         (PiBounded, [">= -PI", "<= PI"]),
+        (TauBounded, [">= 0.0", "< TAU"]),
     ],
     [
         (Positive, Pos),
@@ -0,0 +1,146 @@
+//! Tests for angle wrapping on `PiBounded` and `TauBounded` types
+//!
+//! Angles already in range must come back bit-for-bit unchanged, and wrapped results
+//! must stay within the bounds of their width even where `PI` and `TAU` round.
+
+#![expect(clippy::float_cmp)]
+
+use core::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI, TAU};
+use strict_num_extended::*;
+
+#[test]
+fn test_tau_bounded_creation() {
+    assert!(TauBoundedF64::new(0.0).is_ok());
+    assert!(TauBoundedF64::new(PI).is_ok());
+    assert!(TauBoundedF64::new(TAU - 1e-9).is_ok());
+    assert_eq!(TauBoundedF64::new(TAU), Err(FloatError::AboveRange));
+    assert_eq!(TauBoundedF64::new(-0.1), Err(FloatError::BelowRange));
+    assert_eq!(
+        TauBoundedF32::new(core::f32::consts::TAU),
+        Err(FloatError::AboveRange)
+    );
+    assert_eq!(TauBoundedF64::DESCRIPTION, "range [0, TAU)");
+}
+
+#[test]
+fn test_wrap_to_pi_keeps_values_in_range() {
+    for value in [-PI, -FRAC_PI_2, -0.0, 0.0, 1e-300, FRAC_PI_4, PI] {
+        let wrapped = FinF64::new(value).unwrap().wrap_to_pi();
+        assert_eq!(wrapped.get().to_bits(), value.to_bits());
+    }
+
+    let pi = FinF32::new(core::f32::consts::PI).unwrap();
+    assert_eq!(pi.wrap_to_pi().get(), core::f32::consts::PI);
+}
+
+#[test]
+fn test_wrap_to_pi_folds() {
+    let wrap = |value: f64| FinF64::new(value).unwrap().wrap_to_pi().get();
+
+    assert_eq!(wrap(TAU), 0.0);
+    assert_eq!(wrap(-TAU), 0.0);
+    assert_eq!(wrap(3.0 * PI), PI);
+    assert_eq!(wrap(-3.0 * PI), PI);
+    assert_eq!(wrap(3.0 * FRAC_PI_2), -FRAC_PI_2);
+    assert_eq!(wrap(-3.0 * FRAC_PI_2), FRAC_PI_2);
+    assert!((wrap(100.0) - (100.0 - 32.0 * PI)).abs() < 1e-12);
+}
+
+#[test]
+fn test_wrap_to_tau() {
+    let wrap = |value: f64| FinF64::new(value).unwrap().wrap_to_tau().get();
+
+    assert_eq!(wrap(0.0), 0.0);
+    assert_eq!(wrap(PI), PI);
+    assert_eq!(wrap(TAU), 0.0);
+    assert_eq!(wrap(-FRAC_PI_2), 3.0 * FRAC_PI_2);
+    assert_eq!(wrap(-PI), PI);
+
+    // The exact result TAU - 1e-20 rounds up to TAU and must wrap to zero
+    assert_eq!(wrap(-1e-20), 0.0);
+    assert_eq!(FinF32::new(-1e-20).unwrap().wrap_to_tau().get(), 0.0);
+}
+
+#[test]
+fn test_wrap_extreme_values_stay_in_range() {
+    for value in [f64::MAX, f64::MIN, 1e17, -1e17, f64::MIN_POSITIVE, -5e-324] {
+        let fin = FinF64::new(value).unwrap();
+        assert!(PiBoundedF64::contains(fin.wrap_to_pi().get()));
+        assert!(TauBoundedF64::contains(fin.wrap_to_tau().get()));
+    }
+
+    for value in [f32::MAX, f32::MIN, -1e-30, 1e9] {
+        let fin = FinF32::new(value).unwrap();
+        assert!(PiBoundedF32::contains(fin.wrap_to_pi().get()));
+        assert!(TauBoundedF32::contains(fin.wrap_to_tau().get()));
+    }
+}
+
+#[test]
+fn test_wrapping_add_and_sub() {
+    let pi = PiBoundedF64::new(PI).unwrap();
+    let neg_pi = PiBoundedF64::new(-PI).unwrap();
+    let zero = PiBoundedF64::new(0.0).unwrap();
+    let quarter = PiBoundedF64::new(FRAC_PI_4).unwrap();
+
+    assert_eq!(pi.wrapping_add(zero).get(), PI);
+    assert_eq!(neg_pi.wrapping_sub(zero).get(), -PI);
+    assert_eq!(pi.wrapping_add(pi).get(), 0.0);
+    assert_eq!(neg_pi.wrapping_add(neg_pi).get(), 0.0);
+    assert_eq!(pi.wrapping_sub(neg_pi).get(), 0.0);
+    assert_eq!(quarter.wrapping_sub(pi).get(), quarter.get() - PI);
+
+    let sum = pi.wrapping_add(quarter);
+    assert_eq!(sum.get(), (PI + FRAC_PI_4) - TAU);
+    assert!(PiBoundedF64::contains(sum.get()));
+}
+
+#[test]
+fn test_wrapping_f32_boundaries() {
+    let pi = PiBoundedF32::new(core::f32::consts::PI).unwrap();
+    let tiny = PiBoundedF32::new(f32::EPSILON).unwrap();
+
+    let sum = pi.wrapping_add(tiny);
+    assert!(PiBoundedF32::contains(sum.get()));
+    assert!(sum.get() < 0.0);
+    assert_eq!(
+        pi.wrapping_sub(pi.wrapping_add(pi)).get(),
+        core::f32::consts::PI
+    );
+}
+
+#[test]
+fn test_shortest_angle_to() {
+    let angle = |value: f64| FinF64::new(value).unwrap();
+
+    assert_eq!(
+        angle(0.0).shortest_angle_to(angle(FRAC_PI_2)).get(),
+        FRAC_PI_2
+    );
+    assert_eq!(
+        angle(FRAC_PI_2).shortest_angle_to(angle(0.0)).get(),
+        -FRAC_PI_2
+    );
+    assert_eq!(angle(-PI).shortest_angle_to(angle(PI)).get(), 0.0);
+    assert_eq!(angle(TAU).shortest_angle_to(angle(0.0)).get(), 0.0);
+
+    let delta: PiBoundedF64 = angle(f64::MAX).shortest_angle_to(angle(f64::MIN));
+    assert!(PiBoundedF64::contains(delta.get()));
+
+    // Across the ±PI seam the short way round is taken
+    let from = PiBoundedF64::new(PI - 0.25).unwrap();
+    let to = PiBoundedF64::new(-PI + 0.25).unwrap();
+    assert!((from.shortest_angle_to(to).get() - 0.5).abs() < 1e-12);
+    assert!((to.shortest_angle_to(from).get() + 0.5).abs() < 1e-12);
+}
+
+#[test]
+fn test_tau_bounded_inference() {
+    let angle = TauBoundedF64::new(PI).unwrap();
+    // The product bound [0, TAU] is closed, so it does not fit [0, TAU)
+    let scaled: NonNegativeF64 = angle * NormalizedF64::new(0.5).unwrap();
+    assert_eq!(scaled.get(), FRAC_PI_2);
+
+    let negated: PiBoundedF64 = angle.wrap_to_pi();
+    assert_eq!(negated.get(), PI);
+}
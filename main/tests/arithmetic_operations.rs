@@ -199,6 +199,9 @@ mod test_safe_operations {
         SymmetricF64,
         0.4
     );
+
+    // [-PI, PI] * [-PI, PI] exceeds every declared bounded type, so it widens to Fin
+    test_arith!(test_pibounded_mul_pibounded, PiBoundedF64, 3.0, *, PiBoundedF64, 3.0, FinF64, 9.0);
 }
 
 mod test_fallible_operations {
@@ -482,11 +485,12 @@ mod test_edge_cases {
     test_arith!(test_normalized_extremes, NormalizedF64, 0.0, +, NormalizedF64, 1.0, Result<NonNegativeF64, FloatError>, Ok(1.0));
 }
 
+/// Divisions of bounded dividends, which fail when a subnormal divisor overflows
 mod test_safe_division {
     use super::*;
 
     // Original: test_safe_arith! -> returns direct value
-    test_arith!(test_normalized_div_nonzero, NormalizedF64, 0.5, /, NonZeroF64, 2.0, FinF64, 0.25);
+    test_arith!(test_normalized_div_nonzero, NormalizedF64, 0.5, /, NonZeroF64, 2.0, Result<FinF64, FloatError>, Ok(0.25));
 
     // Original: test_safe_arith! -> returns direct value
     test_arith!(
@@ -496,8 +500,8 @@ mod test_safe_division {
         /,
         PositiveF64,
         2.0,
-        NonNegativeF64,
-        0.5
+        Result<NonNegativeF64, FloatError>,
+        Ok(0.5)
     );
 
     // Original: test_safe_arith! -> returns direct value
@@ -508,8 +512,8 @@ mod test_safe_division {
         /,
         PositiveF64,
         4.0,
-        NonNegativeF64,
-        0.2
+        Result<NonNegativeF64, FloatError>,
+        Ok(0.2)
     );
 
     // Original: test_safe_arith! -> returns direct value
@@ -520,8 +524,8 @@ mod test_safe_division {
         /,
         NonZeroF64,
         2.0,
-        FinF64,
-        -0.25
+        Result<FinF64, FloatError>,
+        Ok(-0.25)
     );
 
     // Original: test_safe_arith! -> returns direct value
@@ -532,12 +536,12 @@ mod test_safe_division {
         /,
         NegativeF64,
         -2.0,
-        NonNegativeF64,
-        0.5
+        Result<NonNegativeF64, FloatError>,
+        Ok(0.5)
     );
 
     // Original: test_safe_arith! -> returns direct value
-    test_arith!(test_symmetric_div_nonzero, SymmetricF64, 0.5, /, NonZeroF64, 2.0, FinF64, 0.25);
+    test_arith!(test_symmetric_div_nonzero, SymmetricF64, 0.5, /, NonZeroF64, 2.0, Result<FinF64, FloatError>, Ok(0.25));
 
     // Original: test_safe_arith! -> returns direct value
     test_arith!(
@@ -547,12 +551,12 @@ mod test_safe_division {
         /,
         PositiveF64,
         4.0,
-        FinF64,
-        -0.2
+        Result<FinF64, FloatError>,
+        Ok(-0.2)
     );
 
     // Original: test_safe_arith! -> returns direct value
-    test_arith!(test_normalized_zero_div_nonzero, NormalizedF64, 0.0, /, NonZeroF64, 5.0, FinF64, 0.0);
+    test_arith!(test_normalized_zero_div_nonzero, NormalizedF64, 0.0, /, NonZeroF64, 5.0, Result<FinF64, FloatError>, Ok(0.0));
 
     // Original: test_safe_arith! -> returns direct value
     test_arith!(
@@ -562,8 +566,8 @@ mod test_safe_division {
         /,
         NegativeF64,
         -2.0,
-        NonPositiveF64,
-        -0.25
+        Result<NonPositiveF64, FloatError>,
+        Ok(-0.25)
     );
 
     // Note: These tests use f64::MIN/f64::MAX constants and cannot use new_const
@@ -571,8 +575,8 @@ mod test_safe_division {
     fn test_one_div_f64_min() {
         const A: NormalizedF64 = NormalizedF64::new_const(1.0);
         const B: NonZeroF64 = NonZeroF64::new_const(f64::MIN);
-        // Safe operation: 1.0 / f64::MIN ≈ -5.56e-319 (finite, not overflow)
-        let result: FinF64 = A / B;
+        // 1.0 / f64::MIN ≈ -5.56e-319 (finite, not overflow)
+        let result: FinF64 = (A / B).unwrap();
         // Result should be very small but finite
         assert!(result.get().is_finite());
         assert!(result.get() < 0.0); // Should be negative (1.0 / negative = negative)
@@ -583,8 +587,8 @@ mod test_safe_division {
     fn test_negative_one_div_f64_max() {
         const A: NegativeNormalizedF64 = NegativeNormalizedF64::new_const(-1.0);
         const B: NonZeroF64 = NonZeroF64::new_const(f64::MAX);
-        // Safe operation: -1.0 / f64::MAX ≈ -5.56e-319 (finite)
-        let result: FinF64 = A / B;
+        // -1.0 / f64::MAX ≈ -5.56e-319 (finite)
+        let result: FinF64 = (A / B).unwrap();
         assert!(result.get().is_finite());
         assert!(result.get() < 0.0);
         assert!(result.get().abs() < 1e-308);
@@ -594,10 +598,33 @@ mod test_safe_division {
     fn test_symmetric_extremes_div() {
         const A: SymmetricF64 = SymmetricF64::new_const(1.0);
         const B: PositiveF64 = PositiveF64::new_const(f64::MIN_POSITIVE);
-        // Safe operation: 1.0 / smallest positive ≈ 8.99e+307 (large but finite)
-        let result: FinF64 = A / B;
+        // 1.0 / smallest positive normal ≈ 4.49e+307 (large but finite)
+        let result: FinF64 = (A / B).unwrap();
         assert!(result.get().is_finite());
         assert!(result.get() > 0.0);
         assert!(result.get() > 1e307);
     }
+
+    #[test]
+    fn test_subnormal_divisor_overflows() {
+        let one = NormalizedF64::new(1.0).unwrap();
+        let tiny = PositiveF64::new(5e-324).unwrap();
+        assert_eq!(one / tiny, Err(FloatError::Overflow));
+        let negative_tiny = NegativeF64::new(-5e-324).unwrap();
+        assert_eq!(one / negative_tiny, Err(FloatError::Overflow));
+        let symmetric = SymmetricF64::new(-1.0).unwrap();
+        assert_eq!(
+            symmetric / NonZeroF64::new(5e-324).unwrap(),
+            Err(FloatError::Overflow)
+        );
+
+        let one_f32 = NormalizedF32::new(1.0).unwrap();
+        let tiny_f32 = PositiveF32::new(1e-45).unwrap();
+        assert_eq!(one_f32 / tiny_f32, Err(FloatError::Overflow));
+        let angle = PiBoundedF32::new(-3.0).unwrap();
+        assert_eq!(
+            angle / NonZeroF32::new(-1e-45).unwrap(),
+            Err(FloatError::Overflow)
+        );
+    }
 }
@@ -0,0 +1,90 @@
+//! Tests for compound assignment operators and `try_*_assign` methods
+//!
+//! Operators are only available when the operation is safe and its output type
+//! equals the left-hand type; everything else goes through the fallible methods.
+
+#![expect(clippy::float_cmp)]
+
+use strict_num_extended::*;
+
+#[test]
+fn test_closed_mul_assign() {
+    let mut x = NormalizedF64::new(0.5).unwrap();
+    x *= NormalizedF64::new(0.5).unwrap();
+    assert_eq!(x.get(), 0.25);
+
+    let mut s = SymmetricF32::new(-0.5).unwrap();
+    s *= SymmetricF32::new(0.5).unwrap();
+    assert_eq!(s.get(), -0.25);
+}
+
+#[test]
+fn test_closed_assign_with_subset_rhs() {
+    // Symmetric * Normalized stays Symmetric
+    let mut s = SymmetricF64::new(-0.8).unwrap();
+    s *= NormalizedF64::new(0.5).unwrap();
+    assert_eq!(s.get(), -0.4);
+}
+
+#[test]
+fn test_try_add_assign() {
+    let mut x = PositiveF64::new(1.0).unwrap();
+    x.try_add_assign(PositiveF64::new(2.0).unwrap()).unwrap();
+    assert_eq!(x.get(), 3.0);
+
+    let mut max = PositiveF64::new(f64::MAX).unwrap();
+    assert!(
+        max.try_add_assign(PositiveF64::new(f64::MAX).unwrap())
+            .is_err()
+    );
+    assert_eq!(max.get(), f64::MAX);
+}
+
+#[test]
+fn test_try_sub_assign_checks_own_constraint() {
+    let mut x = PositiveF64::new(5.0).unwrap();
+    x.try_sub_assign(PositiveF64::new(2.0).unwrap()).unwrap();
+    assert_eq!(x.get(), 3.0);
+
+    // The difference is a valid Fin value but not a Positive one
+    assert_eq!(
+        x.try_sub_assign(PositiveF64::new(4.0).unwrap()),
+        Err(FloatError::BelowRange)
+    );
+    assert_eq!(x.get(), 3.0);
+}
+
+#[test]
+fn test_try_mul_assign_overflow() {
+    let mut x = FinF32::new(f32::MAX).unwrap();
+    assert!(x.try_mul_assign(FinF32::new(2.0).unwrap()).is_err());
+    assert_eq!(x.get(), f32::MAX);
+}
+
+#[test]
+fn test_try_div_assign() {
+    let mut x = NonNegativeF64::new(6.0).unwrap();
+    x.try_div_assign(PositiveF64::new(4.0).unwrap()).unwrap();
+    assert_eq!(x.get(), 1.5);
+
+    assert!(x.try_div_assign(NonNegativeF64::new(0.0).unwrap()).is_err());
+    assert_eq!(x.get(), 1.5);
+}
+
+#[test]
+fn test_try_assign_with_primitive() {
+    let mut x = NormalizedF64::new(0.5).unwrap();
+    x.try_add_assign(0.25).unwrap();
+    assert_eq!(x.get(), 0.75);
+
+    assert_eq!(x.try_add_assign(0.5), Err(FloatError::AboveRange));
+    assert!(x.try_mul_assign(f64::NAN).is_err());
+    assert_eq!(x.get(), 0.75);
+}
+
+#[test]
+fn test_try_assign_on_closed_operation() {
+    let mut x = NormalizedF32::new(0.5).unwrap();
+    x.try_mul_assign(NormalizedF32::new(0.5).unwrap()).unwrap();
+    assert_eq!(x.get(), 0.25);
+}
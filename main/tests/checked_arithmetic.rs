@@ -0,0 +1,131 @@
+//! Tests for the `Checked` wrapper
+//!
+//! `Checked` must infer the same output types as the plain operators and keep the
+//! first error of a chain.
+
+#![expect(clippy::float_cmp)]
+
+use strict_num_extended::*;
+
+#[test]
+fn test_checked_op_checked() {
+    let a = Checked::new(PositiveF64::new(6.0).unwrap());
+    let b = Checked::new(NegativeF64::new(-2.0).unwrap());
+
+    let sum: Checked<FinF64> = a + b;
+    assert_eq!(sum.into_result().unwrap().get(), 4.0);
+
+    let quotient: Checked<NegativeF64> = a / b;
+    assert_eq!(quotient.into_result().unwrap().get(), -3.0);
+
+    let remainder: Checked<NonNegativeF64> = a.rem_euclid(b);
+    assert_eq!(remainder.into_result().unwrap().get(), 0.0);
+}
+
+#[test]
+fn test_checked_with_plain_operands() {
+    let a = PositiveF64::new(3.0).unwrap();
+    let b = NormalizedF64::new(0.5).unwrap();
+
+    let lhs: Checked<PositiveF64> = Checked::new(a) + a;
+    assert_eq!(lhs.into_result().unwrap().get(), 6.0);
+
+    let rhs: Checked<NonNegativeF64> = a * Checked::new(b);
+    assert_eq!(rhs.into_result().unwrap().get(), 1.5);
+
+    let primitive: Checked<FinF64> = Checked::new(a) - 5.0;
+    assert_eq!(primitive.into_result().unwrap().get(), -2.0);
+
+    let reversed: Checked<FinF32> = 1.0_f32 / Checked::new(PositiveF32::new(4.0).unwrap());
+    assert_eq!(reversed.into_result().unwrap().get(), 0.25);
+}
+
+#[test]
+fn test_checked_keeps_first_error() {
+    let max = Checked::new(PositiveF64::new(f64::MAX).unwrap());
+    let zero = FinF64::new(0.0).unwrap();
+
+    let overflow = max * max;
+    assert_eq!(overflow.error(), Some(FloatError::Overflow));
+
+    let chained = (overflow / zero) - f64::NAN;
+    assert_eq!(chained.error(), Some(FloatError::Overflow));
+
+    let late = Checked::new(PositiveF64::new(1.0).unwrap()) + (overflow / zero);
+    assert_eq!(late.error(), Some(FloatError::Overflow));
+
+    let primitive = Checked::new(FinF64::new(1.0).unwrap()) + f64::INFINITY;
+    assert!(primitive.is_err());
+}
+
+#[test]
+fn test_checked_result_interop() {
+    let ok: Result<PositiveF64, FloatError> = PositiveF64::new(2.0);
+    let err: Result<PositiveF64, FloatError> = PositiveF64::new(-2.0);
+
+    let sum = Checked::from(ok) + Checked::from(ok);
+    let resolved: Result<PositiveF64, FloatError> = sum.into();
+    assert_eq!(resolved.unwrap().get(), 4.0);
+
+    let failed = Checked::from(ok) * Checked::from(err);
+    assert_eq!(failed.into_result(), Err(FloatError::BelowRange));
+
+    let missing: Option<PositiveF64> = None;
+    let from_option = Checked::from_result(missing.ok_or(FloatError::NoneOperand)) + ok.unwrap();
+    assert_eq!(from_option.error(), Some(FloatError::NoneOperand));
+}
+
+#[test]
+fn test_checked_unary() {
+    let a = Checked::new(PositiveF64::new(2.0).unwrap());
+
+    let negated: Checked<NegativeF64> = -a;
+    assert_eq!(negated.into_result().unwrap().get(), -2.0);
+
+    let abs: Checked<PositiveF64> = negated.abs();
+    assert_eq!(abs.into_result().unwrap().get(), 2.0);
+
+    let sign: Checked<NegativeNormalizedF64> = negated.signum();
+    assert_eq!(sign.into_result().unwrap().get(), -1.0);
+
+    let double_neg: Checked<PositiveF64> = -(-a);
+    assert_eq!(double_neg, a);
+
+    let err: Checked<PositiveF64> = Checked::from_result(Err(FloatError::NaN));
+    assert_eq!((-err).error(), Some(FloatError::NaN));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_checked_math() {
+    let angle = Checked::new(FinF64::new(0.0).unwrap());
+    let sin: Checked<SymmetricF64> = angle.sin();
+    let cos: Checked<SymmetricF64> = angle.cos();
+    assert_eq!(sin.into_result().unwrap().get(), 0.0);
+    assert_eq!(cos.into_result().unwrap().get(), 1.0);
+    assert_eq!(angle.tan().into_result().unwrap().get(), 0.0);
+
+    let root: Checked<PositiveF64> = Checked::new(PositiveF64::new(9.0).unwrap()).sqrt();
+    assert_eq!(root.into_result().unwrap().get(), 3.0);
+    assert_eq!(
+        Checked::new(FinF64::new(-4.0).unwrap()).sqrt().error(),
+        Some(FloatError::NaN)
+    );
+
+    // Hypotenuse in one expression
+    let x = Checked::new(FinF64::new(3.0).unwrap());
+    let y = Checked::new(FinF64::new(-4.0).unwrap());
+    let hypot = (x * x + y * y).sqrt();
+    assert_eq!(hypot.into_result().unwrap().get(), 5.0);
+}
+
+#[test]
+fn test_checked_map_and_then() {
+    let a = Checked::new(FinF64::new(0.25).unwrap());
+    let normalized = a.and_then(Strict::try_convert::<Normalized>);
+    assert_eq!(normalized.into_result().unwrap().get(), 0.25);
+
+    let doubled = a.map(|value| value.get() * 2.0);
+    assert_eq!(doubled.into_result(), Ok(0.5));
+    assert!(a.is_ok());
+}
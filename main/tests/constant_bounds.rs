@@ -0,0 +1,64 @@
+//! Tests for constant expressions in constraint bounds
+//!
+//! Bounds written with named constants are emitted per float width, so f32 types
+//! compare against the f32 constant rather than a rounded f64 value.
+
+#![expect(clippy::float_cmp)]
+#![allow(clippy::manual_range_contains)]
+
+mod consts {
+    strict_num_extended_macros::generate_finite_float_types!([
+        (Fin, []),
+        (NonNegative, [">= 0.0"]),
+        (Symmetric, [">= -1.0", "<= 1.0"]),
+        (Normalized, [">= 0.0", "<= 1.0"]),
+        (Positive, ["> 0.0"]),
+        (Turn, [">= 0.0", "< TAU"]),
+        (QuarterTurn, [">= -FRAC_PI_2", "<= FRAC_PI_2"]),
+        (SingleRange, [">= -f32::MAX", "<= f32::MAX"]),
+        (Tolerant, [">= -1e-3", "<= 2 * PI"]),
+    ]);
+}
+
+use consts::*;
+
+#[test]
+fn test_tau_upper_bound() {
+    assert!(TurnF64::new(0.0).is_ok());
+    assert!(TurnF64::new(core::f64::consts::PI).is_ok());
+    assert!(TurnF64::new(core::f64::consts::TAU).is_err());
+    assert!(TurnF32::new(core::f32::consts::TAU).is_err());
+    assert!(TurnF32::new(6.0).is_ok());
+}
+
+#[test]
+fn test_constant_rounded_per_width() {
+    // f32 FRAC_PI_2 is slightly above the f64 one, but still within its own bound
+    assert!(QuarterTurnF32::new(core::f32::consts::FRAC_PI_2).is_ok());
+    assert!(QuarterTurnF32::new(-core::f32::consts::FRAC_PI_2).is_ok());
+    assert!(QuarterTurnF64::new(core::f64::consts::FRAC_PI_2).is_ok());
+    assert!(QuarterTurnF64::new(core::f64::consts::FRAC_PI_2 + 1e-15).is_err());
+}
+
+#[test]
+fn test_width_pinned_constant() {
+    assert!(SingleRangeF32::new(f32::MAX).is_ok());
+    assert!(SingleRangeF32::new(f32::MIN).is_ok());
+    assert!(SingleRangeF64::new(f64::from(f32::MAX)).is_ok());
+    assert!(SingleRangeF64::new(f64::from(f32::MAX) * 2.0).is_err());
+}
+
+#[test]
+fn test_constant_arithmetic() {
+    assert!(TolerantF64::new(-1e-3).is_ok());
+    assert!(TolerantF64::new(-1.1e-3).is_err());
+    assert!(TolerantF64::new(core::f64::consts::TAU).is_ok());
+    assert!(TolerantF64::new(7.0).is_err());
+    assert!(TolerantF32::new(core::f32::consts::TAU).is_ok());
+}
+
+#[test]
+fn test_constant_bounds_in_const_context() {
+    const QUARTER: QuarterTurnF32 = QuarterTurnF32::new_const(core::f32::consts::FRAC_PI_2);
+    assert_eq!(QUARTER.get(), core::f32::consts::FRAC_PI_2);
+}
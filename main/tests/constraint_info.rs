@@ -0,0 +1,112 @@
+//! Tests for constraint metadata exposed through `ConstraintInfo` and `FiniteFloat`
+//!
+//! Bounds are reported per float width, so rounded bounds such as `PI` differ
+//! between the `f32` and `f64` types.
+
+use core::f64::consts::PI;
+use strict_num_extended::*;
+
+#[test]
+fn test_sign_only_bounds() {
+    assert_eq!(PositiveF64::LOWER, Some(0.0));
+    assert_eq!(PositiveF64::UPPER, None);
+    const { assert!(!PositiveF64::LOWER_INCLUSIVE) };
+    const { assert!(PositiveF64::EXCLUDES_ZERO) };
+
+    assert_eq!(NonPositiveF32::LOWER, None);
+    assert_eq!(NonPositiveF32::UPPER, Some(0.0));
+    const { assert!(NonPositiveF32::UPPER_INCLUSIVE) };
+    const { assert!(!NonPositiveF32::EXCLUDES_ZERO) };
+
+    assert_eq!(FinF64::LOWER, None);
+    assert_eq!(FinF64::UPPER, None);
+    const { assert!(NonZeroF64::EXCLUDES_ZERO) };
+}
+
+#[test]
+fn test_interval_bounds() {
+    assert_eq!(NormalizedF32::LOWER, Some(0.0));
+    assert_eq!(NormalizedF32::UPPER, Some(1.0));
+    const { assert!(NormalizedF32::LOWER_INCLUSIVE && NormalizedF32::UPPER_INCLUSIVE) };
+
+    assert_eq!(SymmetricF64::LOWER, Some(-1.0));
+    assert_eq!(PiBoundedF64::UPPER, Some(PI));
+    assert_eq!(PiBoundedF32::UPPER, Some(core::f32::consts::PI));
+}
+
+#[test]
+fn test_contains() {
+    assert!(NormalizedF64::contains(0.0));
+    assert!(NormalizedF64::contains(1.0));
+    assert!(!NormalizedF64::contains(1.5));
+    assert!(!NormalizedF64::contains(f64::NAN));
+    assert!(!PositiveF32::contains(0.0));
+    assert!(!NonZeroF64::contains(-0.0));
+    assert!(!FinF64::contains(f64::INFINITY));
+    assert!(PiBoundedF32::contains(core::f32::consts::PI));
+}
+
+#[test]
+fn test_contains_matches_new() {
+    for value in [-4.0, -1.0, -0.5, 0.0, 0.5, 1.0, PI, 4.0] {
+        assert_eq!(
+            NormalizedF64::contains(value),
+            NormalizedF64::new(value).is_ok()
+        );
+        assert_eq!(NonZeroF64::contains(value), NonZeroF64::new(value).is_ok());
+        assert_eq!(
+            PiBoundedF64::contains(value),
+            PiBoundedF64::new(value).is_ok()
+        );
+    }
+}
+
+#[test]
+fn test_names_and_descriptions() {
+    assert_eq!(PositiveF64::NAME, "Positive");
+    assert_eq!(NegativeNormalizedF32::NAME, "NegativeNormalized");
+    assert_eq!(PosF64::NAME, "Positive");
+    assert_eq!(NonNegativeF64::DESCRIPTION, "non-negative");
+    assert_eq!(NonZeroF32::DESCRIPTION, "non-zero");
+    assert_eq!(SymmetricF64::DESCRIPTION, "range [-1, 1]");
+}
+
+#[test]
+fn test_dyn_finite_float_reports_constraint() {
+    let values: Vec<Box<dyn FiniteFloat>> = vec![
+        Box::new(FinF32::new(1.0).unwrap()),
+        Box::new(NegativeF64::new(-2.0).unwrap()),
+        Box::new(NormalizedF64::new(0.5).unwrap()),
+    ];
+
+    let names: Vec<_> = values.iter().map(|v| v.constraint_name()).collect();
+    assert_eq!(names, ["Fin", "Negative", "Normalized"]);
+
+    let descriptions: Vec<_> = values.iter().map(|v| v.describe()).collect();
+    assert_eq!(descriptions, ["finite", "negative", "range [0, 1]"]);
+}
+
+#[test]
+fn test_generic_over_constraint_info() {
+    fn slider_range<T: ConstraintInfo<Raw = f64>>() -> (f64, f64) {
+        (T::LOWER.unwrap_or(f64::MIN), T::UPPER.unwrap_or(f64::MAX))
+    }
+
+    assert_eq!(slider_range::<NormalizedF64>(), (0.0, 1.0));
+    assert_eq!(slider_range::<NonNegativeF64>(), (0.0, f64::MAX));
+}
+
+mod custom {
+    strict_num_extended::define_constraints!([(Percent, [">= 0.0", "<= 100.0"])]);
+}
+
+#[test]
+fn test_custom_constraint_info() {
+    use custom::PercentF64;
+
+    assert_eq!(PercentF64::NAME, "Percent");
+    assert_eq!(PercentF64::UPPER, Some(100.0));
+    assert_eq!(PercentF64::DESCRIPTION, "range [0, 100]");
+    assert!(!PercentF64::contains(100.5));
+    assert_eq!(PercentF64::new(50.0).unwrap().constraint_name(), "Percent");
+}
@@ -0,0 +1,151 @@
+//! Tests for custom constraint types defined with `define_constraints!`
+//!
+//! Custom types are aliases of the main crate's `Strict` type, and interoperate
+//! with the built-in types through conversions and arithmetic.
+
+#![expect(clippy::float_cmp)]
+#![allow(clippy::manual_range_contains)]
+
+use strict_num_extended::{
+    Constraint, FinF32, FinF64, FiniteFloat, FloatError, NegativeF64, NonNegativeF64,
+    NormalizedF64, ParseFloatError, PositiveF64, Strict,
+};
+
+strict_num_extended::define_constraints!(
+    [
+        (Probability, [">= 0.0", "<= 1.0"]),
+        (Kelvin, [">= 0.0"]),
+        (Percent, [">= 0.0", "<= 100.0"]),
+    ],
+    [(Probability, Prob)]
+);
+
+mod nested {
+    strict_num_extended::define_constraints!([(Celsius, [">= -273.15"])]);
+}
+
+use nested::CelsiusF64;
+
+#[test]
+fn test_custom_type_validation() {
+    assert!(ProbabilityF64::new(0.5).is_ok());
+    assert_eq!(ProbabilityF64::new(1.5), Err(FloatError::AboveRange));
+    assert_eq!(ProbabilityF32::new(f32::NAN), Err(FloatError::NaN));
+    assert!(KelvinF64::new(-1.0).is_err());
+    assert!(CelsiusF64::new(-273.15).is_ok());
+    assert!(CelsiusF64::new(-300.0).is_err());
+}
+
+#[test]
+fn test_custom_type_alias() {
+    let p: ProbF64 = ProbabilityF64::new(0.25).unwrap();
+    assert_eq!(p.get(), 0.25);
+}
+
+#[test]
+fn test_conversions_with_builtin_types() {
+    let p = ProbabilityF64::new(0.75).unwrap();
+
+    // Subset of the built-in types: infallible
+    let fin: FinF64 = p.into();
+    let norm: NormalizedF64 = p.into();
+    let non_neg: NonNegativeF64 = p.into();
+    assert_eq!(fin.get(), 0.75);
+    assert_eq!(norm.get(), 0.75);
+    assert_eq!(non_neg.get(), 0.75);
+
+    // Same bounds as Normalized: infallible both ways
+    let back: ProbabilityF64 = norm.into();
+    assert_eq!(back, p);
+
+    // Superset to subset: fallible
+    let large = FinF64::new(2.0).unwrap();
+    assert_eq!(
+        large.try_convert::<Probability>(),
+        Err(FloatError::AboveRange)
+    );
+    assert!(FinF64::new(300.0).unwrap().try_convert::<Kelvin>().is_ok());
+    let zero = KelvinF64::new(0.0).unwrap();
+    assert!(zero.try_convert::<strict_num_extended::Positive>().is_err());
+}
+
+#[test]
+fn test_conversions_between_custom_types() {
+    let p = ProbabilityF64::new(0.5).unwrap();
+    let k: KelvinF64 = p.convert();
+    assert_eq!(k.get(), 0.5);
+    let hot = KelvinF64::new(200.0).unwrap();
+    assert!(hot.try_convert::<Percent>().is_err());
+}
+
+#[test]
+fn test_arithmetic_with_builtin_types() {
+    let k = KelvinF64::new(300.0).unwrap();
+    let norm = NormalizedF64::new(0.5).unwrap();
+
+    // Results are inferred as built-in types when one matches
+    let sum: Result<NonNegativeF64, FloatError> = k + norm;
+    assert_eq!(sum.unwrap().get(), 300.5);
+    let reversed: Result<NonNegativeF64, FloatError> = norm + k;
+    assert_eq!(reversed.unwrap().get(), 300.5);
+
+    let p = ProbabilityF64::new(0.5).unwrap();
+    let product: NormalizedF64 = p * norm;
+    assert_eq!(product.get(), 0.25);
+
+    let diff: FinF64 = k - PositiveF64::new(400.0).unwrap();
+    assert_eq!(diff.get(), -100.0);
+
+    let neg: NegativeF64 = NegativeF64::new(-1.0).unwrap();
+    assert!((k / neg).is_ok());
+}
+
+#[test]
+fn test_arithmetic_with_primitives_option_and_result() {
+    let p = ProbabilityF64::new(0.5).unwrap();
+    let sum: Result<FinF64, FloatError> = p + 1.0;
+    assert_eq!(sum.unwrap().get(), 1.5);
+    let reversed: Result<FinF64, FloatError> = 1.0 + p;
+    assert_eq!(reversed.unwrap().get(), 1.5);
+
+    let norm = NormalizedF64::new(0.5).unwrap();
+    let product: Option<NormalizedF64> = p * Some(norm);
+    assert_eq!(product.unwrap().get(), 0.25);
+
+    let option_rhs: Option<NormalizedF64> = norm * Some(p);
+    assert_eq!(option_rhs.unwrap().get(), 0.25);
+
+    let result: Result<NormalizedF64, FloatError> = Ok(norm);
+    let chained = result * p;
+    assert_eq!(chained.unwrap().get(), 0.25);
+}
+
+#[test]
+fn test_generic_over_custom_constraints() {
+    fn name<C: Constraint>(_: Strict<f64, C>) -> &'static str {
+        C::NAME
+    }
+
+    assert_eq!(name(ProbabilityF64::new(0.5).unwrap()), "Probability");
+    assert_eq!(name(NormalizedF64::new(0.5).unwrap()), "Normalized");
+    assert!(Kelvin::F64_BOUNDS.contains(0.0));
+    assert!(!Kelvin::F64_BOUNDS.contains(-1.0));
+}
+
+#[test]
+fn test_custom_type_traits() {
+    let p = ProbabilityF32::new(0.5).unwrap();
+    assert_eq!(FiniteFloat::as_f64(&p), 0.5);
+
+    let f: FinF32 = p.into();
+    assert_eq!(f.get(), 0.5);
+    let widened: ProbabilityF64 = p.into();
+    assert_eq!(widened.get(), 0.5);
+
+    let parsed: Result<PercentF64, ParseFloatError> = "42".parse();
+    assert_eq!(parsed.unwrap().get(), 42.0);
+    assert!("101".parse::<PercentF64>().is_err());
+
+    assert_eq!((-p).get(), -0.5);
+    assert_eq!(PercentF64::ZERO.get(), 0.0);
+}
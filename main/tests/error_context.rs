@@ -0,0 +1,180 @@
+//! Tests for the context carried by `DetailedError`
+//!
+//! Errors must record the rejected value, the target type, the violated bound and, for
+//! arithmetic, the operation, while `FloatError` stays a plain, matchable kind.
+
+#![expect(clippy::float_cmp)]
+
+use core::f64::consts::PI;
+use strict_num_extended::*;
+
+#[test]
+fn test_new_detailed_records_value_type_and_bound() {
+    let err = PositiveF64::new_detailed(-2.0).unwrap_err();
+    assert_eq!(err.kind(), FloatError::BelowRange);
+    assert_eq!(err.value(), Some(-2.0));
+    assert_eq!(err.type_name(), Some("PositiveF64"));
+    assert_eq!(
+        err.bound(),
+        Some(ViolatedBound::Lower {
+            bound: 0.0,
+            inclusive: false
+        })
+    );
+    assert_eq!(err.operation(), None);
+    assert_eq!(err.to_string(), "PositiveF64: -2.0 violates > 0");
+
+    // `new` reports the kind alone
+    assert!(matches!(
+        PositiveF64::new(-2.0),
+        Err(FloatError::BelowRange)
+    ));
+    assert_eq!(PositiveF64::new_detailed(2.0).unwrap().get(), 2.0);
+}
+
+#[test]
+fn test_violated_bound_of_each_side() {
+    let above = NormalizedF64::new_detailed(1.5).unwrap_err();
+    assert_eq!(
+        above.bound(),
+        Some(ViolatedBound::Upper {
+            bound: 1.0,
+            inclusive: true
+        })
+    );
+    assert_eq!(above.to_string(), "NormalizedF64: 1.5 violates <= 1");
+
+    let below = NonNegativeF64::new_detailed(-0.5).unwrap_err();
+    assert_eq!(below.to_string(), "NonNegativeF64: -0.5 violates >= 0");
+
+    let zero = NonZeroF64::new_detailed(0.0).unwrap_err();
+    assert_eq!(zero.bound(), Some(ViolatedBound::Zero));
+    assert_eq!(zero.to_string(), "NonZeroF64: 0.0 violates != 0");
+
+    let tau = TauBoundedF64::new_detailed(core::f64::consts::TAU).unwrap_err();
+    assert_eq!(
+        tau.bound(),
+        Some(ViolatedBound::Upper {
+            bound: core::f64::consts::TAU,
+            inclusive: false
+        })
+    );
+
+    let pi = PiBoundedF64::new_detailed(-4.0).unwrap_err();
+    assert_eq!(
+        pi.bound(),
+        Some(ViolatedBound::Lower {
+            bound: -PI,
+            inclusive: true
+        })
+    );
+}
+
+#[test]
+fn test_non_finite_values() {
+    let nan = FinF64::new_detailed(f64::NAN).unwrap_err();
+    assert_eq!(nan.kind(), FloatError::NaN);
+    assert_eq!(nan.bound(), None);
+    assert_eq!(nan.to_string(), "FinF64: NaN is not a finite number");
+
+    let inf = PositiveF64::new_detailed(f64::INFINITY).unwrap_err();
+    assert_eq!(inf.kind(), FloatError::PosInf);
+    assert_eq!(inf.value(), Some(f64::INFINITY));
+    assert_eq!(inf.to_string(), "PositiveF64: inf is not a finite number");
+
+    let neg_inf = FinF32::new_detailed(f32::NEG_INFINITY).unwrap_err();
+    assert_eq!(neg_inf.kind(), FloatError::NegInf);
+}
+
+#[test]
+fn test_f32_values_print_at_their_width() {
+    let err = NormalizedF32::new_detailed(1.1).unwrap_err();
+    assert_eq!(err.type_name(), Some("NormalizedF32"));
+    assert_eq!(err.value(), Some(f64::from(1.1f32)));
+    assert_eq!(err.to_string(), "NormalizedF32: 1.1 violates <= 1");
+}
+
+#[test]
+fn test_checked_records_operation() {
+    let tiny = PositiveF64::new(1e-200).unwrap();
+    let err = (Checked::new(tiny) * tiny).detailed_error().unwrap();
+    let operation = err.operation().unwrap();
+    assert_eq!(operation.operator(), Operator::Mul);
+    assert_eq!(operation.lhs(), 1e-200);
+    assert_eq!(operation.rhs(), 1e-200);
+    assert_eq!(
+        err.to_string(),
+        "PositiveF64: 1e-200 * 1e-200 = 0.0 underflows, violating > 0"
+    );
+
+    let max = PositiveF64::new(f64::MAX).unwrap();
+    let overflow = (max + Checked::new(max)).detailed_error().unwrap();
+    assert_eq!(overflow.operation().unwrap().operator(), Operator::Add);
+    assert_eq!(overflow.value(), Some(f64::INFINITY));
+    assert_eq!(overflow.kind(), FloatError::Overflow);
+}
+
+#[test]
+fn test_checked_records_primitive_operation() {
+    let a = FinF64::new(1.0).unwrap();
+    let division = (Checked::new(a) / 0.0).detailed_error().unwrap();
+    assert_eq!(division.to_string(), "FinF64: 1.0 / 0.0 divides by zero");
+
+    let b = FinF32::new(f32::MAX).unwrap();
+    let overflow = (2.0f32 * Checked::new(b)).detailed_error().unwrap();
+    let operation = overflow.operation().unwrap();
+    assert_eq!(operation.lhs(), 2.0);
+    assert_eq!(operation.rhs(), f64::from(f32::MAX));
+}
+
+#[test]
+fn test_euclid_operation_display() {
+    let a = Checked::new(FinF64::new(1.0).unwrap());
+    let zero = NonNegativeF64::new(0.0).unwrap();
+    let err = a.div_euclid(zero).detailed_error().unwrap();
+    assert_eq!(err.operation().unwrap().operator(), Operator::DivEuclid);
+    assert!(err.to_string().contains("1.0.div_euclid(0.0)"));
+}
+
+#[test]
+fn test_context_survives_checked_chains() {
+    let a = PositiveF64::new(2.0).unwrap();
+    let b = PositiveF64::new(5.0).unwrap();
+    let zero = FinF64::new(0.0).unwrap();
+
+    let chained = (Checked::new(a) / zero) * b;
+    assert_eq!(chained.error(), Some(FloatError::DivisionByZero));
+    let err = chained.detailed_error().unwrap();
+    assert_eq!(err.operation().unwrap().operator(), Operator::Div);
+    assert_eq!(err.operation().unwrap().lhs(), 2.0);
+    assert!(matches!(
+        chained.into_result(),
+        Err(FloatError::DivisionByZero)
+    ));
+}
+
+#[test]
+fn test_conversions_between_kinds_and_detailed_errors() {
+    let detailed = NormalizedF32::new_detailed(7.0).unwrap_err();
+    assert_eq!(detailed, FloatError::AboveRange);
+    assert_eq!(FloatError::from(detailed), FloatError::AboveRange);
+
+    let bare = DetailedError::from(FloatError::NaN);
+    assert_eq!(bare.value(), None);
+    assert_eq!(bare.to_string(), "value is NaN (Not a Number)");
+
+    let from_kind = Checked::<FinF64>::from_result(Err(FloatError::NoneOperand));
+    assert_eq!(
+        from_kind.into_detailed_result(),
+        Err(DetailedError::from(FloatError::NoneOperand))
+    );
+}
+
+#[test]
+fn test_question_mark_converts_to_the_kind() {
+    fn parse(value: f64) -> Result<PositiveF64, FloatError> {
+        Ok(PositiveF64::new_detailed(value)?)
+    }
+
+    assert!(matches!(parse(-1.0), Err(FloatError::BelowRange)));
+}
@@ -0,0 +1,144 @@
+//! Tests for the classification of arithmetic failures
+//!
+//! Each failing operation must report why it failed: a zero divisor, an overflow, an
+//! underflow, a non-finite primitive operand, or a value outside the output range.
+
+#![expect(clippy::float_cmp)]
+
+use strict_num_extended::*;
+
+#[test]
+fn test_division_by_zero() {
+    let a = PositiveF64::new(1.0).unwrap();
+    let zero = NonNegativeF64::new(0.0).unwrap();
+    let negative_zero = FinF64::new(-0.0).unwrap();
+
+    assert_eq!((a / zero).unwrap_err(), FloatError::DivisionByZero);
+    assert_eq!((a / negative_zero).unwrap_err(), FloatError::DivisionByZero);
+    assert_eq!((a % zero).unwrap_err(), FloatError::DivisionByZero);
+    assert_eq!(a.rem_euclid(zero).unwrap_err(), FloatError::DivisionByZero);
+    assert_eq!(a.div_euclid(zero).unwrap_err(), FloatError::DivisionByZero);
+    assert_eq!((a / 0.0).unwrap_err(), FloatError::DivisionByZero);
+    assert_eq!((1.0 / zero).unwrap_err(), FloatError::DivisionByZero);
+
+    // Zero divided by zero is reported as division by zero, not as NaN
+    assert_eq!((zero / zero).unwrap_err(), FloatError::DivisionByZero);
+    let zero_by_zero = (Checked::new(zero) / zero).detailed_error().unwrap();
+    assert_eq!(zero_by_zero.kind(), FloatError::DivisionByZero);
+    assert_eq!(zero_by_zero.bound(), None);
+}
+
+#[test]
+fn test_overflow() {
+    let max = PositiveF64::new(f64::MAX).unwrap();
+    let min = NegativeF64::new(f64::MIN).unwrap();
+
+    assert_eq!((max + max).unwrap_err(), FloatError::Overflow);
+    let sum = (Checked::new(max) + max).detailed_error().unwrap();
+    assert_eq!(sum.value(), Some(f64::INFINITY));
+    assert_eq!((max * min).unwrap_err(), FloatError::Overflow);
+    let difference = (Checked::new(min) - max).detailed_error().unwrap();
+    assert_eq!(difference.value(), Some(f64::NEG_INFINITY));
+
+    let tiny = PositiveF64::new(1e-300).unwrap();
+    assert_eq!((max / tiny).unwrap_err(), FloatError::Overflow);
+    assert_eq!((max * 2.0).unwrap_err(), FloatError::Overflow);
+
+    let big = FinF32::new(f32::MAX).unwrap();
+    assert_eq!((big + big).unwrap_err(), FloatError::Overflow);
+}
+
+#[test]
+fn test_underflow() {
+    let tiny = PositiveF64::new(1e-200).unwrap();
+    let huge = PositiveF64::new(1e200).unwrap();
+
+    assert_eq!((tiny * tiny).unwrap_err(), FloatError::Underflow);
+    let product = (Checked::new(tiny) * tiny).detailed_error().unwrap();
+    assert_eq!(product.value(), Some(0.0));
+    assert_eq!(
+        product.bound(),
+        Some(ViolatedBound::Lower {
+            bound: 0.0,
+            inclusive: false
+        })
+    );
+    assert_eq!((tiny / huge).unwrap_err(), FloatError::Underflow);
+
+    let negative = NegativeF32::new(-1e-30).unwrap();
+    assert_eq!(
+        (negative * PositiveF32::new(1e-30).unwrap()).unwrap_err(),
+        FloatError::Underflow
+    );
+}
+
+#[test]
+fn test_invalid_operand() {
+    let a = FinF64::new(2.0).unwrap();
+
+    assert_eq!((a + f64::NAN).unwrap_err(), FloatError::InvalidOperand);
+    assert_eq!((f64::INFINITY - a).unwrap_err(), FloatError::InvalidOperand);
+    // The quotient would be a valid zero, but the operand itself is rejected
+    assert_eq!((a / f64::INFINITY).unwrap_err(), FloatError::InvalidOperand);
+    assert_eq!(
+        (f32::NEG_INFINITY * FinF32::new(1.0).unwrap()).unwrap_err(),
+        FloatError::InvalidOperand
+    );
+
+    let err = (Checked::new(a) + f64::NAN).detailed_error().unwrap();
+    assert_eq!(
+        err.to_string(),
+        "FinF64: 2.0 + NaN has a non-finite operand"
+    );
+}
+
+#[test]
+fn test_out_of_range_direction() {
+    let mut x = NormalizedF64::new(0.5).unwrap();
+    assert_eq!(x.try_add_assign(1.0), Err(FloatError::AboveRange));
+    assert_eq!(x.try_sub_assign(1.0), Err(FloatError::BelowRange));
+
+    assert_eq!(x.try_mul_assign(4.0), Err(FloatError::AboveRange));
+    assert_eq!(x.try_div_assign(-2.0), Err(FloatError::BelowRange));
+
+    let mut non_zero = NonZeroF64::new(2.0).unwrap();
+    assert_eq!(non_zero.try_mul_assign(0.0), Err(FloatError::ExcludedZero));
+
+    // The empty sum is zero, above every negative value
+    let no_negatives: [NegativeF64; 0] = [];
+    let sum: Result<NegativeF64, FloatError> = no_negatives.iter().sum();
+    assert_eq!(sum, Err(FloatError::AboveRange));
+
+    assert!(x.try_sub_assign(1.0).unwrap_err().is_out_of_range());
+    assert!(!x.try_div_assign(0.0).unwrap_err().is_out_of_range());
+}
+
+#[test]
+fn test_narrowing_conversion() {
+    let huge = PositiveF64::new(1e300).unwrap();
+    assert_eq!(huge.try_into_f32_type().unwrap_err(), FloatError::Overflow);
+
+    let tiny = PositiveF64::new(1e-300).unwrap();
+    assert_eq!(tiny.try_into_f32_type().unwrap_err(), FloatError::Underflow);
+
+    // Zero is a valid NonNegative value, so it does not underflow
+    let small = NonNegativeF64::new(1e-300).unwrap();
+    assert_eq!(small.try_into_f32_type().unwrap().get(), 0.0);
+}
+
+#[test]
+fn test_kinds_without_context_keep_their_messages() {
+    assert_eq!(FloatError::DivisionByZero.to_string(), "division by zero");
+    assert_eq!(
+        FloatError::Overflow.to_string(),
+        "arithmetic result overflowed to infinity"
+    );
+    assert_eq!(
+        FloatError::Underflow.to_string(),
+        "arithmetic result underflowed to zero"
+    );
+    assert_eq!(
+        FloatError::InvalidOperand.to_string(),
+        "operand is NaN or infinite"
+    );
+}
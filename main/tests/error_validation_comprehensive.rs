@@ -160,8 +160,14 @@ mod test_zero_variants {
         assert!(NonPositiveF64::new(0.0).is_ok());
 
         // NonZero should reject +0.0
-        assert!(matches!(NonZeroF32::new(0.0), Err(FloatError::OutOfRange)));
-        assert!(matches!(NonZeroF64::new(0.0), Err(FloatError::OutOfRange)));
+        assert!(matches!(
+            NonZeroF32::new(0.0),
+            Err(FloatError::ExcludedZero)
+        ));
+        assert!(matches!(
+            NonZeroF64::new(0.0),
+            Err(FloatError::ExcludedZero)
+        ));
     }
 
     #[test]
@@ -179,8 +185,14 @@ mod test_zero_variants {
         assert!(NonPositiveF64::new(-0.0).is_ok());
 
         // NonZero should reject -0.0 (since -0.0 == 0.0, and val != 0.0 is false)
-        assert!(matches!(NonZeroF32::new(-0.0), Err(FloatError::OutOfRange)));
-        assert!(matches!(NonZeroF64::new(-0.0), Err(FloatError::OutOfRange)));
+        assert!(matches!(
+            NonZeroF32::new(-0.0),
+            Err(FloatError::ExcludedZero)
+        ));
+        assert!(matches!(
+            NonZeroF64::new(-0.0),
+            Err(FloatError::ExcludedZero)
+        ));
     }
 
     #[test]
@@ -194,16 +206,16 @@ mod test_zero_variants {
     #[test]
     fn test_nonzero_rejects_both_zeros() {
         // Positive should reject both +0.0 and -0.0
-        assert!(matches!(PositiveF32::new(0.0), Err(FloatError::OutOfRange)));
+        assert!(matches!(PositiveF32::new(0.0), Err(FloatError::BelowRange)));
         assert!(matches!(
             PositiveF32::new(-0.0),
-            Err(FloatError::OutOfRange)
+            Err(FloatError::BelowRange)
         ));
 
-        assert!(matches!(NegativeF32::new(0.0), Err(FloatError::OutOfRange)));
+        assert!(matches!(NegativeF32::new(0.0), Err(FloatError::AboveRange)));
         assert!(matches!(
             NegativeF32::new(-0.0),
-            Err(FloatError::OutOfRange)
+            Err(FloatError::AboveRange)
         ));
     }
 }
@@ -219,7 +231,7 @@ mod test_division_by_zero_errors {
         let zero = NonNegativeF64::new(0.0).unwrap();
 
         let result = a / zero;
-        assert!(matches!(result, Err(FloatError::NaN)));
+        assert!(matches!(result, Err(FloatError::DivisionByZero)));
     }
 
     #[test]
@@ -229,7 +241,7 @@ mod test_division_by_zero_errors {
         let zero_neg = unsafe { NonNegativeF64::new_unchecked(-0.0) };
 
         let result = a / zero_neg;
-        assert!(matches!(result, Err(FloatError::NaN)));
+        assert!(matches!(result, Err(FloatError::DivisionByZero)));
     }
 
     #[test]
@@ -237,12 +249,15 @@ mod test_division_by_zero_errors {
         // FinF64
         let fin_a = FinF64::new(10.0).unwrap();
         let fin_zero = FinF64::new(0.0).unwrap();
-        assert!(matches!(fin_a / fin_zero, Err(FloatError::NaN)));
+        assert!(matches!(fin_a / fin_zero, Err(FloatError::DivisionByZero)));
 
         // NormalizedF64
         let norm_a = NormalizedF64::new(0.5).unwrap();
         let norm_zero = NormalizedF64::new(0.0).unwrap();
-        assert!(matches!(norm_a / norm_zero, Err(FloatError::NaN)));
+        assert!(matches!(
+            norm_a / norm_zero,
+            Err(FloatError::DivisionByZero)
+        ));
     }
 
     #[test]
@@ -284,24 +299,24 @@ mod test_precision_loss {
     fn test_range_overflow() {
         // Large numbers outside f32 range become infinity
         let huge = FinF64::new(1e40).unwrap();
-        // Conversion fails because infinity is rejected by FinF32::new()
+        // Conversion fails because the value overflows the f32 range
         assert!(huge.try_into_f32_type().is_err());
 
-        // Verify the returned error type is PosInf
+        // Verify the returned error type is Overflow
         let result = huge.try_into_f32_type();
-        assert!(matches!(result, Err(FloatError::PosInf)));
+        assert!(matches!(result, Err(FloatError::Overflow)));
     }
 
     #[test]
     fn test_range_underflow() {
         // Small numbers outside f32 range become negative infinity
         let tiny = FinF64::new(-1e40).unwrap();
-        // Conversion fails because negative infinity is rejected by FinF32::new()
+        // Conversion fails because the value overflows the f32 range
         assert!(tiny.try_into_f32_type().is_err());
 
-        // Verify the returned error type is NegInf
+        // Verify the returned error type is Overflow
         let result = tiny.try_into_f32_type();
-        assert!(matches!(result, Err(FloatError::NegInf)));
+        assert!(matches!(result, Err(FloatError::Overflow)));
     }
 
     #[test]
@@ -347,8 +362,8 @@ mod test_overflow_underflow {
         let b = NonNegativeF64::new(1e308).unwrap();
 
         let result = a + b;
-        // Should return PosInf error
-        assert!(matches!(result, Err(FloatError::PosInf)));
+        // Should return Overflow error
+        assert!(matches!(result, Err(FloatError::Overflow)));
     }
 
     #[test]
@@ -358,8 +373,8 @@ mod test_overflow_underflow {
 
         // NonPositive - NonNegative result type is Fin (deduced via operator overloading)
         let result = a - b;
-        // Should return NegInf error
-        assert!(matches!(result, Err(FloatError::NegInf)));
+        // Should return Overflow error
+        assert!(matches!(result, Err(FloatError::Overflow)));
     }
 
     #[test]
@@ -368,8 +383,8 @@ mod test_overflow_underflow {
         let b = NonNegativeF64::new(1e200).unwrap();
 
         let result = a * b;
-        // Should return PosInf error
-        assert!(matches!(result, Err(FloatError::PosInf)));
+        // Should return Overflow error
+        assert!(matches!(result, Err(FloatError::Overflow)));
     }
 
     #[test]
@@ -381,7 +396,7 @@ mod test_overflow_underflow {
 
         let result = a + b;
         // 2e38 + 2e38 = 4e38 > f32::MAX, should overflow
-        assert!(matches!(result, Err(FloatError::PosInf)));
+        assert!(matches!(result, Err(FloatError::Overflow)));
     }
 }
 
@@ -397,11 +412,11 @@ mod test_boundary_values {
         assert!(NormalizedF32::new(1.0).is_ok());
         assert!(matches!(
             NormalizedF32::new(-0.001),
-            Err(FloatError::OutOfRange)
+            Err(FloatError::BelowRange)
         ));
         assert!(matches!(
             NormalizedF32::new(1.001),
-            Err(FloatError::OutOfRange)
+            Err(FloatError::AboveRange)
         ));
     }
 
@@ -412,11 +427,11 @@ mod test_boundary_values {
         assert!(SymmetricF32::new(1.0).is_ok());
         assert!(matches!(
             SymmetricF32::new(-1.001),
-            Err(FloatError::OutOfRange)
+            Err(FloatError::BelowRange)
         ));
         assert!(matches!(
             SymmetricF32::new(1.001),
-            Err(FloatError::OutOfRange)
+            Err(FloatError::AboveRange)
         ));
     }
 
@@ -436,7 +451,7 @@ mod test_boundary_values {
         assert!(NonNegativeF32::new(f32::MAX).is_ok());
         assert!(matches!(
             NonNegativeF32::new(-0.001),
-            Err(FloatError::OutOfRange)
+            Err(FloatError::BelowRange)
         ));
     }
 
@@ -447,7 +462,7 @@ mod test_boundary_values {
         assert!(NonPositiveF32::new(f32::MIN).is_ok());
         assert!(matches!(
             NonPositiveF32::new(0.001),
-            Err(FloatError::OutOfRange)
+            Err(FloatError::AboveRange)
         ));
     }
 
@@ -456,8 +471,14 @@ mod test_boundary_values {
         // NonZero: (-∞, 0.0) ∪ (0.0, +∞)
         assert!(NonZeroF32::new(0.001).is_ok());
         assert!(NonZeroF32::new(-0.001).is_ok());
-        assert!(matches!(NonZeroF32::new(0.0), Err(FloatError::OutOfRange)));
-        assert!(matches!(NonZeroF32::new(-0.0), Err(FloatError::OutOfRange)));
+        assert!(matches!(
+            NonZeroF32::new(0.0),
+            Err(FloatError::ExcludedZero)
+        ));
+        assert!(matches!(
+            NonZeroF32::new(-0.0),
+            Err(FloatError::ExcludedZero)
+        ));
     }
 }
 
@@ -481,8 +502,16 @@ mod test_error_messages {
             "value is negative infinity"
         );
         assert_eq!(
-            format!("{}", FloatError::OutOfRange),
-            "value is outside the valid range for this type"
+            format!("{}", FloatError::BelowRange),
+            "value is below the valid range for this type"
+        );
+        assert_eq!(
+            format!("{}", FloatError::AboveRange),
+            "value is above the valid range for this type"
+        );
+        assert_eq!(
+            format!("{}", FloatError::ExcludedZero),
+            "value is zero, which this type excludes"
         );
         assert_eq!(
             format!("{}", FloatError::NoneOperand),
@@ -495,7 +524,9 @@ mod test_error_messages {
         assert!(format!("{:?}", FloatError::NaN).contains("NaN"));
         assert!(format!("{:?}", FloatError::PosInf).contains("PosInf"));
         assert!(format!("{:?}", FloatError::NegInf).contains("NegInf"));
-        assert!(format!("{:?}", FloatError::OutOfRange).contains("OutOfRange"));
+        assert!(format!("{:?}", FloatError::BelowRange).contains("BelowRange"));
+        assert!(format!("{:?}", FloatError::AboveRange).contains("AboveRange"));
+        assert!(format!("{:?}", FloatError::ExcludedZero).contains("ExcludedZero"));
         assert!(format!("{:?}", FloatError::NoneOperand).contains("NoneOperand"));
     }
 }
@@ -553,7 +584,7 @@ mod test_tryfrom_consistency {
         let result_try: Result<NonNegativeF32, _> = NonNegativeF32::try_from(-1.0f32);
 
         match (result_new, result_try) {
-            (Err(FloatError::OutOfRange), Err(FloatError::OutOfRange)) => {}
+            (Err(FloatError::BelowRange), Err(FloatError::BelowRange)) => {}
             _ => panic!("TryFrom and new() should return the same error type"),
         }
     }
@@ -0,0 +1,135 @@
+//! Tests for `max`, `min`, `clamp` and `clamp_to`
+//!
+//! The output of `max` and `min` must be the tightest declared type containing every
+//! possible result, and clamping must never fail.
+
+#![expect(clippy::float_cmp)]
+
+use strict_num_extended::*;
+
+#[test]
+fn test_max_infers_from_bounds() {
+    let positive = PositiveF64::new(0.5).unwrap();
+    let fin = FinF64::new(-3.0).unwrap();
+
+    let larger: PositiveF64 = positive.max(fin);
+    assert_eq!(larger.get(), 0.5);
+    let commuted: PositiveF64 = fin.max(positive);
+    assert_eq!(commuted.get(), 0.5);
+
+    // (0, +∞) from a Positive and a NonNegative operand
+    let zero = NonNegativeF64::new(0.0).unwrap();
+    let nonzero: PositiveF64 = zero.max(positive);
+    assert_eq!(nonzero.get(), 0.5);
+
+    // [0, 1] from Normalized and NegativeNormalized
+    let weight = NormalizedF32::new(0.25).unwrap();
+    let negative = NegativeNormalizedF32::new(-1.0).unwrap();
+    let normalized: NormalizedF32 = weight.max(negative);
+    assert_eq!(normalized.get(), 0.25);
+}
+
+#[test]
+fn test_min_infers_from_bounds() {
+    let negative = NegativeF64::new(-1.0).unwrap();
+    let fin = FinF64::new(2.0).unwrap();
+
+    let smaller: NegativeF64 = negative.min(fin);
+    assert_eq!(smaller.get(), -1.0);
+
+    // [-1, 1] from Normalized and Symmetric
+    let weight = NormalizedF64::new(0.5).unwrap();
+    let sym = SymmetricF64::new(-0.5).unwrap();
+    let symmetric: SymmetricF64 = weight.min(sym);
+    assert_eq!(symmetric.get(), -0.5);
+
+    // [0, 1] from Normalized and Positive
+    let large = PositiveF64::new(10.0).unwrap();
+    let bounded: NormalizedF64 = large.min(weight);
+    assert_eq!(bounded.get(), 0.5);
+
+    // The minimum of two non-zero values is non-zero
+    let a = NonZeroF64::new(-2.0).unwrap();
+    let b = PositiveF64::new(3.0).unwrap();
+    let nonzero: NonZeroF64 = a.min(b);
+    assert_eq!(nonzero.get(), -2.0);
+}
+
+#[test]
+fn test_same_type_keeps_type() {
+    let a = NormalizedF64::new(0.2).unwrap();
+    let b = NormalizedF64::new(0.7).unwrap();
+    let max: NormalizedF64 = a.max(b);
+    let min: NormalizedF64 = a.min(b);
+    assert_eq!((min.get(), max.get()), (0.2, 0.7));
+
+    let c = FinF32::new(1.0).unwrap();
+    let d: FinF32 = c.max(FinF32::new(2.0).unwrap());
+    assert_eq!(d.get(), 2.0);
+}
+
+#[test]
+fn test_clamp() {
+    let lo = NormalizedF64::new(0.25).unwrap();
+    let hi = NormalizedF64::new(0.75).unwrap();
+
+    let above: NormalizedF64 = FinF64::new(3.0).unwrap().clamp(lo, hi);
+    assert_eq!(above.get(), 0.75);
+    let below: NormalizedF64 = FinF64::new(-3.0).unwrap().clamp(lo, hi);
+    assert_eq!(below.get(), 0.25);
+    let inside: NormalizedF64 = FinF64::new(0.5).unwrap().clamp(lo, hi);
+    assert_eq!(inside.get(), 0.5);
+
+    // Typed bounds give the tightest type: [Positive, NonZero] can still be negative
+    let floor = PositiveF64::new(1.0).unwrap();
+    let ceiling = PositiveF64::new(4.0).unwrap();
+    let positive: PositiveF64 = FinF64::new(-8.0).unwrap().clamp(floor, ceiling);
+    assert_eq!(positive.get(), 1.0);
+
+    // lo > hi gives hi instead of panicking
+    let inverted: NormalizedF64 = FinF64::new(0.5).unwrap().clamp(hi, lo);
+    assert_eq!(inverted.get(), 0.25);
+}
+
+#[test]
+fn test_clamp_to() {
+    let gain = FinF64::new(1.5).unwrap();
+    assert_eq!(gain.clamp_to::<Normalized>().get(), 1.0);
+
+    let normalized: NormalizedF64 = FinF64::new(-0.5).unwrap().clamp_to();
+    assert_eq!(normalized.get(), 0.0);
+
+    // An exclusive zero bound clamps to the smallest normal value beyond it
+    let positive: PositiveF64 = FinF64::new(-2.0).unwrap().clamp_to();
+    assert_eq!(positive.get(), f64::MIN_POSITIVE);
+
+    let max = FinF32::new(f32::MAX).unwrap();
+    assert_eq!(max.clamp_to::<Symmetric>().get(), 1.0);
+    assert_eq!(max.clamp_to::<Fin>().get(), f32::MAX);
+}
+
+#[test]
+fn test_custom_constraint_extremum() {
+    strict_num_extended::define_constraints!([
+        (Probability, [">= 0.0", "<= 1.0"]),
+        (Unit, ["> -1.0", "< 1.0"]),
+    ]);
+
+    // Same bounds as Normalized, but the operand type is preferred
+    let p = ProbabilityF64::new(0.3).unwrap();
+    let q = ProbabilityF64::new(0.6).unwrap();
+    let larger: ProbabilityF64 = p.max(q);
+    assert_eq!(larger.get(), 0.6);
+
+    // [0, 1] from (-1, 1) and Probability
+    let unit = UnitF64::new(-0.5).unwrap();
+    let probability: ProbabilityF64 = unit.max(p);
+    assert_eq!(probability.get(), 0.3);
+
+    // (-1, 1] has no exact type, so the tightest containing one is used
+    let sym: SymmetricF64 = unit.max(SymmetricF64::new(-1.0).unwrap());
+    assert_eq!(sym.get(), -0.5);
+
+    let clamped: UnitF64 = FinF64::new(5.0).unwrap().clamp_to();
+    assert!(clamped.get() < 1.0);
+}
@@ -1269,28 +1269,28 @@ mod test_float_error {
     fn test_nonnnegative_constraint_error() {
         let result = NonNegativeF32::new(-1.0);
         assert!(result.is_err());
-        assert!(matches!(result, Err(FloatError::OutOfRange)));
+        assert!(matches!(result, Err(FloatError::BelowRange)));
     }
 
     #[test]
     fn test_nonzero_constraint_error() {
         let result = NonZeroF32::new(0.0);
         assert!(result.is_err());
-        assert!(matches!(result, Err(FloatError::OutOfRange)));
+        assert!(matches!(result, Err(FloatError::ExcludedZero)));
     }
 
     #[test]
     fn test_nonnpositive_constraint_error() {
         let result = NonPositiveF32::new(1.0);
         assert!(result.is_err());
-        assert!(matches!(result, Err(FloatError::OutOfRange)));
+        assert!(matches!(result, Err(FloatError::AboveRange)));
     }
 
     #[test]
     fn test_normalized_constraint_error() {
         let result = SymmetricF32::new(2.0);
         assert!(result.is_err());
-        assert!(matches!(result, Err(FloatError::OutOfRange)));
+        assert!(matches!(result, Err(FloatError::AboveRange)));
     }
 
     #[test]
@@ -1304,10 +1304,10 @@ mod test_float_error {
         let err = FloatError::NegInf;
         assert_eq!(format!("{}", err), "value is negative infinity");
 
-        let err = FloatError::OutOfRange;
+        let err = FloatError::BelowRange;
         assert_eq!(
             format!("{}", err),
-            "value is outside the valid range for this type"
+            "value is below the valid range for this type"
         );
 
         let err = FloatError::NoneOperand;
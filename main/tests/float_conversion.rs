@@ -49,13 +49,13 @@ fn test_try_into_f32_type_precision_loss() {
 
 #[test]
 fn test_try_into_f32_type_range_overflow() {
-    // Range overflow results in infinity, which is rejected
+    // Range overflow results in infinity, which is reported as an overflow
     let value_f64 = FinF64::new_const(1e40);
     let result: Result<FinF32, FloatError> = value_f64.try_into_f32_type();
     assert!(result.is_err(), "Should fail due to infinity");
     assert!(
-        matches!(result, Err(FloatError::PosInf)),
-        "Should be PosInf error"
+        matches!(result, Err(FloatError::Overflow)),
+        "Should be Overflow error"
     );
 }
 
@@ -0,0 +1,64 @@
+//! Tests for per-width bounds and arithmetic inference
+//!
+//! f32 and f64 types share their conditions, but bounds are rounded and
+//! arithmetic safety is judged separately for each width.
+
+#![expect(clippy::float_cmp)]
+#![allow(clippy::manual_range_contains)]
+
+mod wide {
+    strict_num_extended_macros::generate_finite_float_types!([
+        (Fin, []),
+        (NonNegative, [">= 0.0"]),
+        (Symmetric, [">= -1.0", "<= 1.0"]),
+        (Normalized, [">= 0.0", "<= 1.0"]),
+        (Positive, ["> 0.0"]),
+        (Large, [">= 0.0", "<= 1e30"]),
+        (Tenth, [">= 0.0", "<= 0.1"]),
+    ]);
+}
+
+use wide::*;
+
+#[test]
+fn test_multiplication_safe_in_f64_only() {
+    // 1e20 * 1e20 fits in f64 but overflows f32
+    let a = LargeF64::new(1e20).unwrap();
+    let wide_product: NonNegativeF64 = a * a;
+    assert_eq!(wide_product.get(), 1e40);
+
+    let b = LargeF32::new(1e20).unwrap();
+    let narrow_product: Result<NonNegativeF32, FloatError> = b * b;
+    assert!(narrow_product.is_err());
+
+    let c = LargeF32::new(2.0).unwrap();
+    assert_eq!((c * c).unwrap().get(), 4.0);
+}
+
+#[test]
+fn test_literal_bound_rounded_per_width() {
+    // 0.1f32 is slightly above 0.1f64 but matches the f32 bound exactly
+    assert!(TenthF32::new(0.1).is_ok());
+    assert!(TenthF64::new(0.1).is_ok());
+    assert!(TenthF64::new(f64::from(0.1f32)).is_err());
+}
+
+#[test]
+fn test_outward_rounded_f32_bound_widens_fallibly() {
+    let tenth = TenthF32::new(0.1).unwrap();
+    assert_eq!(TenthF64::try_from(tenth), Err(FloatError::AboveRange));
+
+    let small = TenthF32::new(0.05).unwrap();
+    assert_eq!(TenthF64::try_from(small).unwrap().get(), f64::from(0.05f32));
+}
+
+#[test]
+fn test_exact_bounds_widen_infallibly() {
+    let half = NormalizedF32::new(0.5).unwrap();
+    let widened: NormalizedF64 = half.into();
+    assert_eq!(widened.get(), 0.5);
+
+    // 1e30f32 rounds above 1e30, so this bound widens fallibly as well
+    let large = LargeF32::new(1e30).unwrap();
+    assert!(LargeF64::try_from(large).is_err());
+}
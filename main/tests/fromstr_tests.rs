@@ -82,7 +82,7 @@ mod test_constraint_validation {
         let result: Result<NonNegativeF32, _> = "-1.0".parse();
         assert!(matches!(
             result,
-            Err(ParseFloatError::ValidationFailed(FloatError::OutOfRange))
+            Err(ParseFloatError::ValidationFailed(FloatError::BelowRange))
         ));
     }
 
@@ -91,7 +91,7 @@ mod test_constraint_validation {
         let result: Result<PositiveF32, _> = "0.0".parse();
         assert!(matches!(
             result,
-            Err(ParseFloatError::ValidationFailed(FloatError::OutOfRange))
+            Err(ParseFloatError::ValidationFailed(FloatError::BelowRange))
         ));
     }
 
@@ -100,7 +100,7 @@ mod test_constraint_validation {
         let result: Result<NegativeF32, _> = "0.0".parse();
         assert!(matches!(
             result,
-            Err(ParseFloatError::ValidationFailed(FloatError::OutOfRange))
+            Err(ParseFloatError::ValidationFailed(FloatError::AboveRange))
         ));
     }
 
@@ -109,7 +109,7 @@ mod test_constraint_validation {
         let result: Result<NormalizedF64, _> = "1.5".parse();
         assert!(matches!(
             result,
-            Err(ParseFloatError::ValidationFailed(FloatError::OutOfRange))
+            Err(ParseFloatError::ValidationFailed(FloatError::AboveRange))
         ));
     }
 
@@ -118,7 +118,7 @@ mod test_constraint_validation {
         let result: Result<NormalizedF32, _> = "-0.5".parse();
         assert!(matches!(
             result,
-            Err(ParseFloatError::ValidationFailed(FloatError::OutOfRange))
+            Err(ParseFloatError::ValidationFailed(FloatError::BelowRange))
         ));
     }
 
@@ -127,7 +127,7 @@ mod test_constraint_validation {
         let result: Result<NonZeroF32, _> = "0.0".parse();
         assert!(matches!(
             result,
-            Err(ParseFloatError::ValidationFailed(FloatError::OutOfRange))
+            Err(ParseFloatError::ValidationFailed(FloatError::ExcludedZero))
         ));
     }
 
@@ -136,7 +136,7 @@ mod test_constraint_validation {
         let result: Result<PositiveF64, _> = "-1.0".parse();
         assert!(matches!(
             result,
-            Err(ParseFloatError::ValidationFailed(FloatError::OutOfRange))
+            Err(ParseFloatError::ValidationFailed(FloatError::BelowRange))
         ));
     }
 
@@ -145,7 +145,7 @@ mod test_constraint_validation {
         let result: Result<SymmetricF32, _> = "1.5".parse();
         assert!(matches!(
             result,
-            Err(ParseFloatError::ValidationFailed(FloatError::OutOfRange))
+            Err(ParseFloatError::ValidationFailed(FloatError::AboveRange))
         ));
     }
 }
@@ -221,7 +221,7 @@ mod test_error_messages {
 
         let err = result.unwrap_err();
         let msg = format!("{err}");
-        assert!(msg.contains("outside the valid range"));
+        assert!(msg.contains("below the valid range"));
     }
 
     #[test]
@@ -231,7 +231,7 @@ mod test_error_messages {
 
         let err = result.unwrap_err();
         let msg = format!("{err}");
-        assert!(msg.contains("outside the valid range"));
+        assert!(msg.contains("below the valid range"));
     }
 
     #[test]
@@ -241,7 +241,7 @@ mod test_error_messages {
 
         let err = result.unwrap_err();
         let msg = format!("{err}");
-        assert!(msg.contains("outside the valid range"));
+        assert!(msg.contains("above the valid range"));
     }
 
     #[test]
@@ -297,30 +297,30 @@ mod test_error_preserves_input {
     #[test]
     fn test_validation_failed_wraps_float_error() {
         let result: Result<NonNegativeF32, _> = "-1.0".parse();
-        if let Err(ParseFloatError::ValidationFailed(FloatError::OutOfRange)) = result {
-            // Correctly wrapped OutOfRange error
+        if let Err(ParseFloatError::ValidationFailed(FloatError::BelowRange)) = result {
+            // Correctly wrapped BelowRange error
         } else {
-            panic!("Expected ValidationFailed(OutOfRange) error");
+            panic!("Expected ValidationFailed(BelowRange) error");
         }
     }
 
     #[test]
     fn test_positive_zero_validation_failed() {
         let result: Result<PositiveF32, _> = "0.0".parse();
-        if let Err(ParseFloatError::ValidationFailed(FloatError::OutOfRange)) = result {
+        if let Err(ParseFloatError::ValidationFailed(FloatError::BelowRange)) = result {
             // Positive should reject 0.0
         } else {
-            panic!("Expected ValidationFailed(OutOfRange) error");
+            panic!("Expected ValidationFailed(BelowRange) error");
         }
     }
 
     #[test]
     fn test_negative_zero_validation_failed() {
         let result: Result<NegativeF32, _> = "0.0".parse();
-        if let Err(ParseFloatError::ValidationFailed(FloatError::OutOfRange)) = result {
+        if let Err(ParseFloatError::ValidationFailed(FloatError::AboveRange)) = result {
             // Negative should reject 0.0
         } else {
-            panic!("Expected ValidationFailed(OutOfRange) error");
+            panic!("Expected ValidationFailed(AboveRange) error");
         }
     }
 
@@ -0,0 +1,124 @@
+//! Tests for arithmetic with integer operands
+//!
+//! Integers must act as the constraint implied by their sign, convert exactly when
+//! they can, and report an inexact conversion as `PrecisionLoss`.
+
+#![expect(clippy::float_cmp, clippy::op_ref)]
+
+use core::num::{NonZeroI32, NonZeroU32, NonZeroU64, NonZeroUsize};
+
+use strict_num_extended::*;
+
+#[test]
+fn test_unsigned_acts_as_non_negative() {
+    let mean = NormalizedF64::new(0.5).unwrap();
+    let samples: u32 = 4;
+
+    // Normalized * NonNegative
+    let total: Result<NonNegativeF64, FloatError> = mean * samples;
+    assert_eq!(total.unwrap().get(), 2.0);
+
+    let positive = PositiveF64::new(3.0).unwrap();
+    let sum: NonNegativeF64 = (positive + 2_u8).unwrap();
+    assert_eq!(sum.get(), 5.0);
+
+    let offset: Result<FinF32, FloatError> = 10_u16 - FinF32::new(2.5).unwrap();
+    assert_eq!(offset.unwrap().get(), 7.5);
+}
+
+#[test]
+fn test_signed_acts_as_fin() {
+    let value = PositiveF64::new(1.5).unwrap();
+
+    let scaled: Result<FinF64, FloatError> = value * -2_i32;
+    assert_eq!(scaled.unwrap().get(), -3.0);
+
+    let shifted: Result<FinF32, FloatError> = -3_i8 + NormalizedF32::new(1.0).unwrap();
+    assert_eq!(shifted.unwrap().get(), -2.0);
+
+    let remainder = FinF64::new(-7.0).unwrap().rem_euclid(3_i16).unwrap();
+    assert_eq!(remainder.get(), 2.0);
+}
+
+#[test]
+fn test_non_zero_integers() {
+    let count = NonZeroU32::new(4).unwrap();
+    let total = PositiveF64::new(10.0).unwrap();
+
+    // NonZeroU32 acts as Positive
+    let mean: Result<PositiveF64, FloatError> = total / count;
+    assert_eq!(mean.unwrap().get(), 2.5);
+
+    let sum = FinF64::new(-6.0).unwrap();
+    let average: FinF64 = (sum / NonZeroUsize::new(3).unwrap()).unwrap();
+    assert_eq!(average.get(), -2.0);
+
+    let factor = NonZeroI32::new(-2).unwrap();
+    let product: Result<NonZeroF64, FloatError> = NonZeroF64::new(1.5).unwrap() * factor;
+    assert_eq!(product.unwrap().get(), -3.0);
+
+    let err = (FinF64::new(1.0).unwrap() / 0_u32).unwrap_err();
+    assert_eq!(err, FloatError::DivisionByZero);
+}
+
+#[test]
+fn test_precision_loss() {
+    let one = FinF64::new(1.0).unwrap();
+
+    // 2^53 converts exactly, 2^53 + 1 does not
+    let exact = (one + (1_u64 << 53)).unwrap();
+    assert_eq!(exact.get(), 2.0_f64.powi(53));
+
+    assert_eq!(one + ((1_u64 << 53) + 1), Err(FloatError::PrecisionLoss));
+
+    // The maximum rounds up to 2^64, which must not pass as exact
+    assert_eq!(one * u64::MAX, Err(FloatError::PrecisionLoss));
+    assert_eq!((i64::MIN * one).unwrap().get(), -(2.0_f64.powi(63)));
+
+    // i32 is exact in f64 but not in f32
+    let small = FinF32::new(1.0).unwrap();
+    assert_eq!((small * (1_i32 << 24)).unwrap().get(), 2.0_f32.powi(24));
+    assert_eq!(small * ((1_i32 << 24) + 1), Err(FloatError::PrecisionLoss));
+    assert_eq!(
+        small * NonZeroU64::new(u64::MAX).unwrap(),
+        Err(FloatError::PrecisionLoss)
+    );
+}
+
+#[test]
+fn test_exact_conversions_keep_safe_outputs() {
+    // NonNegative - NonNegative is safe, so a u32 operand needs no validation
+    let level = NonNegativeF64::new(0.25).unwrap();
+    let difference: FinF64 = level - 3_u32;
+    assert_eq!(difference.get(), -2.75);
+
+    // A wider integer may be inexact, so the same operation is fallible
+    let wide: Result<FinF64, FloatError> = level - 3_u64;
+    assert_eq!(wide.unwrap().get(), -2.75);
+}
+
+#[test]
+fn test_references_and_try_assign() {
+    let counts = [2_u32, 3, 5];
+    let scale = PositiveF64::new(0.5).unwrap();
+
+    let scaled: Vec<f64> = counts.iter().map(|n| (scale * n).unwrap().get()).collect();
+    assert_eq!(scaled, [1.0, 1.5, 2.5]);
+    assert_eq!((&counts[0] * &scale).unwrap().get(), 1.0);
+
+    let mut total = PositiveF64::new(1.0).unwrap();
+    total.try_add_assign(2_u8).unwrap();
+    total.try_mul_assign(counts.get(1).unwrap()).unwrap();
+    assert_eq!(total.get(), 9.0);
+    assert!(total.try_mul_assign(-1_i32).is_err());
+    assert_eq!(total.get(), 9.0);
+}
+
+#[test]
+fn test_custom_constraint_with_integers() {
+    strict_num_extended::define_constraints!([(Unit, ["> -1.0", "< 1.0"])]);
+
+    let unit = UnitF64::new(0.5).unwrap();
+    let product: Result<FinF64, FloatError> = unit * 4_u32;
+    assert_eq!(product.unwrap().get(), 2.0);
+}
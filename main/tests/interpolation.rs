@@ -0,0 +1,174 @@
+//! Tests for `lerp`, `inverse_lerp` and the easing curves
+//!
+//! `lerp` must return the tightest type containing both endpoints and never leave
+//! the interval between them, and every easing curve must stay in [0, 1].
+
+#![expect(clippy::float_cmp)]
+
+use strict_num_extended::*;
+
+#[test]
+fn test_lerp_endpoints_are_exact() {
+    let a = FinF64::new(-3.7).unwrap();
+    let b = FinF64::new(12.1).unwrap();
+
+    let start: FinF64 = a.lerp(b, NormalizedF64::new(0.0).unwrap());
+    let end: FinF64 = a.lerp(b, NormalizedF64::new(1.0).unwrap());
+    assert_eq!(start.get(), -3.7);
+    assert_eq!(end.get(), 12.1);
+
+    let mid: FinF32 = FinF32::new(2.0)
+        .unwrap()
+        .lerp(FinF32::new(4.0).unwrap(), NormalizedF32::new(0.5).unwrap());
+    assert_eq!(mid.get(), 3.0);
+}
+
+#[test]
+fn test_lerp_infers_from_bounds() {
+    let t = NormalizedF64::new(0.5).unwrap();
+
+    // Same bounded type on both sides
+    let a = NormalizedF64::new(0.2).unwrap();
+    let b = NormalizedF64::new(0.8).unwrap();
+    let normalized: NormalizedF64 = a.lerp(b, t);
+    assert!((normalized.get() - 0.5).abs() < 1e-12);
+
+    // [-1, 1] from NegativeNormalized and Normalized
+    let neg = NegativeNormalizedF64::new(-1.0).unwrap();
+    let sym: SymmetricF64 = neg.lerp(NormalizedF64::new(1.0).unwrap(), t);
+    assert_eq!(sym.get(), 0.0);
+
+    // Values between two positives stay positive, between opposite signs they don't
+    let positive: PositiveF64 = PositiveF64::new(1.0)
+        .unwrap()
+        .lerp(PositiveF64::new(3.0).unwrap(), t);
+    assert_eq!(positive.get(), 2.0);
+    let crossing: FinF64 = NegativeF64::new(-1.0)
+        .unwrap()
+        .lerp(PositiveF64::new(1.0).unwrap(), t);
+    assert_eq!(crossing.get(), 0.0);
+
+    // Angles stay within [-PI, PI]
+    let angle: PiBoundedF64 = PiBoundedF64::new(-1.0)
+        .unwrap()
+        .lerp(NormalizedF64::new(1.0).unwrap(), t);
+    assert_eq!(angle.get(), 0.0);
+}
+
+#[test]
+fn test_lerp_stays_between_endpoints() {
+    // Products that round to zero must not leave the Positive range
+    let tiny = PositiveF64::new(5e-324).unwrap();
+    let result: PositiveF64 = tiny.lerp(tiny, NormalizedF64::new(0.3).unwrap());
+    assert_eq!(result.get(), 5e-324);
+
+    // The widest range does not overflow
+    let min = FinF64::new(f64::MIN).unwrap();
+    let max = FinF64::new(f64::MAX).unwrap();
+    let mid: FinF64 = min.lerp(max, NormalizedF64::new(0.5).unwrap());
+    assert_eq!(mid.get(), 0.0);
+
+    let top: FinF64 = max.lerp(max, NormalizedF64::new(0.7).unwrap());
+    assert_eq!(top.get(), f64::MAX);
+}
+
+#[test]
+fn test_inverse_lerp() {
+    let start = FinF64::new(10.0).unwrap();
+    let end = FinF64::new(20.0).unwrap();
+
+    let t: NormalizedF64 = start.inverse_lerp(end, FinF64::new(12.5).unwrap()).unwrap();
+    assert_eq!(t.get(), 0.25);
+    let reversed = end.inverse_lerp(start, FinF64::new(12.5).unwrap()).unwrap();
+    assert_eq!(reversed.get(), 0.75);
+
+    let outside = start.inverse_lerp(end, FinF64::new(30.0).unwrap());
+    assert_eq!(outside, Err(FloatError::AboveRange));
+
+    let degenerate = start.inverse_lerp(start, FinF64::new(10.0).unwrap());
+    assert_eq!(degenerate, Err(FloatError::DivisionByZero));
+
+    // Differences beyond f64::MAX are still resolved
+    let min = FinF64::new(f64::MIN).unwrap();
+    let max = FinF64::new(f64::MAX).unwrap();
+    let half = min.inverse_lerp(max, FinF64::new(0.0).unwrap()).unwrap();
+    assert_eq!(half.get(), 0.5);
+}
+
+#[test]
+fn test_inverse_lerp_near_max() {
+    let max = FinF64::new(f64::MAX).unwrap();
+    let half_max = FinF64::new(f64::MAX / 2.0).unwrap();
+
+    let t = half_max
+        .inverse_lerp(max, FinF64::new(f64::MAX * 0.75).unwrap())
+        .unwrap();
+    assert!((t.get() - 0.5).abs() < 1e-15);
+
+    // `value - start` overflows, which is still reported as out of range
+    let far = FinF64::new(f64::MIN).unwrap();
+    assert_eq!(max.inverse_lerp(half_max, far), Err(FloatError::BelowRange));
+    assert_eq!(
+        far.inverse_lerp(FinF64::new(-1.0).unwrap(), max),
+        Err(FloatError::AboveRange)
+    );
+}
+
+#[test]
+fn test_easing_curves_fix_endpoints() {
+    let curves: [fn(NormalizedF64) -> NormalizedF64; 11] = [
+        easing::smoothstep,
+        easing::smootherstep,
+        easing::ease_in_quad,
+        easing::ease_out_quad,
+        easing::ease_in_out_quad,
+        easing::ease_in_cubic,
+        easing::ease_out_cubic,
+        easing::ease_in_out_cubic,
+        easing::ease_in_sine,
+        easing::ease_out_sine,
+        easing::ease_in_out_sine,
+    ];
+    let zero = NormalizedF64::new(0.0).unwrap();
+    let one = NormalizedF64::new(1.0).unwrap();
+
+    for curve in curves {
+        assert_eq!(curve(zero).get(), 0.0);
+        assert_eq!(curve(one).get(), 1.0);
+        for i in 0..=100 {
+            let t = NormalizedF64::new(f64::from(i) / 100.0).unwrap();
+            let value = curve(t).get();
+            assert!((0.0..=1.0).contains(&value));
+        }
+    }
+}
+
+#[test]
+fn test_easing_values() {
+    let quarter = NormalizedF32::new(0.25).unwrap();
+    assert_eq!(easing::smoothstep(quarter).get(), 0.156_25);
+    assert_eq!(easing::ease_in_quad(quarter).get(), 0.0625);
+    assert_eq!(easing::ease_out_quad(quarter).get(), 0.4375);
+    assert_eq!(easing::ease_in_out_cubic(quarter).get(), 0.0625);
+
+    let half = NormalizedF64::new(0.5).unwrap();
+    assert_eq!(easing::smootherstep(half).get(), 0.5);
+    assert_eq!(easing::ease_in_out_quad(half).get(), 0.5);
+    assert!((easing::ease_in_out_sine(half).get() - 0.5).abs() < 1e-15);
+}
+
+#[test]
+fn test_custom_constraint_lerp() {
+    strict_num_extended::define_constraints!([(Percent, [">= 0.0", "<= 100.0"])]);
+
+    let low = PercentF64::new(20.0).unwrap();
+    let high = PercentF64::new(60.0).unwrap();
+    let mid: PercentF64 = low.lerp(high, NormalizedF64::new(0.25).unwrap());
+    assert_eq!(mid.get(), 30.0);
+
+    // [0, 100] from Percent and Normalized
+    let mixed: PercentF64 = NormalizedF64::new(1.0)
+        .unwrap()
+        .lerp(high, NormalizedF64::new(0.0).unwrap());
+    assert_eq!(mixed.get(), 1.0);
+}
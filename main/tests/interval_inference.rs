@@ -0,0 +1,87 @@
+//! Tests for the `interval-inference` feature
+//!
+//! With the feature, the bounded operations must never fail for operands whose results
+//! lie in a finite interval, and return an operand type or a type unbounded on one side
+//! containing that interval. The operators must keep their default inference.
+
+#![cfg(feature = "interval-inference")]
+#![expect(clippy::float_cmp)]
+
+use strict_num_extended::*;
+
+#[test]
+fn test_bounded_add_and_sub_are_safe() {
+    let a = NormalizedF64::new(0.75).unwrap();
+    let b = NormalizedF64::new(1.0).unwrap();
+
+    // [0, 2] fits no operand type, so the sign type is picked
+    let sum: NonNegativeF64 = a.add_bounded(b);
+    assert_eq!(sum.get(), 1.75);
+
+    let difference: FinF64 = a.sub_bounded(b);
+    assert_eq!(difference.get(), -0.25);
+
+    let n = NegativeNormalizedF32::new(-1.0).unwrap();
+    let below: NonPositiveF32 = n.add_bounded(n);
+    assert_eq!(below.get(), -2.0);
+
+    let angle = TauBoundedF64::new(3.0).unwrap();
+    let turns: NonNegativeF64 = angle.add_bounded(angle);
+    assert_eq!(turns.get(), 6.0);
+}
+
+#[test]
+fn test_bounded_mul_keeps_operand_types() {
+    let a = NormalizedF64::new(0.5).unwrap();
+    let s = SymmetricF64::new(-0.5).unwrap();
+
+    let squared: NormalizedF64 = a.mul_bounded(a);
+    assert_eq!(squared.get(), 0.25);
+
+    let product: SymmetricF64 = a.mul_bounded(s);
+    assert_eq!(product.get(), -0.25);
+
+    let angle = PiBoundedF32::new(-3.0).unwrap();
+    let scaled: PiBoundedF32 = angle.mul_bounded(NormalizedF32::new(0.5).unwrap());
+    assert_eq!(scaled.get(), -1.5);
+
+    let pi = PiBoundedF64::new(-3.0).unwrap();
+    let area: FinF64 = pi.mul_bounded(pi);
+    assert_eq!(area.get(), 9.0);
+}
+
+#[test]
+fn test_operators_are_unchanged() {
+    let a = NormalizedF64::new(0.75).unwrap();
+    let b = NormalizedF64::new(1.0).unwrap();
+    let bounded: Result<NonNegativeF64, FloatError> = a + b;
+    assert_eq!(bounded.unwrap().get(), 1.75);
+
+    let p = PositiveF64::new(1.0).unwrap();
+    let sum: Result<PositiveF64, FloatError> = p + p;
+    assert_eq!(sum.unwrap().get(), 2.0);
+}
+
+#[test]
+fn test_custom_bounded_operands() {
+    strict_num_extended::define_constraints!([
+        (Half, [">= 0.5", "<= 1.0"]),
+        (Open, ["> 0.0", "< 1.0"]),
+    ]);
+
+    let half = HalfF64::new(0.5).unwrap();
+    let one = HalfF64::new(1.0).unwrap();
+
+    // [0.5, 1] / [0.5, 1] = [0.5, 2]
+    let quotient: PositiveF64 = one.div_bounded(half);
+    assert_eq!(quotient.get(), 2.0);
+
+    // [0.5, 1] * [0.5, 1] = [0.25, 1]
+    let product: PositiveF64 = half.mul_bounded(half);
+    assert_eq!(product.get(), 0.25);
+
+    // (0, 1) * (0, 1) can underflow to zero
+    let tiny = OpenF64::new(1e-200).unwrap();
+    let underflow: NonNegativeF64 = tiny.mul_bounded(tiny);
+    assert_eq!(underflow.get(), 0.0);
+}
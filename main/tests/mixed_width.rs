@@ -0,0 +1,85 @@
+//! Tests for arithmetic between F32 and F64 types
+//!
+//! The F32 operand must be widened losslessly and the output must be the F64 version of
+//! the inferred type, whichever side the F32 operand is on.
+
+#![expect(clippy::float_cmp, clippy::op_ref)]
+
+use strict_num_extended::*;
+
+#[test]
+fn test_f32_lhs() {
+    let reading = FinF32::new(1.5).unwrap();
+    let offset = FinF64::new(0.25).unwrap();
+
+    let sum: Result<FinF64, FloatError> = reading + offset;
+    assert_eq!(sum.unwrap().get(), 1.75);
+
+    let weight = NormalizedF32::new(0.5).unwrap();
+    let gain = NormalizedF64::new(0.5).unwrap();
+    let product: NormalizedF64 = weight * gain;
+    assert_eq!(product.get(), 0.25);
+
+    let neg = NegativeF32::new(-2.0).unwrap();
+    let pos = PositiveF64::new(1.0).unwrap();
+    let difference: Result<NegativeF64, FloatError> = neg - pos;
+    assert_eq!(difference.unwrap().get(), -3.0);
+}
+
+#[test]
+fn test_f32_rhs() {
+    let parameter = PositiveF64::new(3.0).unwrap();
+    let reading = PositiveF32::new(2.0).unwrap();
+
+    let quotient: Result<PositiveF64, FloatError> = parameter / reading;
+    assert_eq!(quotient.unwrap().get(), 1.5);
+
+    let remainder: NonNegativeF64 = parameter % reading;
+    assert_eq!(remainder.get(), 1.0);
+
+    let sym = SymmetricF64::new(-0.5).unwrap();
+    let weight = NormalizedF32::new(0.5).unwrap();
+    let scaled: SymmetricF64 = sym * weight;
+    assert_eq!(scaled.get(), -0.25);
+}
+
+#[test]
+fn test_widening_is_exact() {
+    // 0.1f32 is not 0.1f64, the exact f32 value must be used
+    let reading = FinF32::new(0.1).unwrap();
+    let zero = FinF64::new(0.0).unwrap();
+    let sum = (reading + zero).unwrap();
+    assert_eq!(sum.get(), f64::from(0.1_f32));
+
+    let max = FinF32::new(f32::MAX).unwrap();
+    let doubled = (max + FinF64::new(f64::from(f32::MAX)).unwrap()).unwrap();
+    assert_eq!(doubled.get(), 2.0 * f64::from(f32::MAX));
+}
+
+#[test]
+fn test_euclid_and_references() {
+    let a = FinF32::new(-7.0).unwrap();
+    let b = FinF64::new(2.0).unwrap();
+
+    assert_eq!(a.rem_euclid(b).unwrap().get(), 1.0);
+    assert_eq!(a.div_euclid(b).unwrap().get(), -4.0);
+    assert_eq!(b.div_euclid(a).unwrap().get(), -0.0);
+
+    let by_ref: Result<FinF64, FloatError> = &a + &b;
+    assert_eq!(by_ref.unwrap().get(), -5.0);
+    assert_eq!((b * &a).unwrap().get(), -14.0);
+    assert_eq!((&a - b).unwrap().get(), -9.0);
+}
+
+#[test]
+fn test_errors_of_widened_operands() {
+    let zero = FinF32::new(0.0).unwrap();
+    let one = FinF64::new(1.0).unwrap();
+
+    assert_eq!(one / zero, Err(FloatError::DivisionByZero));
+    let max = PositiveF64::new(f64::MAX).unwrap();
+    assert_eq!(
+        PositiveF32::new(2.0).unwrap() * max,
+        Err(FloatError::Overflow)
+    );
+}
@@ -0,0 +1,102 @@
+//! Tests for the fused multiply-add
+//!
+//! `mul_add` must compose the output types of `*` and `+`, skip validation for bounded
+//! operands and round once, like `f64::mul_add`.
+
+#![expect(clippy::float_cmp)]
+
+use strict_num_extended::*;
+
+#[test]
+fn test_bounded_mul_add_is_safe() {
+    let a = NormalizedF64::new(0.5).unwrap();
+    let b = NormalizedF64::new(0.5).unwrap();
+    let c = NormalizedF64::new(1.0).unwrap();
+
+    let fused: NonNegativeF64 = a.mul_add(b, c);
+    assert_eq!(fused.get(), 1.25);
+
+    let neg = NegativeNormalizedF64::new(-1.0).unwrap();
+    let mixed: FinF64 = a.mul_add(neg, c);
+    assert_eq!(mixed.get(), 0.5);
+
+    let sym = SymmetricF32::new(-0.5).unwrap();
+    let narrow: FinF32 = sym.mul_add(sym, NormalizedF32::new(0.25).unwrap());
+    assert_eq!(narrow.get(), 0.5);
+}
+
+#[test]
+fn test_bounded_product_plus_unbounded_addend() {
+    // Adding at most 1 to any finite value cannot overflow
+    let a = NormalizedF64::new(1.0).unwrap();
+    let max = FinF64::new(f64::MAX).unwrap();
+    let sum: FinF64 = a.mul_add(a, max);
+    assert_eq!(sum.get(), f64::MAX);
+
+    let big = NonNegativeF64::new(f64::MAX).unwrap();
+    let rounded: NonNegativeF64 = a.mul_add(a, big);
+    assert_eq!(rounded.get(), f64::MAX);
+}
+
+#[test]
+fn test_unbounded_mul_add_is_validated() {
+    let max = PositiveF64::new(f64::MAX).unwrap();
+    let two = PositiveF64::new(2.0).unwrap();
+    let one = PositiveF64::new(1.0).unwrap();
+
+    let ok: Result<PositiveF64, FloatError> = two.mul_add(two, one);
+    assert_eq!(ok.unwrap().get(), 5.0);
+
+    assert_eq!(max.mul_add(two, one), Err(FloatError::Overflow));
+    let err = Checked::new(max)
+        .mul_add(two, one)
+        .detailed_error()
+        .unwrap();
+    assert_eq!(err.kind(), FloatError::Overflow);
+    let operation = err.operation().unwrap();
+    assert_eq!(operation.operator(), Operator::MulAdd);
+    assert_eq!(operation.lhs(), f64::MAX);
+    assert_eq!(operation.rhs(), 2.0);
+    assert_eq!(operation.addend(), Some(1.0));
+    assert_eq!(
+        err.to_string(),
+        "PositiveF64: 1.7976931348623157e308.mul_add(2.0, 1.0) = inf overflows"
+    );
+}
+
+#[test]
+fn test_fallible_product_makes_mul_add_fallible() {
+    // Positive * Positive may underflow, so the fused result is validated even though
+    // adding a positive value keeps it positive
+    let tiny = PositiveF64::new(1e-200).unwrap();
+    let result: Result<PositiveF64, FloatError> = tiny.mul_add(tiny, tiny);
+    assert_eq!(result.unwrap().get(), 1e-200);
+}
+
+#[test]
+fn test_single_rounding() {
+    let tenth = FinF64::new(0.1).unwrap();
+    let ten = FinF64::new(10.0).unwrap();
+    let minus_one = FinF64::new(-1.0).unwrap();
+
+    let fused = tenth.mul_add(ten, minus_one).unwrap();
+    assert_eq!(fused.get(), 0.1_f64.mul_add(10.0, -1.0));
+    assert_ne!(fused.get(), 0.0);
+
+    // The operators round the product first
+    let separate = ((tenth * ten).unwrap() + minus_one).unwrap();
+    assert_eq!(separate.get(), 0.0);
+}
+
+#[test]
+fn test_custom_constraint_mul_add() {
+    strict_num_extended::define_constraints!([(Unit, ["> -1.0", "< 1.0"])]);
+
+    let a = UnitF64::new(0.5).unwrap();
+    let fused: FinF64 = a.mul_add(a, a);
+    assert_eq!(fused.get(), 0.75);
+
+    let weight = NormalizedF64::new(0.5).unwrap();
+    let mixed: FinF64 = weight.mul_add(weight, a);
+    assert_eq!(mixed.get(), 0.75);
+}
@@ -0,0 +1,63 @@
+//! Tests for negation pairing on parsed bounds
+//!
+//! Negated types are found by mirroring the bounds of each type around zero, so
+//! conditions pair regardless of how their bounds are spelled.
+
+// The bounds below spell PI as a literal on purpose
+#![expect(clippy::float_cmp, clippy::approx_constant)]
+
+mod spelled {
+    strict_num_extended_macros::generate_finite_float_types!([
+        (Fin, []),
+        (NonNegative, [">= 0.0"]),
+        (Normalized, [">= 0.0", "<= 1.0"]),
+        (NegativeNormalized, [">= -1.0", "<= 0.0"]),
+        (Symmetric, [">= -1.0", "<= 1.0"]),
+        (Up, ["> 0"]),
+        (Down, ["< -0.0"]),
+        (HalfTurn, [">= 0.0", "<= PI"]),
+        (NegHalfTurn, [">= -3.141592653589793", "<= 0"]),
+        (Band, ["> -2 * 0.5", "< 1", "!= 0.0"]),
+        (LowHalf, [">= -1", "< 0.5"]),
+        (HighHalf, ["<= 1.0", "> -0.5"]),
+    ]);
+}
+
+use spelled::*;
+
+#[test]
+fn test_zero_spelling_pairs() {
+    let up = UpF64::new(2.0).unwrap();
+    let down: DownF64 = -up;
+    assert_eq!(down.get(), -2.0);
+    let back: UpF32 = -DownF32::new(-0.5).unwrap();
+    assert_eq!(back.get(), 0.5);
+}
+
+#[test]
+fn test_constant_and_literal_pair() {
+    let turn = HalfTurnF64::new(core::f64::consts::PI).unwrap();
+    let negated: NegHalfTurnF64 = -turn;
+    assert_eq!(negated.get(), -core::f64::consts::PI);
+
+    let turn32 = HalfTurnF32::new(core::f32::consts::PI).unwrap();
+    let negated32: NegHalfTurnF32 = -turn32;
+    assert_eq!(negated32.get(), -core::f32::consts::PI);
+}
+
+#[test]
+fn test_self_mirrored_with_zero_exclusion() {
+    let band = BandF64::new(-0.25).unwrap();
+    let negated: BandF64 = -band;
+    assert_eq!(negated.get(), 0.25);
+}
+
+#[test]
+fn test_exclusive_sides_swap() {
+    // [-1, 0.5) mirrors to (-0.5, 1]
+    let low = LowHalfF64::new(-1.0).unwrap();
+    let high: HighHalfF64 = -low;
+    assert_eq!(high.get(), 1.0);
+    let low_again: LowHalfF64 = -high;
+    assert_eq!(low_again.get(), -1.0);
+}
@@ -0,0 +1,123 @@
+//! Tests for exclusive (open) bounds in the constraint DSL
+//!
+//! These types are generated locally because the built-in set only uses closed
+//! intervals and sign constraints.
+
+#![expect(clippy::float_cmp)]
+#![allow(clippy::manual_range_contains)]
+
+mod open {
+    strict_num_extended_macros::generate_finite_float_types!([
+        (Fin, []),
+        (NonNegative, [">= 0.0"]),
+        (Symmetric, [">= -1.0", "<= 1.0"]),
+        (Normalized, [">= 0.0", "<= 1.0"]),
+        (Positive, ["> 0.0"]),
+        (OpenUnit, ["> 0.0", "< 1.0"]),
+        (HalfOpenUnit, [">= 0.0", "< 1.0"]),
+        (OpenAngle, ["> 0.0", "< PI"]),
+        (AboveOne, ["> 1.0"]),
+    ]);
+}
+
+use open::*;
+
+#[test]
+fn test_open_unit_rejects_both_endpoints() {
+    assert!(OpenUnitF64::new(0.5).is_ok());
+    assert!(OpenUnitF64::new(f64::MIN_POSITIVE).is_ok());
+    assert!(OpenUnitF64::new(1.0 - f64::EPSILON).is_ok());
+
+    assert_eq!(OpenUnitF64::new(0.0), Err(FloatError::BelowRange));
+    assert_eq!(OpenUnitF64::new(-0.0), Err(FloatError::BelowRange));
+    assert_eq!(OpenUnitF64::new(1.0), Err(FloatError::AboveRange));
+    assert_eq!(OpenUnitF32::new(1.0), Err(FloatError::AboveRange));
+}
+
+#[test]
+fn test_half_open_unit() {
+    assert!(HalfOpenUnitF64::new(0.0).is_ok());
+    assert!(HalfOpenUnitF64::new(0.999).is_ok());
+    assert!(HalfOpenUnitF64::new(1.0).is_err());
+    assert!(HalfOpenUnitF32::new(0.0).is_ok());
+    assert!(HalfOpenUnitF32::new(1.0).is_err());
+}
+
+#[test]
+fn test_exclusive_non_zero_bounds() {
+    assert!(OpenAngleF64::new(core::f64::consts::FRAC_PI_2).is_ok());
+    assert!(OpenAngleF64::new(core::f64::consts::PI).is_err());
+    assert!(OpenAngleF64::new(0.0).is_err());
+
+    assert!(AboveOneF64::new(1.0).is_err());
+    assert!(AboveOneF64::new(1.0 + f64::EPSILON).is_ok());
+}
+
+#[test]
+fn test_exclusive_bounds_in_const_context() {
+    const HALF: OpenUnitF64 = OpenUnitF64::new_const(0.5);
+    assert_eq!(HALF.get(), 0.5);
+}
+
+#[test]
+fn test_constants_respect_exclusive_bounds() {
+    assert_eq!(OpenUnitF64::HALF.get(), 0.5);
+    assert_eq!(HalfOpenUnitF64::ZERO.get(), 0.0);
+    assert_eq!(OpenAngleF64::FRAC_PI_2.get(), core::f64::consts::FRAC_PI_2);
+    assert_eq!(AboveOneF64::TWO.get(), 2.0);
+}
+
+#[test]
+fn test_open_interval_subset_conversions() {
+    // (0, 1) ⊂ [0, 1]: infallible
+    let open = OpenUnitF64::new(0.25).unwrap();
+    let closed: NormalizedF64 = open.into();
+    assert_eq!(closed.get(), 0.25);
+
+    // (0, 1) ⊂ (0, +∞): infallible
+    let positive: PositiveF64 = open.into();
+    assert_eq!(positive.get(), 0.25);
+
+    // [0, 1] ⊄ (0, 1): fallible at both endpoints
+    let one = NormalizedF64::new(1.0).unwrap();
+    assert!(OpenUnitF64::try_from(one).is_err());
+    let zero = NormalizedF64::new(0.0).unwrap();
+    assert!(OpenUnitF64::try_from(zero).is_err());
+
+    // [0, 1) ⊄ (0, 1) because of zero
+    let half_open = HalfOpenUnitF64::new(0.0).unwrap();
+    assert!(OpenUnitF64::try_from(half_open).is_err());
+}
+
+#[test]
+fn test_open_interval_multiplication_is_validated() {
+    // (0, 1) * (0, 1) can underflow to 0.0, so the result is validated
+    let tiny = OpenUnitF64::new(f64::MIN_POSITIVE).unwrap();
+    let result = tiny * tiny;
+    assert!(result.is_err());
+
+    let a = OpenUnitF64::new(0.5).unwrap();
+    let b = OpenUnitF64::new(0.5).unwrap();
+    assert_eq!((a * b).unwrap().get(), 0.25);
+}
+
+#[test]
+fn test_closed_interval_multiplication_stays_safe() {
+    let a = NormalizedF64::new(0.5).unwrap();
+    let b = NormalizedF64::new(0.5).unwrap();
+    let product: NormalizedF64 = a * b;
+    assert_eq!(product.get(), 0.25);
+}
+
+#[test]
+fn test_quotients_reaching_infinity_are_validated() {
+    // (0, 1) / (0, +inf) has no finite upper side, so it's not inferred to fit
+    // `Positive` and overflows for a subnormal divisor
+    let a = OpenUnitF64::new(0.5).unwrap();
+    let tiny = PositiveF64::new(5e-324).unwrap();
+    assert_eq!((a / tiny).unwrap_err(), FloatError::Overflow);
+    assert_eq!(a.div_euclid(tiny).unwrap_err(), FloatError::Overflow);
+
+    let quotient = a.div_euclid(PositiveF64::new(0.25).unwrap());
+    assert_eq!(quotient.unwrap().get(), 2.0);
+}
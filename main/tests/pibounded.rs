@@ -27,10 +27,12 @@ fn test_pibounded_f32_creation() {
     assert!(PiBoundedF32::new(3.14).is_ok());
     assert!(PiBoundedF32::new(-3.14).is_ok());
 
-    // Note: f32::consts::PI is slightly larger than PI in f64 precision due to precision limits
-    // So we test values close to PI
     assert!(PiBoundedF32::new(3.14159).is_ok());
 
+    // f32 types are validated against the f32 constant, which rounds above PI in f64
+    assert!(PiBoundedF32::new(core::f32::consts::PI).is_ok());
+    assert!(PiBoundedF32::new(-core::f32::consts::PI).is_ok());
+
     // Invalid values
     assert!(PiBoundedF32::new(4.0).is_err());
     assert!(PiBoundedF32::new(-4.0).is_err());
@@ -46,3 +48,14 @@ fn test_pibounded_const_context() {
     assert_eq!(PI.get(), core::f64::consts::PI);
     assert_eq!(NEG_PI.get(), -core::f64::consts::PI);
 }
+
+#[test]
+fn test_pibounded_f32_to_f64_is_fallible() {
+    // Widening f32::consts::PI does not fit within the f64 bounds
+    let pi = PiBoundedF32::new(core::f32::consts::PI).unwrap();
+    assert_eq!(PiBoundedF64::try_from(pi), Err(FloatError::AboveRange));
+    assert_eq!(pi.try_into_f64_type(), Err(FloatError::AboveRange));
+
+    let half = PiBoundedF32::new(1.5).unwrap();
+    assert_eq!(PiBoundedF64::try_from(half).unwrap().get(), 1.5);
+}
@@ -46,7 +46,7 @@ mod tests {
     #[test]
     fn test_finf64_div_zero_f64() {
         let a = FinF64::new(5.0).unwrap();
-        assert!(matches!(a / 0.0f64, Err(FloatError::NaN)));
+        assert!(matches!(a / 0.0f64, Err(FloatError::DivisionByZero)));
     }
 
     #[test]
@@ -116,7 +116,7 @@ mod tests {
     #[test]
     fn test_finf32_div_zero_f32() {
         let a = FinF32::new(5.0).unwrap();
-        assert!(matches!(a / 0.0f32, Err(FloatError::NaN)));
+        assert!(matches!(a / 0.0f32, Err(FloatError::DivisionByZero)));
     }
 
     #[test]
@@ -369,144 +369,147 @@ mod tests {
     #[test]
     fn test_finf64_add_nan() {
         let a = FinF64::new(2.0).unwrap();
-        assert!(matches!(a + NAN, Err(FloatError::NaN)));
+        assert!(matches!(a + NAN, Err(FloatError::InvalidOperand)));
     }
 
     #[test]
     fn test_finf64_add_infinity() {
         let a = FinF64::new(2.0).unwrap();
-        assert!(matches!(a + INFINITY, Err(FloatError::NaN)));
+        assert!(matches!(a + INFINITY, Err(FloatError::InvalidOperand)));
     }
 
     #[test]
     fn test_finf64_add_neg_infinity() {
         let a = FinF64::new(2.0).unwrap();
-        assert!(matches!(a + NEG_INFINITY, Err(FloatError::NaN)));
+        assert!(matches!(a + NEG_INFINITY, Err(FloatError::InvalidOperand)));
     }
 
     #[test]
     fn test_finf64_sub_nan() {
         let a = FinF64::new(2.0).unwrap();
-        assert!(matches!(a - NAN, Err(FloatError::NaN)));
+        assert!(matches!(a - NAN, Err(FloatError::InvalidOperand)));
     }
 
     #[test]
     fn test_finf64_mul_nan() {
         let a = FinF64::new(2.0).unwrap();
-        assert!(matches!(a * NAN, Err(FloatError::NaN)));
+        assert!(matches!(a * NAN, Err(FloatError::InvalidOperand)));
     }
 
     #[test]
     fn test_finf64_div_nan() {
         let a = FinF64::new(2.0).unwrap();
-        assert!(matches!(a / NAN, Err(FloatError::NaN)));
+        assert!(matches!(a / NAN, Err(FloatError::InvalidOperand)));
     }
 
     #[test]
     fn test_f64_add_finf64_nan() {
         let b = FinF64::new(3.0).unwrap();
-        assert!(matches!(NAN + b, Err(FloatError::NaN)));
+        assert!(matches!(NAN + b, Err(FloatError::InvalidOperand)));
     }
 
     #[test]
     fn test_f64_sub_finf64_nan() {
         let b = FinF64::new(3.0).unwrap();
-        assert!(matches!(NAN - b, Err(FloatError::NaN)));
+        assert!(matches!(NAN - b, Err(FloatError::InvalidOperand)));
     }
 
     #[test]
     fn test_f64_mul_finf64_nan() {
         let b = FinF64::new(3.0).unwrap();
-        assert!(matches!(NAN * b, Err(FloatError::NaN)));
+        assert!(matches!(NAN * b, Err(FloatError::InvalidOperand)));
     }
 
     #[test]
     fn test_f64_div_finf64_nan() {
         let b = FinF64::new(3.0).unwrap();
-        assert!(matches!(NAN / b, Err(FloatError::NaN)));
+        assert!(matches!(NAN / b, Err(FloatError::InvalidOperand)));
     }
 
     #[test]
     fn test_finf32_add_nan() {
         let a = FinF32::new(2.0).unwrap();
-        assert!(matches!(a + F32_NAN, Err(FloatError::NaN)));
+        assert!(matches!(a + F32_NAN, Err(FloatError::InvalidOperand)));
     }
 
     #[test]
     fn test_finf32_add_infinity() {
         let a = FinF32::new(2.0).unwrap();
-        assert!(matches!(a + F32_INFINITY, Err(FloatError::NaN)));
+        assert!(matches!(a + F32_INFINITY, Err(FloatError::InvalidOperand)));
     }
 
     #[test]
     fn test_finf32_add_neg_infinity() {
         let a = FinF32::new(2.0).unwrap();
-        assert!(matches!(a + F32_NEG_INFINITY, Err(FloatError::NaN)));
+        assert!(matches!(
+            a + F32_NEG_INFINITY,
+            Err(FloatError::InvalidOperand)
+        ));
     }
 
     #[test]
     fn test_f32_add_finf32_nan() {
         let b = FinF32::new(3.0).unwrap();
-        assert!(matches!(F32_NAN + b, Err(FloatError::NaN)));
+        assert!(matches!(F32_NAN + b, Err(FloatError::InvalidOperand)));
     }
 
     #[test]
     fn test_f32_add_finf32_infinity() {
         let b = FinF32::new(3.0).unwrap();
-        assert!(matches!(F32_INFINITY + b, Err(FloatError::NaN)));
+        assert!(matches!(F32_INFINITY + b, Err(FloatError::InvalidOperand)));
     }
 
     #[test]
     fn test_nonnegativef64_add_nan() {
         let a = NonNegativeF64::new(2.0).unwrap();
-        assert!(matches!(a + NAN, Err(FloatError::NaN)));
+        assert!(matches!(a + NAN, Err(FloatError::InvalidOperand)));
     }
 
     #[test]
     fn test_nonnegativef32_add_nan() {
         let a = NonNegativeF32::new(2.0).unwrap();
-        assert!(matches!(a + F32_NAN, Err(FloatError::NaN)));
+        assert!(matches!(a + F32_NAN, Err(FloatError::InvalidOperand)));
     }
 
     #[test]
     fn test_normalizedf64_add_nan() {
         let a = NormalizedF64::new(0.5).unwrap();
-        assert!(matches!(a + NAN, Err(FloatError::NaN)));
+        assert!(matches!(a + NAN, Err(FloatError::InvalidOperand)));
     }
 
     #[test]
     fn test_nonzero_f64_add_nan() {
         let a = NonZeroF64::new(2.0).unwrap();
-        assert!(matches!(a + NAN, Err(FloatError::NaN)));
+        assert!(matches!(a + NAN, Err(FloatError::InvalidOperand)));
     }
 
     #[test]
     fn test_nonpositivef64_add_nan() {
         let a = NonPositiveF64::new(-2.0).unwrap();
-        assert!(matches!(a + NAN, Err(FloatError::NaN)));
+        assert!(matches!(a + NAN, Err(FloatError::InvalidOperand)));
     }
 
     #[test]
     fn test_positivef64_add_nan() {
         let a = PositiveF64::new(2.0).unwrap();
-        assert!(matches!(a + NAN, Err(FloatError::NaN)));
+        assert!(matches!(a + NAN, Err(FloatError::InvalidOperand)));
     }
 
     #[test]
     fn test_symmetricf64_add_nan() {
         let a = SymmetricF64::new(0.5).unwrap();
-        assert!(matches!(a + NAN, Err(FloatError::NaN)));
+        assert!(matches!(a + NAN, Err(FloatError::InvalidOperand)));
     }
 
     #[test]
     fn test_negative_normalizedf64_add_nan() {
         let a = NegativeNormalizedF64::new(-0.5).unwrap();
-        assert!(matches!(a + NAN, Err(FloatError::NaN)));
+        assert!(matches!(a + NAN, Err(FloatError::InvalidOperand)));
     }
 
     #[test]
     fn test_negativef64_add_nan() {
         let a = NegativeF64::new(-2.0).unwrap();
-        assert!(matches!(a + NAN, Err(FloatError::NaN)));
+        assert!(matches!(a + NAN, Err(FloatError::InvalidOperand)));
     }
 }
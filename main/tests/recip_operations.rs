@@ -0,0 +1,89 @@
+//! Tests for `recip`
+//!
+//! The reciprocal must keep the sign of its input, never be zero, and be infallible
+//! only when the magnitude of the input is bounded away from zero.
+
+#![expect(clippy::float_cmp)]
+
+use strict_num_extended::*;
+
+#[test]
+fn test_recip_keeps_sign() {
+    let positive: Result<PositiveF64, FloatError> = PositiveF64::new(4.0).unwrap().recip();
+    assert_eq!(positive.unwrap().get(), 0.25);
+
+    let negative: Result<NegativeF32, FloatError> = NegativeF32::new(-2.0).unwrap().recip();
+    assert_eq!(negative.unwrap().get(), -0.5);
+
+    let nonzero: Result<NonZeroF64, FloatError> = NonZeroF64::new(-0.5).unwrap().recip();
+    assert_eq!(nonzero.unwrap().get(), -2.0);
+
+    // Values including zero still give a non-zero reciprocal on success
+    let non_negative: Result<PositiveF64, FloatError> = NonNegativeF64::new(8.0).unwrap().recip();
+    assert_eq!(non_negative.unwrap().get(), 0.125);
+    let normalized: Result<PositiveF64, FloatError> = NormalizedF64::new(0.5).unwrap().recip();
+    assert_eq!(normalized.unwrap().get(), 2.0);
+    let neg_norm: Result<NegativeF64, FloatError> =
+        NegativeNormalizedF64::new(-0.25).unwrap().recip();
+    assert_eq!(neg_norm.unwrap().get(), -4.0);
+    let fin: Result<NonZeroF64, FloatError> = FinF64::new(10.0).unwrap().recip();
+    assert_eq!(fin.unwrap().get(), 0.1);
+    let sym: Result<NonZeroF64, FloatError> = SymmetricF64::new(-1.0).unwrap().recip();
+    assert_eq!(sym.unwrap().get(), -1.0);
+}
+
+#[test]
+fn test_recip_errors() {
+    let zero = FinF64::new(0.0).unwrap();
+    assert_eq!(zero.recip(), Err(FloatError::DivisionByZero));
+    let err = Checked::new(zero).recip().detailed_error().unwrap();
+    let operation = err.operation().unwrap();
+    assert_eq!(operation.operator(), Operator::Div);
+    assert_eq!((operation.lhs(), operation.rhs()), (1.0, 0.0));
+
+    let neg_zero = NonPositiveF32::new(-0.0).unwrap().recip();
+    assert_eq!(neg_zero, Err(FloatError::DivisionByZero));
+
+    // Subnormal values have an infinite reciprocal
+    let positive = PositiveF64::new(5e-324).unwrap().recip();
+    assert_eq!(positive, Err(FloatError::Overflow));
+    let nonzero = NonZeroF32::new(-1e-45).unwrap().recip();
+    assert_eq!(nonzero, Err(FloatError::Overflow));
+
+    // The reciprocal of the largest value is subnormal, but still non-zero
+    let max: PositiveF64 = PositiveF64::new(f64::MAX).unwrap().recip().unwrap();
+    assert!(max.get() > 0.0);
+}
+
+#[test]
+fn test_recip_bounded_away_from_zero() {
+    strict_num_extended::define_constraints!([
+        (AtLeastOne, [">= 1.0"]),
+        (UnitInterval, ["> 0.0", "<= 1.0"]),
+        (FarNegative, ["<= -2.0", ">= -4.0"]),
+    ]);
+
+    // [1, ∞) → (0, 1], infallible
+    let value = AtLeastOneF64::new(4.0).unwrap();
+    let inverse: UnitIntervalF64 = value.recip();
+    assert_eq!(inverse.get(), 0.25);
+    let one: UnitIntervalF32 = AtLeastOneF32::new(1.0).unwrap().recip();
+    assert_eq!(one.get(), 1.0);
+
+    // (0, 1] can still overflow on subnormals
+    let back: Result<PositiveF64, FloatError> = inverse.recip();
+    assert_eq!(back.unwrap().get(), 4.0);
+
+    // [-4, -2] → [-0.5, -0.25], the tightest declared type is NegativeNormalized
+    let narrow: NegativeNormalizedF64 = FarNegativeF64::new(-2.0).unwrap().recip();
+    assert_eq!(narrow.get(), -0.5);
+}
+
+#[test]
+fn test_checked_recip() {
+    let value = Checked::new(PositiveF64::new(2.0).unwrap()).recip();
+    assert_eq!(value.into_result().unwrap().get(), 0.5);
+
+    let failed = Checked::new(FinF64::new(0.0).unwrap()).recip();
+    assert_eq!(failed.error(), Some(FloatError::DivisionByZero));
+}
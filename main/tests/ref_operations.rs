@@ -0,0 +1,131 @@
+//! Tests for arithmetic operators taking operands by reference
+//!
+//! Every by-reference form forwards to the by-value operator, so the output types
+//! and error behavior must match it exactly.
+
+#![expect(clippy::float_cmp, clippy::op_ref)]
+
+use core::ops::{Add, Mul};
+use strict_num_extended::*;
+
+#[test]
+fn test_constraint_ref_matrix() {
+    let a = PositiveF64::new(6.0).unwrap();
+    let b = NegativeF64::new(-4.0).unwrap();
+
+    let by_value: FinF64 = a + b;
+    let lhs_ref: FinF64 = &a + b;
+    let rhs_ref: FinF64 = a + &b;
+    let both_ref: FinF64 = &a + &b;
+    assert_eq!(by_value, lhs_ref);
+    assert_eq!(by_value, rhs_ref);
+    assert_eq!(by_value, both_ref);
+
+    let product: Result<NegativeF64, FloatError> = &a * &b;
+    assert_eq!(product.unwrap().get(), -24.0);
+
+    let quotient: Result<NegativeF64, FloatError> = &a / b;
+    assert_eq!(quotient.unwrap().get(), -1.5);
+    assert_eq!(&a % &b, a % b);
+}
+
+#[test]
+fn test_ref_errors_match_by_value() {
+    let max = PositiveF64::new(f64::MAX).unwrap();
+    assert_eq!(&max * &max, max * max);
+    assert!((&max * max).is_err());
+
+    let zero = FinF64::new(0.0).unwrap();
+    let one = FinF64::new(1.0).unwrap();
+    assert_eq!(&one / &zero, one / zero);
+    assert!((one / &zero).is_err());
+}
+
+#[test]
+fn test_primitive_ref_matrix() {
+    let a = PositiveF32::new(3.0).unwrap();
+    let rhs = 2.0_f32;
+
+    assert_eq!((&a * rhs).unwrap().get(), 6.0);
+    assert_eq!((a * &rhs).unwrap().get(), 6.0);
+    assert_eq!((&a * &rhs).unwrap().get(), 6.0);
+    assert_eq!((&rhs - a).unwrap().get(), -1.0);
+    assert_eq!((rhs - &a).unwrap().get(), -1.0);
+    assert_eq!((&rhs - &a).unwrap().get(), -1.0);
+
+    let nan = f64::NAN;
+    assert!((&PositiveF64::new(1.0).unwrap() + &nan).is_err());
+}
+
+#[test]
+fn test_euclid_ref() {
+    let a = FinF64::new(-7.0).unwrap();
+    let b = PositiveF64::new(3.0).unwrap();
+
+    let rem: NonNegativeF64 = (&a).rem_euclid(&b);
+    assert_eq!(rem.get(), 2.0);
+    assert_eq!(RemEuclid::rem_euclid(&a, b), a.rem_euclid(b));
+    assert_eq!(DivEuclid::div_euclid(a, &b), a.div_euclid(b));
+}
+
+#[test]
+fn test_option_ref() {
+    let a = PositiveF64::new(5.0).unwrap();
+    let some: Option<NegativeF64> = Some(NegativeF64::new(-3.0).unwrap());
+    let none: Option<NegativeF64> = None;
+
+    let sum: Option<FinF64> = &a + some;
+    assert_eq!(sum.unwrap().get(), 2.0);
+    assert_eq!(a + &some, sum);
+    assert_eq!(&a + &some, sum);
+    assert!((&a + &none).is_none());
+}
+
+#[test]
+fn test_result_ref() {
+    let a = NonNegativeF64::new(4.0).unwrap();
+    let ok: Result<NonPositiveF64, FloatError> = Ok(NonPositiveF64::new(-1.0).unwrap());
+    let err: Result<NonNegativeF64, FloatError> = Err(FloatError::NaN);
+
+    let sum: Result<FinF64, FloatError> = &a + &ok;
+    assert_eq!(sum.unwrap().get(), 3.0);
+    assert_eq!(&a + ok, a + ok);
+    assert_eq!(&ok - a, ok - a);
+    assert_eq!(ok - &a, ok - a);
+    assert_eq!(&err * &a, Err(FloatError::NaN));
+}
+
+#[test]
+fn test_generic_ref_bounds() {
+    fn product_of<T>(values: &[T], one: T) -> T
+    where
+        for<'a> T: Mul<&'a T, Output = T>,
+    {
+        values.iter().fold(one, |acc, v| acc * v)
+    }
+
+    fn sum_of<'a, T>(a: &'a T, b: &'a T) -> <&'a T as Add<&'a T>>::Output
+    where
+        &'a T: Add<&'a T>,
+    {
+        a + b
+    }
+
+    let signs = [SymmetricF64::new(0.5).unwrap(); 3];
+    let product = product_of(&signs, SymmetricF64::new(-1.0).unwrap());
+    assert_eq!(product.get(), -0.125);
+
+    let values = [
+        PositiveF64::new(1.5).unwrap(),
+        PositiveF64::new(2.0).unwrap(),
+    ];
+    let sum: Result<PositiveF64, FloatError> = sum_of(&values[0], &values[1]);
+    assert_eq!(sum.unwrap().get(), 3.5);
+
+    // Positive + Positive may overflow, so folding by reference propagates errors
+    let total = values
+        .iter()
+        .try_fold(PositiveF64::new(0.5).unwrap(), |acc, v| acc + v)
+        .unwrap();
+    assert_eq!(total.get(), 4.0);
+}
@@ -0,0 +1,211 @@
+//! Tests for the remainder operator and Euclidean division
+//!
+//! The remainder keeps the sign of the dividend and is bounded by the divisor, so it
+//! is safe whenever the divisor excludes zero.
+
+#![expect(clippy::float_cmp)]
+
+use strict_num_extended::*;
+
+#[test]
+fn test_rem_output_types() {
+    let a = PositiveF64::new(7.5).unwrap();
+    let b = PositiveF64::new(2.0).unwrap();
+    let r: NonNegativeF64 = a % b;
+    assert_eq!(r.get(), 1.5);
+
+    let exact: NonNegativeF64 = PositiveF64::new(4.0).unwrap() % b;
+    assert_eq!(exact.get(), 0.0);
+
+    let fin = FinF64::new(-7.5).unwrap();
+    let signed: FinF64 = fin % NonZeroF64::new(-2.0).unwrap();
+    assert_eq!(signed.get(), -1.5);
+
+    let neg: NonPositiveF32 = NegativeF32::new(-5.0).unwrap() % PositiveF32::new(3.0).unwrap();
+    assert_eq!(neg.get(), -2.0);
+}
+
+#[test]
+fn test_rem_bounded_by_operands() {
+    let norm: NormalizedF64 = NormalizedF64::new(0.75).unwrap() % PositiveF64::new(0.5).unwrap();
+    assert_eq!(norm.get(), 0.25);
+
+    let sym: SymmetricF64 = SymmetricF64::new(-0.75).unwrap() % NonZeroF64::new(0.5).unwrap();
+    assert_eq!(sym.get(), -0.25);
+
+    let angle: PiBoundedF64 = PiBoundedF64::new(3.0).unwrap() % PositiveF64::new(2.0).unwrap();
+    assert_eq!(angle.get(), 1.0);
+
+    // The divisor bounds the result even for an unbounded dividend
+    let wrapped: Result<NormalizedF64, FloatError> =
+        PositiveF64::new(2.75).unwrap() % NormalizedF64::new(1.0).unwrap();
+    assert_eq!(wrapped.unwrap().get(), 0.75);
+}
+
+#[test]
+fn test_rem_zero_divisor() {
+    let a = FinF64::new(3.0).unwrap();
+    let zero = FinF64::new(0.0).unwrap();
+    let r: Result<FinF64, FloatError> = a % zero;
+    assert!(r.is_err());
+    assert_eq!((a % FinF64::new(2.0).unwrap()).unwrap().get(), 1.0);
+}
+
+#[test]
+fn test_rem_with_primitives() {
+    let a = PositiveF64::new(5.0).unwrap();
+    let r: Result<NonNegativeF64, FloatError> = a % 3.0;
+    assert_eq!(r.unwrap().get(), 2.0);
+    assert!((a % 0.0).is_err());
+    assert!((a % f64::NAN).is_err());
+
+    // A primitive dividend is treated as `Fin`
+    let lhs_primitive: Result<FinF32, FloatError> = 5.0f32 % PositiveF32::new(3.0).unwrap();
+    assert_eq!(lhs_primitive.unwrap().get(), 2.0);
+    let negative_lhs: Result<FinF32, FloatError> = -5.0f32 % PositiveF32::new(3.0).unwrap();
+    assert_eq!(negative_lhs.unwrap().get(), -2.0);
+}
+
+#[test]
+fn test_rem_with_option_and_result() {
+    let a = PositiveF64::new(5.0).unwrap();
+    let some: Option<NonNegativeF64> = a % Some(PositiveF64::new(3.0).unwrap());
+    assert_eq!(some.unwrap().get(), 2.0);
+    let none: Option<NonNegativeF64> = a % None::<PositiveF64>;
+    assert!(none.is_none());
+
+    let ok: Result<PositiveF64, FloatError> = Ok(a);
+    let r: Result<NonNegativeF64, FloatError> = ok % PositiveF64::new(2.0).unwrap();
+    assert_eq!(r.unwrap().get(), 1.0);
+}
+
+#[test]
+fn test_rem_assign() {
+    let mut x = NonNegativeF64::new(10.0).unwrap();
+    x %= PositiveF64::new(4.0).unwrap();
+    assert_eq!(x.get(), 2.0);
+
+    let mut y = FinF64::new(10.0).unwrap();
+    assert!(y.try_rem_assign(FinF64::new(0.0).unwrap()).is_err());
+    assert_eq!(y.get(), 10.0);
+    y.try_rem_assign(3.0).unwrap();
+    assert_eq!(y.get(), 1.0);
+}
+
+#[test]
+fn test_rem_euclid() {
+    const TURN: PositiveF64 = PositiveF64::new_const(360.0);
+    let heading: NonNegativeF64 = FinF64::new(-90.0).unwrap().rem_euclid(TURN);
+    assert_eq!(heading.get(), 270.0);
+
+    let wrapped: Result<NormalizedF32, FloatError> = FinF32::new(-0.25)
+        .unwrap()
+        .rem_euclid(NormalizedF32::new(1.0).unwrap());
+    assert_eq!(wrapped.unwrap().get(), 0.75);
+
+    // Rounding may land exactly on the divisor
+    let edge: NonNegativeF64 = NegativeF64::new(-1e-20)
+        .unwrap()
+        .rem_euclid(PositiveF64::new(1.0).unwrap());
+    assert_eq!(edge.get(), 1.0);
+
+    assert!(FinF64::new(1.0).unwrap().rem_euclid(0.0).is_err());
+    let by_primitive: Result<NonNegativeF64, FloatError> =
+        FinF64::new(-1.0).unwrap().rem_euclid(3.0);
+    assert_eq!(by_primitive.unwrap().get(), 2.0);
+}
+
+#[test]
+fn test_div_euclid() {
+    let q = FinF64::new(-90.0)
+        .unwrap()
+        .div_euclid(PositiveF64::new(360.0).unwrap());
+    assert_eq!(q.unwrap().get(), -1.0);
+
+    let quotient: Result<NonNegativeF64, FloatError> = NormalizedF64::new(0.75)
+        .unwrap()
+        .div_euclid(PositiveF64::new(0.5).unwrap());
+    assert_eq!(quotient.unwrap().get(), 1.0);
+
+    assert!(FinF64::new(1.0).unwrap().div_euclid(0.0).is_err());
+    assert!(
+        FinF64::new(f64::MAX)
+            .unwrap()
+            .div_euclid(PositiveF64::new(0.5).unwrap())
+            .is_err()
+    );
+}
+
+#[test]
+fn test_div_euclid_by_subnormal_overflows() {
+    strict_num_extended::define_constraints!([(OpenUnit, ["> 0.0", "< 1.0"])]);
+
+    let one = NormalizedF64::new(1.0).unwrap();
+    let positive = PositiveF64::new(5e-324).unwrap();
+    assert_eq!(one.div_euclid(positive).unwrap_err(), FloatError::Overflow);
+
+    let half = OpenUnitF64::new(0.5).unwrap();
+    let tiny = OpenUnitF64::new(5e-324).unwrap();
+    let negative = NegativeF64::new(-5e-324).unwrap();
+    let non_zero = NonZeroF64::new(5e-324).unwrap();
+    assert_eq!(half.div_euclid(tiny).unwrap_err(), FloatError::Overflow);
+    assert_eq!(half.div_euclid(negative).unwrap_err(), FloatError::Overflow);
+    assert_eq!(half.div_euclid(non_zero).unwrap_err(), FloatError::Overflow);
+
+    let half_f32 = OpenUnitF32::new(0.5).unwrap();
+    let symmetric_f32 = SymmetricF32::new(-1.0).unwrap();
+    let positive_f32 = PositiveF32::new(1e-45).unwrap();
+    let non_zero_f32 = NonZeroF32::new(-1e-45).unwrap();
+    assert_eq!(
+        half_f32.div_euclid(positive_f32).unwrap_err(),
+        FloatError::Overflow
+    );
+    assert_eq!(
+        symmetric_f32.div_euclid(non_zero_f32).unwrap_err(),
+        FloatError::Overflow
+    );
+
+    assert_eq!(half.div_euclid(half).unwrap().get(), 1.0);
+}
+
+#[test]
+fn test_euclid_matches_std() {
+    let values = [
+        -7.5, -3.0, -1.0, -0.5, -0.0, 0.0, 0.5, 1.0, 3.0, 7.5, 1e10, -1e10, 1e300, -1e300,
+    ];
+    for &a in &values {
+        for &b in &[-3.0, -0.5, 0.5, 2.0, 1e-3, 1e20] {
+            let lhs = FinF64::new(a).unwrap();
+            let rhs = FinF64::new(b).unwrap();
+            assert_eq!(lhs.rem_euclid(rhs).unwrap().get(), a.rem_euclid(b));
+            if let Ok(q) = lhs.div_euclid(rhs) {
+                assert_eq!(q.get(), a.div_euclid(b), "{a}.div_euclid({b})");
+                assert_eq!(
+                    q.get().is_sign_negative(),
+                    a.div_euclid(b).is_sign_negative()
+                );
+            }
+
+            // Out-of-range values become infinite and are skipped below
+            #[expect(clippy::cast_possible_truncation)]
+            let (a32, b32) = (a as f32, b as f32);
+            if let (Ok(lhs32), Ok(rhs32)) = (FinF32::new(a32), FinF32::new(b32)) {
+                assert_eq!(lhs32.rem_euclid(rhs32).unwrap().get(), a32.rem_euclid(b32));
+                if let Ok(q) = lhs32.div_euclid(rhs32) {
+                    assert_eq!(q.get(), a32.div_euclid(b32), "{a32}.div_euclid({b32})");
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_euclid_with_option_and_result() {
+    let a = FinF64::new(-1.0).unwrap();
+    let r: Option<NonNegativeF64> = a.rem_euclid(Some(PositiveF64::new(3.0).unwrap()));
+    assert_eq!(r.unwrap().get(), 2.0);
+
+    let ok: Result<FinF64, FloatError> = Ok(a);
+    let q = ok.div_euclid(PositiveF64::new(3.0).unwrap());
+    assert_eq!(q.unwrap().get(), -1.0);
+}
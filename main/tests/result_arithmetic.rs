@@ -161,11 +161,11 @@ mod test_result_error_propagation {
 
     #[test]
     fn test_out_of_range_propagation() {
-        let a: Result<NonNegativeF64, FloatError> = Err(FloatError::OutOfRange);
+        let a: Result<NonNegativeF64, FloatError> = Err(FloatError::BelowRange);
         let b: NonPositiveF64 = NonPositiveF64::new_const(-3.0);
         let result: Result<FinF64, FloatError> = a + b;
         assert!(result.is_err());
-        assert_eq!(result.unwrap_err(), FloatError::OutOfRange);
+        assert_eq!(result.unwrap_err(), FloatError::BelowRange);
     }
 }
 
@@ -178,7 +178,7 @@ mod test_result_division_edge_cases {
         let b: NonNegativeF64 = NonNegativeF64::new_const(0.0);
         let result: Result<NonNegativeF64, FloatError> = a / b;
         assert!(result.is_err());
-        assert_eq!(result.unwrap_err(), FloatError::NaN);
+        assert_eq!(result.unwrap_err(), FloatError::DivisionByZero);
     }
 
     #[test]
@@ -187,7 +187,7 @@ mod test_result_division_edge_cases {
         const B: NonNegativeF64 = NonNegativeF64::new_const(0.0);
         let result: Result<NonNegativeF64, FloatError> = a / B;
         assert!(result.is_err());
-        assert_eq!(result.unwrap_err(), FloatError::NaN);
+        assert_eq!(result.unwrap_err(), FloatError::DivisionByZero);
     }
 
     #[test]
@@ -196,7 +196,7 @@ mod test_result_division_edge_cases {
         let b: Result<NonNegativeF64, FloatError> = Ok(NonNegativeF64::new_const(0.0));
         let result: Result<NonNegativeF64, FloatError> = A / b;
         assert!(result.is_err());
-        assert_eq!(result.unwrap_err(), FloatError::NaN);
+        assert_eq!(result.unwrap_err(), FloatError::DivisionByZero);
     }
 
     #[test]
@@ -0,0 +1,153 @@
+//! Tests for `sqrt`, `cbrt` and `hypot`
+//!
+//! Roots of non-negative types must be infallible and keep their type, `sqrt` of
+//! types admitting negative values must fail on them, and `cbrt` must never fail.
+
+#![expect(clippy::float_cmp)]
+
+use strict_num_extended::*;
+
+#[test]
+fn test_sqrt_preserves_non_negative_types() {
+    let non_negative: NonNegativeF64 = NonNegativeF64::new(9.0).unwrap().sqrt();
+    assert_eq!(non_negative.get(), 3.0);
+
+    let positive: PositiveF32 = PositiveF32::new(2.25).unwrap().sqrt();
+    assert_eq!(positive.get(), 1.5);
+
+    let normalized: NormalizedF64 = NormalizedF64::new(0.81).unwrap().sqrt();
+    assert_eq!(normalized.get(), 0.9);
+
+    // The root of the smallest subnormal is still positive
+    let tiny: PositiveF64 = PositiveF64::new(5e-324).unwrap().sqrt();
+    assert!(tiny.get() > 0.0);
+
+    // -0.0 is admitted by NonNegative, and so is its root
+    let neg_zero: NonNegativeF64 = NonNegativeF64::new(-0.0).unwrap().sqrt();
+    assert_eq!(neg_zero.get(), 0.0);
+}
+
+#[test]
+fn test_sqrt_fails_on_negative_values() {
+    let fin: Result<NonNegativeF64, FloatError> = FinF64::new(2.25).unwrap().sqrt();
+    assert_eq!(fin.unwrap().get(), 1.5);
+
+    let sym: Result<NormalizedF64, FloatError> = SymmetricF64::new(0.25).unwrap().sqrt();
+    assert_eq!(sym.unwrap().get(), 0.5);
+
+    let err = FinF64::new(-4.0).unwrap().sqrt();
+    assert_eq!(err, Err(FloatError::NaN));
+
+    let negative = NegativeF32::new(-1.0).unwrap().sqrt();
+    assert!(negative.is_err());
+    assert!(SymmetricF32::new(-0.5).unwrap().sqrt().is_err());
+}
+
+#[test]
+fn test_cbrt_preserves_sign_and_bounds() {
+    let fin: FinF64 = FinF64::new(-8.0).unwrap().cbrt();
+    assert_eq!(fin.get(), -2.0);
+
+    let nonzero: NonZeroF64 = NonZeroF64::new(27.0).unwrap().cbrt();
+    assert_eq!(nonzero.get(), 3.0);
+
+    let negative: NegativeF32 = NegativeF32::new(-64.0).unwrap().cbrt();
+    assert_eq!(negative.get(), -4.0);
+
+    let non_positive: NonPositiveF64 = NonPositiveF64::new(0.0).unwrap().cbrt();
+    assert_eq!(non_positive.get(), 0.0);
+
+    let sym: SymmetricF64 = SymmetricF64::new(-0.125).unwrap().cbrt();
+    assert_eq!(sym.get(), -0.5);
+
+    let neg_norm: NegativeNormalizedF64 = NegativeNormalizedF64::new(-1.0).unwrap().cbrt();
+    assert_eq!(neg_norm.get(), -1.0);
+
+    // Extremes stay finite and non-zero
+    let tiny: PositiveF64 = PositiveF64::new(5e-324).unwrap().cbrt();
+    assert!(tiny.get() > 0.0);
+    let max: FinF32 = FinF32::new(f32::MIN).unwrap().cbrt();
+    assert!(max.get().is_finite());
+}
+
+#[test]
+fn test_hypot() {
+    let dx = PositiveF64::new(3.0).unwrap();
+    let dy = FinF64::new(-4.0).unwrap();
+
+    let positive: Result<PositiveF64, FloatError> = dx.hypot(dy);
+    assert_eq!(positive.unwrap().get(), 5.0);
+    let commuted: Result<PositiveF64, FloatError> = dy.hypot(dx);
+    assert_eq!(commuted.unwrap().get(), 5.0);
+
+    let non_negative: Result<NonNegativeF64, FloatError> = dy.hypot(FinF64::new(0.0).unwrap());
+    assert_eq!(non_negative.unwrap().get(), 4.0);
+
+    // Bounded operands cannot overflow
+    let bounded: NonNegativeF32 = NormalizedF32::new(0.6)
+        .unwrap()
+        .hypot(SymmetricF32::new(0.8).unwrap());
+    assert!((bounded.get() - 1.0).abs() < 1e-6);
+    let nonzero: PositiveF64 = NonZeroF64::new(-1.0)
+        .unwrap()
+        .hypot(PiBoundedF64::new(0.0).unwrap())
+        .unwrap();
+    assert_eq!(nonzero.get(), 1.0);
+
+    let max = FinF64::new(f64::MAX).unwrap();
+    assert_eq!(max.hypot(max), Err(FloatError::Overflow));
+}
+
+#[test]
+fn test_checked_roots() {
+    let side: Checked<PositiveF64> = Checked::new(PositiveF64::new(16.0).unwrap()).sqrt();
+    assert_eq!(side.into_result().unwrap().get(), 4.0);
+    let gain: Checked<NormalizedF64> = Checked::new(NormalizedF64::new(0.25).unwrap()).sqrt();
+    assert_eq!(gain.into_result().unwrap().get(), 0.5);
+
+    let negative = Checked::new(FinF64::new(-2.0).unwrap()).sqrt();
+    let err = negative.detailed_error().unwrap();
+    assert_eq!(err.kind(), FloatError::NaN);
+    assert_eq!(err.type_name(), Some("NonNegativeF64"));
+
+    let edge: Checked<NegativeF64> = Checked::new(NegativeF64::new(-27.0).unwrap()).cbrt();
+    assert_eq!(edge.into_result().unwrap().get(), -3.0);
+
+    let dx = Checked::new(PositiveF64::new(3.0).unwrap());
+    let distance: Checked<PositiveF64> = dx.hypot(FinF64::new(-4.0).unwrap());
+    assert_eq!(distance.into_result().unwrap().get(), 5.0);
+
+    let max = FinF64::new(f64::MAX).unwrap();
+    let overflow = Checked::new(max).hypot(max).detailed_error().unwrap();
+    assert_eq!(overflow.kind(), FloatError::Overflow);
+    assert_eq!(overflow.operation().unwrap().operator(), Operator::Hypot);
+    assert_eq!(overflow.operation().unwrap().lhs(), f64::MAX);
+
+    // Errors of earlier steps are kept
+    let zero = FinF64::new(0.0).unwrap();
+    let failed = (Checked::new(max) / zero).sqrt().cbrt();
+    assert_eq!(failed.error(), Some(FloatError::DivisionByZero));
+}
+
+#[test]
+fn test_custom_constraint_roots() {
+    strict_num_extended::define_constraints!([
+        (AtLeastOne, [">= 1.0"]),
+        (Volume, [">= 0.0", "<= 1000.0"]),
+    ]);
+
+    // [1, ∞) contains every root, so the type is kept
+    let root: AtLeastOneF64 = AtLeastOneF64::new(4.0).unwrap().sqrt();
+    assert_eq!(root.get(), 2.0);
+    let cube: AtLeastOneF64 = AtLeastOneF64::new(8.0).unwrap().cbrt();
+    assert_eq!(cube.get(), 2.0);
+
+    let edge: VolumeF64 = VolumeF64::new(125.0).unwrap().cbrt();
+    assert_eq!(edge.get(), 5.0);
+
+    // Two bounded operands give an infallible hypotenuse
+    let diagonal: NonNegativeF64 = VolumeF64::new(30.0)
+        .unwrap()
+        .hypot(VolumeF64::new(40.0).unwrap());
+    assert_eq!(diagonal.get(), 50.0);
+}
@@ -0,0 +1,142 @@
+//! Tests for saturating arithmetic methods
+//!
+//! Results are clamped to the bounds of the output type that inference picks for
+//! the corresponding operator.
+
+#![expect(clippy::float_cmp)]
+
+use strict_num_extended::*;
+
+#[test]
+fn test_saturating_add_overflow() {
+    let max = PositiveF64::new(f64::MAX).unwrap();
+    let sum: PositiveF64 = max.saturating_add(max);
+    assert_eq!(sum.get(), f64::MAX);
+
+    let min = NegativeF32::new(f32::MIN).unwrap();
+    let total: NegativeF32 = min.saturating_add(min);
+    assert_eq!(total.get(), f32::MIN);
+}
+
+#[test]
+fn test_saturating_within_range_is_exact() {
+    let a = PositiveF64::new(1.5).unwrap();
+    let b = PositiveF64::new(2.0).unwrap();
+    assert_eq!(a.saturating_add(b).get(), 3.5);
+    assert_eq!(a.saturating_sub(b).get(), -0.5);
+    assert_eq!(a.saturating_mul(b).get(), 3.0);
+    assert_eq!(a.saturating_div(b).get(), 0.75);
+}
+
+#[test]
+fn test_saturating_to_bounded_output() {
+    // Normalized + Normalized infers NonNegative, so no clamping to 1.0 happens
+    let a = NormalizedF64::new(0.75).unwrap();
+    let b = NormalizedF64::new(0.5).unwrap();
+    let sum: NonNegativeF64 = a.saturating_add(b);
+    assert_eq!(sum.get(), 1.25);
+
+    let product: NormalizedF64 = a.saturating_mul(b);
+    assert_eq!(product.get(), 0.375);
+
+    let fin = FinF64::new(-3.0).unwrap();
+    let clamped: FinF64 = fin.saturating_mul(FinF64::new(f64::MAX).unwrap());
+    assert_eq!(clamped.get(), f64::MIN);
+}
+
+#[test]
+fn test_saturating_underflow_to_min_positive() {
+    let tiny = PositiveF64::new(f64::MIN_POSITIVE).unwrap();
+    let product: PositiveF64 = tiny.saturating_mul(tiny);
+    assert_eq!(product.get(), f64::MIN_POSITIVE);
+
+    let quotient: PositiveF32 = PositiveF32::new(f32::MIN_POSITIVE)
+        .unwrap()
+        .saturating_div(PositiveF32::new(f32::MAX).unwrap());
+    assert_eq!(quotient.get(), f32::MIN_POSITIVE);
+
+    let negative: NegativeF64 = tiny.saturating_mul(NegativeF64::new(-f64::MIN_POSITIVE).unwrap());
+    assert_eq!(negative.get(), -f64::MIN_POSITIVE);
+}
+
+#[test]
+fn test_saturating_nonzero() {
+    let a = NonZeroF64::new(2.0).unwrap();
+    let b = NonZeroF64::new(1e-300).unwrap();
+    let product: NonZeroF64 = b.saturating_mul(b);
+    assert_eq!(product.get(), f64::MIN_POSITIVE);
+
+    let negative: NonZeroF64 = b.saturating_mul(NonZeroF64::new(-1e-300).unwrap());
+    assert_eq!(negative.get(), -f64::MIN_POSITIVE);
+    assert_eq!(a.saturating_mul(a).get(), 4.0);
+}
+
+#[test]
+fn test_saturating_div_by_zero() {
+    let a = FinF64::new(1.0).unwrap();
+    let zero = FinF64::new(0.0).unwrap();
+    assert_eq!(a.saturating_div(zero).get(), f64::MAX);
+    assert_eq!(
+        FinF64::new(-1.0).unwrap().saturating_div(zero).get(),
+        f64::MIN
+    );
+    assert_eq!(zero.saturating_div(zero).get(), 0.0);
+
+    let positive = NonNegativeF64::new(0.0).unwrap();
+    let ratio: NonNegativeF64 = positive.saturating_div(NonNegativeF64::new(0.0).unwrap());
+    assert_eq!(ratio.get(), 0.0);
+}
+
+#[test]
+fn test_saturating_always_valid() {
+    let values = [
+        f64::MIN,
+        -1.0,
+        -f64::MIN_POSITIVE,
+        0.0,
+        1e-300,
+        0.5,
+        1.0,
+        f64::MAX,
+    ];
+    for &a in &values {
+        for &b in &values {
+            let (lhs, rhs) = (FinF64::new(a).unwrap(), FinF64::new(b).unwrap());
+            assert!(lhs.saturating_add(rhs).get().is_finite());
+            assert!(lhs.saturating_sub(rhs).get().is_finite());
+            assert!(lhs.saturating_mul(rhs).get().is_finite());
+            assert!(lhs.saturating_div(rhs).get().is_finite());
+
+            if let (Ok(l), Ok(r)) = (PositiveF64::new(a), PositiveF64::new(b)) {
+                assert!(l.saturating_mul(r).get() > 0.0);
+                assert!(l.saturating_div(r).get() > 0.0);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_bounds_saturate() {
+    assert_eq!(Normalized::F64_BOUNDS.saturate(1.5), 1.0);
+    assert_eq!(Normalized::F64_BOUNDS.saturate(-0.5), 0.0);
+    assert_eq!(Normalized::F64_BOUNDS.saturate(f64::NAN), 0.0);
+    assert_eq!(Positive::F32_BOUNDS.saturate(-1.0), f32::MIN_POSITIVE);
+    assert_eq!(Negative::F64_BOUNDS.saturate(0.0), -f64::MIN_POSITIVE);
+    assert_eq!(Fin::F64_BOUNDS.saturate(f64::INFINITY), f64::MAX);
+}
+
+mod open {
+    strict_num_extended::define_constraints!([(Unit, ["> -1.0", "< 1.0"])]);
+}
+
+#[test]
+fn test_saturate_exclusive_bounds() {
+    use open::Unit;
+
+    let upper = Unit::F64_BOUNDS.saturate(2.0);
+    assert!(upper < 1.0);
+    assert_eq!(upper, 1.0 - f64::EPSILON / 2.0);
+    let lower = Unit::F64_BOUNDS.saturate(-2.0);
+    assert_eq!(lower, -1.0 + f64::EPSILON / 2.0);
+    assert!(Unit::F32_BOUNDS.contains(Unit::F32_BOUNDS.saturate(1.0)));
+}
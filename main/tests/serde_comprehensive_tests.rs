@@ -166,7 +166,9 @@ fn test_serialize_deserialize_error() {
         FloatError::NaN,
         FloatError::PosInf,
         FloatError::NegInf,
-        FloatError::OutOfRange,
+        FloatError::BelowRange,
+        FloatError::AboveRange,
+        FloatError::ExcludedZero,
     ] {
         let json = serde_json::to_string(&error_variant).unwrap();
         let deserialized: FloatError = serde_json::from_str(&json).unwrap();
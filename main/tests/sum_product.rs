@@ -0,0 +1,118 @@
+//! Tests for `Sum`, `Product` and `sum_compensated`
+//!
+//! Sums and products must keep the constraint of the values, report the first error,
+//! and be infallible for products of types like `Normalized`.
+
+#![expect(clippy::float_cmp)]
+
+use strict_num_extended::*;
+
+fn positives(values: &[f64]) -> Result<Vec<PositiveF64>, FloatError> {
+    values.iter().map(|&v| PositiveF64::new(v)).collect()
+}
+
+#[test]
+fn test_sum() {
+    let values = positives(&[1.0, 2.0, 3.5]).unwrap();
+
+    let by_ref: Result<PositiveF64, FloatError> = values.iter().sum();
+    assert_eq!(by_ref.unwrap().get(), 6.5);
+
+    let by_value: Result<PositiveF64, FloatError> = values.into_iter().sum();
+    assert_eq!(by_value.unwrap().get(), 6.5);
+
+    let negatives = [-1.0, -0.5].map(|v| NegativeF32::new(v).unwrap());
+    let negative_sum: Result<NegativeF32, FloatError> = negatives.iter().sum();
+    assert_eq!(negative_sum.unwrap().get(), -1.5);
+
+    let mixed = [FinF64::new(1.0).unwrap(), FinF64::new(-3.0).unwrap()];
+    let mixed_sum: Result<FinF64, FloatError> = mixed.iter().sum();
+    assert_eq!(mixed_sum.unwrap().get(), -2.0);
+}
+
+#[test]
+fn test_empty_sum_is_zero() {
+    let empty: [NonNegativeF64; 0] = [];
+    let sum: Result<NonNegativeF64, FloatError> = empty.iter().sum();
+    assert_eq!(sum.unwrap().get(), 0.0);
+
+    let no_positives: [PositiveF64; 0] = [];
+    let positive_sum: Result<PositiveF64, FloatError> = no_positives.iter().sum();
+    assert_eq!(positive_sum, Err(FloatError::BelowRange));
+}
+
+#[test]
+fn test_sum_overflow() {
+    let values = positives(&[f64::MAX, f64::MAX, 1.0]).unwrap();
+    let sum: Result<PositiveF64, FloatError> = values.iter().sum();
+    assert_eq!(sum, Err(FloatError::Overflow));
+}
+
+#[test]
+fn test_product() {
+    let values = positives(&[2.0, 0.5, 3.0]).unwrap();
+    let product: Result<PositiveF64, FloatError> = values.iter().product();
+    assert_eq!(product.unwrap().get(), 3.0);
+
+    let tiny = positives(&[1e-200, 1e-200]).unwrap();
+    let underflow: Result<PositiveF64, FloatError> = tiny.into_iter().product();
+    assert_eq!(underflow, Err(FloatError::Underflow));
+
+    let empty: [NonZeroF64; 0] = [];
+    let one: Result<NonZeroF64, FloatError> = empty.iter().product();
+    assert_eq!(one.unwrap().get(), 1.0);
+}
+
+#[test]
+fn test_infallible_product() {
+    let weights = [0.5, 0.8, 0.25].map(|w| NormalizedF64::new(w).unwrap());
+    let total: NormalizedF64 = weights.iter().product();
+    assert_eq!(total.get(), 0.1);
+
+    let by_value: NormalizedF64 = weights.into_iter().product();
+    assert_eq!(by_value.get(), 0.1);
+
+    let signs = [-1.0, -0.5].map(|s| SymmetricF32::new(s).unwrap());
+    let product: SymmetricF32 = signs.iter().product();
+    assert_eq!(product.get(), 0.5);
+
+    let empty: [NormalizedF64; 0] = [];
+    assert_eq!(empty.iter().product::<NormalizedF64>().get(), 1.0);
+
+    // The fallible form is still available
+    let checked: Result<NormalizedF64, FloatError> = weights.iter().product();
+    assert_eq!(checked.unwrap().get(), 0.1);
+}
+
+#[test]
+fn test_sum_compensated() {
+    let mut readings = vec![PositiveF64::new(1.0).unwrap()];
+    readings.extend(positives(&[1e-16; 1000]).unwrap());
+
+    let naive: Result<PositiveF64, FloatError> = readings.iter().sum();
+    assert_eq!(naive.unwrap().get(), 1.0);
+
+    let compensated = readings.iter().copied().sum_compensated().unwrap();
+    assert!((compensated.get() - (1.0 + 1e-13)).abs() < 1e-15);
+
+    let no_readings: [FinF64; 0] = [];
+    assert_eq!(
+        no_readings.into_iter().sum_compensated().unwrap().get(),
+        0.0
+    );
+}
+
+#[test]
+fn test_sum_compensated_reports_overflow() {
+    let values = positives(&[f64::MAX, f64::MAX]).unwrap();
+    assert_eq!(
+        values.into_iter().sum_compensated(),
+        Err(FloatError::Overflow)
+    );
+
+    let empty: [PositiveF64; 0] = [];
+    assert_eq!(
+        empty.into_iter().sum_compensated(),
+        Err(FloatError::BelowRange)
+    );
+}